@@ -0,0 +1,163 @@
+//! Builder for new GVAS files
+
+use crate::{
+    custom_version::custom_versions_for,
+    engine_version::{EngineVersion, FEngineVersion},
+    error::{Error, SerializeError},
+    game_version::DeserializedGameVersion,
+    properties::Property,
+    GvasFile, GvasHeader, GvasTrailer,
+};
+
+/// Custom version format written by UE4 and UE5
+const CUSTOM_VERSION_FORMAT: u32 = 3;
+
+/// Builder for a new [`GvasFile`]
+///
+/// The header is derived from the engine version: the package file versions of the release, the
+/// custom versions known to this crate as they were in that release, and a version 3 header for
+/// UE5 or a version 2 header for UE4.
+///
+/// # Examples
+///
+/// ```
+/// use gvas::{
+///     builder::GvasFileBuilder, engine_version::EngineVersion, error::Error,
+///     properties::int_property::IntProperty,
+/// };
+/// use std::io::Cursor;
+///
+/// let gvas_file = GvasFileBuilder::new(EngineVersion::VER_UE5_3, "/Script/MyGame.MySaveGame")
+///     .property("Score", IntProperty::new(42))
+///     .build()?;
+///
+/// let mut writer = Cursor::new(Vec::new());
+/// gvas_file.write(&mut writer)?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GvasFileBuilder {
+    engine_version: EngineVersion,
+    save_game_class_name: String,
+    patch: u16,
+    change_list: u32,
+    branch: Option<String>,
+    deserialized_game_version: DeserializedGameVersion,
+    properties: Vec<(String, Property)>,
+}
+
+impl GvasFileBuilder {
+    /// Creates a new `GvasFileBuilder` for a file of `save_game_class_name` saved by
+    /// `engine_version`
+    #[inline]
+    pub fn new(engine_version: EngineVersion, save_game_class_name: impl Into<String>) -> Self {
+        GvasFileBuilder {
+            engine_version,
+            save_game_class_name: save_game_class_name.into(),
+            patch: 0,
+            change_list: 0,
+            branch: None,
+            deserialized_game_version: DeserializedGameVersion::Default,
+            properties: vec![],
+        }
+    }
+
+    /// Sets the patch version of the engine, 0 by default
+    #[inline]
+    pub fn patch(mut self, patch: u16) -> Self {
+        self.patch = patch;
+        self
+    }
+
+    /// Sets the changelist the engine was built from, 0 by default
+    #[inline]
+    pub fn change_list(mut self, change_list: u32) -> Self {
+        self.change_list = change_list;
+        self
+    }
+
+    /// Sets the branch the engine was built from, e.g. `++UE5+Release-5.3` by default for 5.3
+    #[inline]
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// Sets the game version, e.g. to wrap the file in a container when writing it
+    #[inline]
+    pub fn game_version(mut self, game_version: DeserializedGameVersion) -> Self {
+        self.deserialized_game_version = game_version;
+        self
+    }
+
+    /// Adds a property
+    ///
+    /// Adding several properties with the same name makes a static array, see
+    /// [`GvasFile::properties`].
+    #[inline]
+    pub fn property(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        self.properties.push((name.into(), property.into()));
+        self
+    }
+
+    /// Builds the header
+    ///
+    /// # Errors
+    ///
+    /// If the engine version isn't a release, e.g. [`EngineVersion::UNKNOWN`], this returns
+    /// [`SerializeError::InvalidValue`]
+    pub fn build_header(&self) -> Result<GvasHeader, Error> {
+        let (Some((major, minor)), Some(package_file_version)) = (
+            self.engine_version.release(),
+            self.engine_version.package_file_version(),
+        ) else {
+            Err(SerializeError::invalid_value(format!(
+                "Can't build a header for engine version {:?}",
+                self.engine_version
+            )))?
+        };
+
+        let branch = match &self.branch {
+            Some(branch) => branch.clone(),
+            None => format!("++UE{major}+Release-{major}.{minor}"),
+        };
+        let engine_version =
+            FEngineVersion::new(major, minor, self.patch, self.change_list, branch);
+        let custom_versions = custom_versions_for(self.engine_version);
+        let save_game_class_name = self.save_game_class_name.clone();
+
+        Ok(match package_file_version.file_version_ue5 {
+            0 => GvasHeader::Version2 {
+                package_file_version: package_file_version.file_version_ue4,
+                engine_version,
+                custom_version_format: CUSTOM_VERSION_FORMAT,
+                custom_versions,
+                save_game_class_name,
+            },
+            package_file_version_ue5 => GvasHeader::Version3 {
+                package_file_version: package_file_version.file_version_ue4,
+                package_file_version_ue5,
+                engine_version,
+                custom_version_format: CUSTOM_VERSION_FORMAT,
+                custom_versions,
+                save_game_class_name,
+            },
+        })
+    }
+
+    /// Builds the file
+    ///
+    /// # Errors
+    ///
+    /// If the engine version isn't a release, e.g. [`EngineVersion::UNKNOWN`], this returns
+    /// [`SerializeError::InvalidValue`]
+    #[inline]
+    pub fn build(self) -> Result<GvasFile, Error> {
+        Ok(GvasFile {
+            header: self.build_header()?,
+            deserialized_game_version: self.deserialized_game_version,
+            properties: self.properties,
+            trailer: GvasTrailer::default(),
+        })
+    }
+}
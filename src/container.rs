@@ -0,0 +1,1690 @@
+//! Save containers
+//!
+//! Some games wrap the GVAS data in an outer format, e.g. to compress it. A [`SaveContainer`]
+//! unwraps such a file to plain GVAS bytes when reading, and wraps the GVAS bytes again when
+//! writing.
+//!
+//! Containers may also unwrap the GVAS data as a stream, so large saves are parsed without
+//! holding all of the GVAS data in memory, see [`SaveContainer::unwrap_stream`]. When writing,
+//! [`SaveContainer::wrap_stream`] takes the GVAS data in parts, but each top-level property is
+//! still serialized into memory as a whole first, since its tag starts with the length of its
+//! value. Writing a save with one large property, e.g. `worldSaveData` of a Palworld
+//! `Level.sav`, holds that property in memory.
+
+use std::{
+    io::{self, Cursor, Read, Seek, SeekFrom, Take, Write},
+    mem,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+
+use crate::{
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
+    error::{DeserializeError, Diagnostic, Error, SerializeError},
+    game_version::{
+        palworld_magic, CompressedData, DeserializedGameVersion, PalworldChunk,
+        PalworldCompressionType, ZlibParameters, CNK_MAGIC, PLM_MAGIC, PLZ_MAGIC,
+    },
+    limits::Limits,
+};
+
+/// Number of bytes passed to [`SaveContainer::detect`]
+pub const DETECT_LENGTH: usize = 64;
+
+/// A reader that can also seek
+pub trait ReadSeek: Read + Seek {}
+
+impl<T: Read + Seek + ?Sized> ReadSeek for T {}
+
+/// A writer that can also seek
+pub trait WriteSeek: Write + Seek {}
+
+impl<T: Write + Seek + ?Sized> WriteSeek for T {}
+
+/// GVAS data read from a container while it is unwrapped, see [`SaveContainer::unwrap_stream`]
+pub trait UnwrapStream: Read {
+    /// Finishes unwrapping after the GVAS data was read, returning the metadata needed to wrap
+    /// it again
+    ///
+    /// # Errors
+    ///
+    /// If the rest of the container is invalid, or the GVAS data exceeds the [`Limits`] the
+    /// stream was started with
+    fn finish(self: Box<Self>) -> Result<DeserializedGameVersion, Error>;
+}
+
+/// GVAS data written to a container while it is wrapped, see [`SaveContainer::wrap_stream`]
+pub trait WrapStream: Write {
+    /// Finishes wrapping after all of the GVAS data was written
+    ///
+    /// # Errors
+    ///
+    /// If writing fails
+    fn finish(self: Box<Self>) -> Result<(), Error>;
+}
+
+/// An outer format wrapping the GVAS data of a save file
+///
+/// # Examples
+///
+/// ```
+/// use gvas::{
+///     container::{ReadSeek, SaveContainer},
+///     error::Error,
+///     game_version::DeserializedGameVersion,
+///     limits::Limits,
+/// };
+/// use std::io::Write;
+///
+/// /// GVAS data with every byte inverted
+/// struct Inverted;
+///
+/// impl SaveContainer for Inverted {
+///     fn name(&self) -> &str {
+///         "Inverted"
+///     }
+///
+///     fn detect(&self, prefix: &[u8]) -> bool {
+///         prefix.starts_with(&[!b'G', !b'V', !b'A', !b'S'])
+///     }
+///
+///     fn unwrap(
+///         &self,
+///         reader: &mut dyn ReadSeek,
+///         _limits: &Limits,
+///     ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+///         let mut data = Vec::new();
+///         reader.read_to_end(&mut data)?;
+///         let data = data.into_iter().map(|b| !b).collect();
+///         Ok((data, DeserializedGameVersion::custom(self.name(), Vec::new())))
+///     }
+///
+///     fn wrap(
+///         &self,
+///         payload: &[u8],
+///         _game_version: &DeserializedGameVersion,
+///         writer: &mut dyn Write,
+///     ) -> Result<(), Error> {
+///         let data: Vec<u8> = payload.iter().map(|b| !b).collect();
+///         writer.write_all(&data)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait SaveContainer {
+    /// Unique name of the container
+    ///
+    /// Stored in [`DeserializedGameVersion`] to find the container again when writing.
+    fn name(&self) -> &str;
+
+    /// Returns true if a file starting with `prefix` uses this container
+    ///
+    /// The prefix holds the first [`DETECT_LENGTH`] bytes of the file, or fewer if the file is
+    /// shorter.
+    fn detect(&self, prefix: &[u8]) -> bool;
+
+    /// Reads the container from `reader`, returning the GVAS data and the metadata needed to
+    /// wrap it again
+    ///
+    /// # Errors
+    ///
+    /// If the container is invalid, or the GVAS data exceeds the given [`Limits`]
+    fn unwrap(
+        &self,
+        reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error>;
+
+    /// Like [`SaveContainer::unwrap`], but reports problems the GVAS data can still be read
+    /// despite, e.g. a checksum mismatch, as [`Diagnostic`]s instead of failing
+    ///
+    /// Used when reading leniently. The default implementation calls [`SaveContainer::unwrap`].
+    ///
+    /// # Errors
+    ///
+    /// If the container is invalid, or the GVAS data exceeds the given [`Limits`]
+    #[inline]
+    fn unwrap_lenient(
+        &self,
+        reader: &mut dyn ReadSeek,
+        limits: &Limits,
+        _diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        self.unwrap(reader, limits)
+    }
+
+    /// Wraps the GVAS data in `payload` and writes the container to `writer`
+    ///
+    /// # Errors
+    ///
+    /// If the metadata in `game_version` doesn't belong to this container, or writing fails
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        writer: &mut dyn Write,
+    ) -> Result<(), Error>;
+
+    /// Starts reading the container from `reader`, returning a stream of the GVAS data instead
+    /// of all of it at once
+    ///
+    /// Used when reading without diagnostics. The default implementation calls
+    /// [`SaveContainer::unwrap`] and returns a stream over the data it read.
+    ///
+    /// # Errors
+    ///
+    /// If the container is invalid, or the GVAS data exceeds the given [`Limits`]
+    #[inline]
+    fn unwrap_stream<'a>(
+        &'a self,
+        reader: &'a mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<Box<dyn UnwrapStream + 'a>, Error> {
+        let (data, game_version) = self.unwrap(reader, limits)?;
+        Ok(Box::new(BufferedUnwrapStream {
+            data: Cursor::new(data),
+            game_version,
+        }))
+    }
+
+    /// Starts writing the container to `writer`, returning a stream the GVAS data is written to
+    /// instead of passing all of it at once
+    ///
+    /// The GVAS data is written a top-level property at a time, each of them serialized into
+    /// memory first.
+    ///
+    /// The default implementation collects the GVAS data and calls [`SaveContainer::wrap`] when
+    /// the stream is finished.
+    ///
+    /// # Errors
+    ///
+    /// If the metadata in `game_version` doesn't belong to this container, or writing fails
+    #[inline]
+    fn wrap_stream<'a>(
+        &'a self,
+        game_version: &'a DeserializedGameVersion,
+        writer: &'a mut dyn WriteSeek,
+    ) -> Result<Box<dyn WrapStream + 'a>, Error> {
+        Ok(Box::new(BufferedWrapStream {
+            container: self,
+            game_version,
+            writer,
+            payload: Vec::new(),
+        }))
+    }
+}
+
+/// GVAS data unwrapped all at once by [`SaveContainer::unwrap`]
+struct BufferedUnwrapStream {
+    data: Cursor<Vec<u8>>,
+    game_version: DeserializedGameVersion,
+}
+
+impl Read for BufferedUnwrapStream {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.data.read(buf)
+    }
+}
+
+impl UnwrapStream for BufferedUnwrapStream {
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<DeserializedGameVersion, Error> {
+        Ok(self.game_version)
+    }
+}
+
+/// GVAS data collected to be wrapped all at once by [`SaveContainer::wrap`]
+struct BufferedWrapStream<'a, C: SaveContainer + ?Sized> {
+    container: &'a C,
+    game_version: &'a DeserializedGameVersion,
+    writer: &'a mut dyn WriteSeek,
+    payload: Vec<u8>,
+}
+
+impl<C: SaveContainer + ?Sized> Write for BufferedWrapStream<'_, C> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.payload.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<C: SaveContainer + ?Sized> WrapStream for BufferedWrapStream<'_, C> {
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<(), Error> {
+        self.container
+            .wrap(&self.payload, self.game_version, self.writer)
+    }
+}
+
+/// Containers known to this crate, used for detecting containers and when writing files
+pub(crate) const BUILTIN_CONTAINERS: &[&dyn SaveContainer] =
+    &[&Palworld::DEFAULT, &SerializeCompressed];
+
+/// Reads the prefix passed to [`SaveContainer::detect`], leaving the reader where it was
+pub(crate) fn read_prefix<R: Read + Seek>(reader: &mut R) -> Result<Vec<u8>, Error> {
+    let start = reader.stream_position()?;
+    let mut prefix = Vec::with_capacity(DETECT_LENGTH);
+    reader
+        .by_ref()
+        .take(DETECT_LENGTH as u64)
+        .read_to_end(&mut prefix)?;
+    reader.seek(SeekFrom::Start(start))?;
+    Ok(prefix)
+}
+
+/// Tag at the start of a `SerializeCompressed` stream
+const PACKAGE_FILE_TAG: u64 = 0x9E2A83C1;
+
+/// Compression type byte of uncompressed `PlM` data
+const PLM_UNCOMPRESSED: u8 = 0x30;
+
+/// Palworld container
+///
+/// A `PlZ` header followed by the GVAS data, optionally compressed with zlib once or twice. Some
+/// saves put a `CNK` header in front, see [`PalworldChunk`]. Its lengths are written again as
+/// read if they differ from the ones of the `PlZ` header, and follow the `PlZ` header otherwise.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gvas::{container::Palworld, error::Error, GvasFile};
+/// use std::{collections::HashMap, fs::File, io::Cursor};
+///
+/// let mut file = File::open("Level.sav")?;
+/// let gvas_file =
+///     GvasFile::read_with_containers(&mut file, &[&Palworld::DEFAULT], &HashMap::new())?;
+///
+/// // Written byte for byte as read, since the properties are unchanged
+/// let mut writer = Cursor::new(Vec::new());
+/// gvas_file.write(&mut writer)?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Palworld {
+    /// Keeps the compressed data when reading, to write it again while the data it holds is
+    /// unchanged
+    ///
+    /// The compressed data is held in memory until the file is dropped, and isn't serialized
+    /// with serde. Otherwise the data is compressed again with the level and header detected
+    /// when reading, which is only a hint and may not give the original compressed data.
+    pub keep_compressed_data: bool,
+}
+
+impl Palworld {
+    /// Palworld container keeping the compressed data, see [`Palworld::keep_compressed_data`]
+    pub const DEFAULT: Palworld = Palworld {
+        keep_compressed_data: true,
+    };
+
+    /// Palworld container compressing the data again when writing, to save memory
+    pub const RECOMPRESS: Palworld = Palworld {
+        keep_compressed_data: false,
+    };
+}
+
+impl Default for Palworld {
+    #[inline]
+    fn default() -> Self {
+        Palworld::DEFAULT
+    }
+}
+
+impl SaveContainer for Palworld {
+    #[inline]
+    fn name(&self) -> &str {
+        "Palworld"
+    }
+
+    #[inline]
+    fn detect(&self, prefix: &[u8]) -> bool {
+        palworld_magic(prefix) == Some(PLZ_MAGIC)
+    }
+
+    fn unwrap(
+        &self,
+        reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let mut stream = PalworldUnwrapStream::new(reader, limits, self.keep_compressed_data)?;
+        let mut data = Vec::with_capacity(bounded_capacity(stream.decompresed_length as usize));
+        stream.read_to_end(&mut data)?;
+        Ok((data, stream.into_game_version()?))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        mut writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let (compression_type, chunk, zlib) = palworld_parameters(game_version)?;
+
+        // For ZlibTwice the compressed length is the length after the first pass.
+        let original = zlib
+            .original
+            .as_ref()
+            .filter(|original| holds(compression_type, original, payload));
+        let (compressed_length, data) = match (compression_type, original) {
+            (PalworldCompressionType::None, _) => (payload.len(), payload.to_vec()),
+            (_, Some(original)) => (original.compressed_length as usize, original.data.clone()),
+            (PalworldCompressionType::Zlib, None) => {
+                let data = compress(payload, zlib_level(zlib.level)?)?;
+                (data.len(), data)
+            }
+            (PalworldCompressionType::ZlibTwice, None) => {
+                let level = zlib_level(zlib.level)?;
+                let once = compress(payload, level)?;
+                (once.len(), compress(&once, level)?)
+            }
+        };
+
+        write_palworld_header(
+            &mut writer,
+            payload.len(),
+            compressed_length,
+            PLZ_MAGIC,
+            chunk,
+        )?;
+        writer.write_enum(compression_type)?;
+        writer.write_all(&data)?;
+        Ok(())
+    }
+
+    fn unwrap_stream<'a>(
+        &'a self,
+        reader: &'a mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<Box<dyn UnwrapStream + 'a>, Error> {
+        Ok(Box::new(PalworldUnwrapStream::new(
+            reader,
+            limits,
+            self.keep_compressed_data,
+        )?))
+    }
+
+    fn wrap_stream<'a>(
+        &'a self,
+        game_version: &'a DeserializedGameVersion,
+        writer: &'a mut dyn WriteSeek,
+    ) -> Result<Box<dyn WrapStream + 'a>, Error> {
+        Ok(Box::new(PalworldWrapStream::new(game_version, writer)?))
+    }
+}
+
+/// Returns the compression type, save type and zlib parameters of a Palworld game version
+#[inline]
+fn palworld_parameters(
+    game_version: &DeserializedGameVersion,
+) -> Result<
+    (
+        PalworldCompressionType,
+        Option<PalworldChunk>,
+        &ZlibParameters,
+    ),
+    Error,
+> {
+    match game_version {
+        DeserializedGameVersion::Palworld {
+            compression_type,
+            chunk,
+            zlib,
+        } => Ok((*compression_type, *chunk, zlib)),
+        _ => Err(SerializeError::invalid_value(format!(
+            "Palworld can't write {game_version:?}"
+        )))?,
+    }
+}
+
+/// Checks if the compressed data `original` holds exactly `payload`
+fn holds(
+    compression_type: PalworldCompressionType,
+    original: &CompressedData,
+    mut payload: &[u8],
+) -> bool {
+    if original.decompressed_length != payload.len() as u64 {
+        return false;
+    }
+    let mut decoder = PalworldWrapStream::decoder(compression_type, original);
+    let mut buffer = [0u8; 8192];
+    loop {
+        match decoder.read(&mut buffer) {
+            Ok(0) => return payload.is_empty(),
+            Ok(read) if payload.starts_with(&buffer[..read]) => payload = &payload[read..],
+            _ => return false,
+        }
+    }
+}
+
+/// Reader keeping a copy of the data read from it, or only of the zlib header
+struct Tee<'a> {
+    reader: Take<&'a mut dyn ReadSeek>,
+    keep: bool,
+    length: u64,
+    data: Vec<u8>,
+}
+
+impl Read for Tee<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        let kept = match self.keep {
+            true => read,
+            false => read.min(2usize.saturating_sub(self.data.len())),
+        };
+        self.data.extend_from_slice(&buf[..kept]);
+        self.length += read as u64;
+        Ok(read)
+    }
+}
+
+/// Decoder of the data following a Palworld header
+enum PalworldDecoder<'a> {
+    None(Take<&'a mut dyn ReadSeek>),
+    Zlib(Take<ZlibDecoder<Tee<'a>>>),
+    ZlibTwice(Take<ZlibDecoder<ZlibDecoder<Tee<'a>>>>),
+}
+
+/// GVAS data decompressed from a [`Palworld`] container while it is read
+///
+/// The compressed data may be kept, so it can be written again if the GVAS data is unchanged.
+struct PalworldUnwrapStream<'a> {
+    decoder: PalworldDecoder<'a>,
+    compression_type: PalworldCompressionType,
+    chunk: Option<PalworldChunk>,
+    decompresed_length: u32,
+    compressed_length: u32,
+    limits: Limits,
+    length: u64,
+}
+
+impl<'a> PalworldUnwrapStream<'a> {
+    fn new(
+        mut reader: &'a mut dyn ReadSeek,
+        limits: &Limits,
+        keep_compressed_data: bool,
+    ) -> Result<Self, Error> {
+        let (decompresed_length, compressed_length, chunk) =
+            read_palworld_header(&mut reader, PLZ_MAGIC, limits)?;
+        let compression_type = reader.read_enum()?;
+
+        let max_size = limits.max_decompressed_size;
+        let tee = |reader: &'a mut dyn ReadSeek| Tee {
+            reader: reader.take(max_size + 1),
+            keep: keep_compressed_data,
+            length: 0,
+            data: Vec::new(),
+        };
+        let decoder = match compression_type {
+            PalworldCompressionType::None => {
+                PalworldDecoder::None(reader.take(decompresed_length.into()))
+            }
+            PalworldCompressionType::Zlib => {
+                PalworldDecoder::Zlib(ZlibDecoder::new(tee(reader)).take(decompresed_length.into()))
+            }
+            PalworldCompressionType::ZlibTwice => PalworldDecoder::ZlibTwice(
+                ZlibDecoder::new(ZlibDecoder::new(tee(reader))).take(max_size + 1),
+            ),
+        };
+
+        Ok(PalworldUnwrapStream {
+            decoder,
+            compression_type,
+            chunk,
+            decompresed_length,
+            compressed_length,
+            limits: *limits,
+            length: 0,
+        })
+    }
+
+    /// Reads the rest of the container, returning the game version it was read with
+    fn into_game_version(mut self) -> Result<DeserializedGameVersion, Error> {
+        io::copy(&mut self, &mut io::sink())?;
+
+        let tee = match self.decoder {
+            PalworldDecoder::None(_) | PalworldDecoder::Zlib(_)
+                if self.length != u64::from(self.decompresed_length) =>
+            {
+                Err(io::Error::from(io::ErrorKind::UnexpectedEof))?
+            }
+            PalworldDecoder::None(_) => None,
+            PalworldDecoder::Zlib(decoder) => Some(decoder.into_inner().into_inner()),
+            PalworldDecoder::ZlibTwice(decoder) => {
+                let mut tee = decoder.into_inner().into_inner().into_inner();
+                self.limits
+                    .check_decompressed_size(self.length, tee.reader.get_mut())?;
+                Some(tee)
+            }
+        };
+
+        let zlib = match tee {
+            Some(mut tee) => {
+                io::copy(&mut tee, &mut io::sink())?;
+                self.limits
+                    .check_decompressed_size(tee.length, tee.reader.get_mut())?;
+                let mut zlib = ZlibParameters::detect(&tee.data);
+                if tee.keep {
+                    zlib.original = Some(CompressedData {
+                        decompressed_length: self.length,
+                        compressed_length: self.compressed_length,
+                        data: tee.data,
+                    });
+                }
+                zlib
+            }
+            None => ZlibParameters::default(),
+        };
+
+        Ok(DeserializedGameVersion::Palworld {
+            compression_type: self.compression_type,
+            chunk: self.chunk,
+            zlib,
+        })
+    }
+}
+
+impl Read for PalworldUnwrapStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = match &mut self.decoder {
+            PalworldDecoder::None(decoder) => decoder.read(buf)?,
+            PalworldDecoder::Zlib(decoder) => decoder.read(buf)?,
+            PalworldDecoder::ZlibTwice(decoder) => decoder.read(buf)?,
+        };
+        self.length += read as u64;
+        Ok(read)
+    }
+}
+
+impl UnwrapStream for PalworldUnwrapStream<'_> {
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<DeserializedGameVersion, Error> {
+        self.into_game_version()
+    }
+}
+
+/// Writer counting the bytes written to it
+struct Counter<W: Write> {
+    writer: W,
+    count: u64,
+}
+
+impl<W: Write> Write for Counter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Encoder of the data following a Palworld header
+enum PalworldEncoder<'a> {
+    None(&'a mut dyn WriteSeek),
+    Zlib(ZlibEncoder<&'a mut dyn WriteSeek>),
+    ZlibTwice(ZlibEncoder<Counter<ZlibEncoder<&'a mut dyn WriteSeek>>>),
+}
+
+impl Write for PalworldEncoder<'_> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            PalworldEncoder::None(encoder) => encoder.write(buf),
+            PalworldEncoder::Zlib(encoder) => encoder.write(buf),
+            PalworldEncoder::ZlibTwice(encoder) => encoder.write(buf),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            PalworldEncoder::None(encoder) => encoder.flush(),
+            PalworldEncoder::Zlib(encoder) => encoder.flush(),
+            PalworldEncoder::ZlibTwice(encoder) => encoder.flush(),
+        }
+    }
+}
+
+enum PalworldWrapState<'a> {
+    /// Nothing written after the header yet, the data matching the original compressed data
+    Original {
+        writer: &'a mut dyn WriteSeek,
+        original: &'a CompressedData,
+        decoder: Box<dyn Read + 'a>,
+        buffer: Vec<u8>,
+    },
+    Encoding(PalworldEncoder<'a>),
+    Finished,
+}
+
+/// GVAS data compressed into a [`Palworld`] container while it is written
+///
+/// The header is written with placeholder lengths first, and updated once the data is written.
+/// While the data matches the compressed data the file was read with, nothing is compressed.
+/// Only if it differs, the matching part is decompressed again and compressed along with the rest.
+/// The kept compressed data is held in memory along with the file, see
+/// [`Palworld::keep_compressed_data`].
+struct PalworldWrapStream<'a> {
+    state: PalworldWrapState<'a>,
+    compression_type: PalworldCompressionType,
+    chunk: Option<PalworldChunk>,
+    level: Compression,
+    header_position: u64,
+    data_position: u64,
+    length: u64,
+}
+
+impl<'a> PalworldWrapStream<'a> {
+    fn new(
+        game_version: &'a DeserializedGameVersion,
+        mut writer: &'a mut dyn WriteSeek,
+    ) -> Result<Self, Error> {
+        let (compression_type, chunk, zlib) = palworld_parameters(game_version)?;
+        let level = match compression_type {
+            PalworldCompressionType::None => Compression::none(),
+            _ => zlib_level(zlib.level)?,
+        };
+
+        let header_position = writer.stream_position()?;
+        write_palworld_header(&mut writer, 0, 0, PLZ_MAGIC, chunk)?;
+        writer.write_enum(compression_type)?;
+        let data_position = writer.stream_position()?;
+
+        let state = match (compression_type, &zlib.original) {
+            (PalworldCompressionType::None, _) | (_, None) => {
+                PalworldWrapState::Encoding(Self::encoder(compression_type, level, writer))
+            }
+            (_, Some(original)) => PalworldWrapState::Original {
+                writer,
+                original,
+                decoder: Self::decoder(compression_type, original),
+                buffer: Vec::new(),
+            },
+        };
+
+        Ok(PalworldWrapStream {
+            state,
+            compression_type,
+            chunk,
+            level,
+            header_position,
+            data_position,
+            length: 0,
+        })
+    }
+
+    #[inline]
+    fn encoder(
+        compression_type: PalworldCompressionType,
+        level: Compression,
+        writer: &'a mut dyn WriteSeek,
+    ) -> PalworldEncoder<'a> {
+        match compression_type {
+            PalworldCompressionType::None => PalworldEncoder::None(writer),
+            PalworldCompressionType::Zlib => PalworldEncoder::Zlib(ZlibEncoder::new(writer, level)),
+            PalworldCompressionType::ZlibTwice => PalworldEncoder::ZlibTwice(ZlibEncoder::new(
+                Counter {
+                    writer: ZlibEncoder::new(writer, level),
+                    count: 0,
+                },
+                level,
+            )),
+        }
+    }
+
+    #[inline]
+    fn decoder(
+        compression_type: PalworldCompressionType,
+        original: &'a CompressedData,
+    ) -> Box<dyn Read + 'a> {
+        match compression_type {
+            PalworldCompressionType::ZlibTwice => {
+                Box::new(ZlibDecoder::new(ZlibDecoder::new(&original.data[..])))
+            }
+            _ => Box::new(ZlibDecoder::new(&original.data[..])),
+        }
+    }
+
+    /// Switches from matching the original data to compressing, compressing the data matched
+    /// so far first
+    fn start_encoding(&mut self) -> io::Result<()> {
+        if let PalworldWrapState::Original {
+            writer, original, ..
+        } = mem::replace(&mut self.state, PalworldWrapState::Finished)
+        {
+            let mut encoder = Self::encoder(self.compression_type, self.level, writer);
+            let mut decoder = Self::decoder(self.compression_type, original).take(self.length);
+            if io::copy(&mut decoder, &mut encoder)? != self.length {
+                Err(io::Error::from(io::ErrorKind::UnexpectedEof))?
+            }
+            self.state = PalworldWrapState::Encoding(encoder);
+        }
+        Ok(())
+    }
+}
+
+impl Write for PalworldWrapStream<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let PalworldWrapState::Original {
+            decoder, buffer, ..
+        } = &mut self.state
+        {
+            buffer.resize(buf.len(), 0);
+            if decoder.read_exact(buffer).is_ok() && buffer == buf {
+                self.length += buf.len() as u64;
+                return Ok(buf.len());
+            }
+            self.start_encoding()?;
+        }
+
+        match &mut self.state {
+            PalworldWrapState::Encoding(encoder) => {
+                let written = encoder.write(buf)?;
+                self.length += written as u64;
+                Ok(written)
+            }
+            _ => Err(io::Error::other("Palworld stream already finished")),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.state {
+            PalworldWrapState::Encoding(encoder) => encoder.flush(),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl WrapStream for PalworldWrapStream<'_> {
+    fn finish(mut self: Box<Self>) -> Result<(), Error> {
+        if let PalworldWrapState::Original { decoder, .. } = &mut self.state {
+            if !matches!(decoder.read(&mut [0]), Ok(0)) {
+                self.start_encoding()?;
+            }
+        }
+
+        // For ZlibTwice the compressed length is the length after the first pass.
+        let (mut writer, compressed_length) =
+            match mem::replace(&mut self.state, PalworldWrapState::Finished) {
+                PalworldWrapState::Original {
+                    writer, original, ..
+                } => {
+                    writer.write_all(&original.data)?;
+                    (writer, u64::from(original.compressed_length))
+                }
+                PalworldWrapState::Encoding(PalworldEncoder::None(writer)) => (writer, self.length),
+                PalworldWrapState::Encoding(PalworldEncoder::Zlib(encoder)) => {
+                    let writer = encoder.finish()?;
+                    let compressed_length = writer.stream_position()? - self.data_position;
+                    (writer, compressed_length)
+                }
+                PalworldWrapState::Encoding(PalworldEncoder::ZlibTwice(encoder)) => {
+                    let once = encoder.finish()?;
+                    (once.writer.finish()?, once.count)
+                }
+                PalworldWrapState::Finished => {
+                    Err(io::Error::other("Palworld stream already finished"))?
+                }
+            };
+
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(self.header_position))?;
+        write_palworld_header(
+            &mut writer,
+            self.length as usize,
+            compressed_length as usize,
+            PLZ_MAGIC,
+            self.chunk,
+        )?;
+        writer.seek(SeekFrom::Start(end))?;
+        Ok(())
+    }
+}
+
+#[inline]
+fn zlib_level(level: Option<u32>) -> Result<Compression, Error> {
+    match level.unwrap_or(ZlibParameters::DEFAULT_LEVEL) {
+        level @ 0..=9 => Ok(Compression::new(level)),
+        level => Err(SerializeError::invalid_value(format!(
+            "Invalid compression level {level}"
+        )))?,
+    }
+}
+
+/// Compression codec for [`PalworldOodle`]
+///
+/// Palworld compresses `PlM` saves with Oodle, which this crate doesn't bundle. Implement this
+/// trait with the Oodle library at hand to read and write such saves.
+pub trait PalworldCodec {
+    /// Compresses `data`
+    ///
+    /// # Errors
+    ///
+    /// If compression fails
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Decompresses `data` to `decompressed_length` bytes
+    ///
+    /// # Errors
+    ///
+    /// If `data` is invalid
+    fn decompress(&self, data: &[u8], decompressed_length: usize) -> Result<Vec<u8>, Error>;
+}
+
+/// Palworld `PlM` container
+///
+/// The same header as [`Palworld`], with the `PlM` magic and data compressed by a
+/// [`PalworldCodec`]. The compression type byte is kept as metadata, `0x30` meaning uncompressed
+/// data, followed by the save type byte of the `CNK` header if there is one. If the lengths of the
+/// `CNK` header differ from the ones of the `PlM` header, they follow as two little endian `u32`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gvas::{
+///     container::{PalworldCodec, PalworldOodle},
+///     error::Error,
+///     GvasFile,
+/// };
+/// use std::{collections::HashMap, fs::File};
+///
+/// struct Oodle;
+///
+/// impl PalworldCodec for Oodle {
+///     fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+///         // Call into the Oodle library here
+///         Ok(data.to_vec())
+///     }
+///
+///     fn decompress(&self, data: &[u8], _decompressed_length: usize) -> Result<Vec<u8>, Error> {
+///         // Call into the Oodle library here
+///         Ok(data.to_vec())
+///     }
+/// }
+///
+/// let container = PalworldOodle::new(Oodle);
+/// let mut file = File::open("Level.sav")?;
+/// let gvas_file = GvasFile::read_with_containers(&mut file, &[&container], &HashMap::new())?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct PalworldOodle<C: PalworldCodec> {
+    /// Codec used for the compressed data
+    pub codec: C,
+}
+
+impl<C: PalworldCodec> PalworldOodle<C> {
+    /// Name of the container, stored in [`DeserializedGameVersion::Custom`]
+    pub const NAME: &'static str = "PalworldOodle";
+
+    /// Creates a new `PalworldOodle` instance
+    #[inline]
+    pub fn new(codec: C) -> Self {
+        PalworldOodle { codec }
+    }
+}
+
+impl<C: PalworldCodec> SaveContainer for PalworldOodle<C> {
+    #[inline]
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    #[inline]
+    fn detect(&self, prefix: &[u8]) -> bool {
+        palworld_magic(prefix) == Some(PLM_MAGIC)
+    }
+
+    fn unwrap(
+        &self,
+        mut reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let (decompresed_length, compressed_length, chunk) =
+            read_palworld_header(&mut reader, PLM_MAGIC, limits)?;
+        let compression_type = reader.read_u8()?;
+
+        let data = match compression_type {
+            PLM_UNCOMPRESSED => reader.read_bytes(decompresed_length as usize)?,
+            _ => {
+                let mut compressed = Vec::new();
+                (&mut reader)
+                    .take(compressed_length.into())
+                    .read_to_end(&mut compressed)?;
+                if compressed.len() != compressed_length as usize {
+                    Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof))?
+                }
+                let data = self
+                    .codec
+                    .decompress(&compressed, decompresed_length as usize)?;
+                if data.len() != decompresed_length as usize {
+                    Err(DeserializeError::InvalidValueSize(
+                        decompresed_length.into(),
+                        data.len() as u64,
+                        reader.stream_position()?,
+                    ))?
+                }
+                data
+            }
+        };
+
+        let mut metadata = vec![compression_type];
+        if let Some(chunk) = chunk {
+            metadata.push(chunk.chunk_type);
+            if chunk.decompressed_length.is_some() || chunk.compressed_length.is_some() {
+                let lengths = [
+                    chunk.decompressed_length.unwrap_or(decompresed_length),
+                    chunk.compressed_length.unwrap_or(compressed_length),
+                ];
+                metadata.extend(lengths.into_iter().flat_map(u32::to_le_bytes));
+            }
+        }
+        Ok((data, DeserializedGameVersion::custom(Self::NAME, metadata)))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        mut writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let (compression_type, chunk) = match game_version {
+            DeserializedGameVersion::Custom {
+                container,
+                metadata,
+            } if container == Self::NAME => match metadata[..] {
+                [compression_type] => (compression_type, None),
+                [compression_type, chunk_type] => {
+                    (compression_type, Some(PalworldChunk::new(chunk_type)))
+                }
+                [compression_type, chunk_type, d0, d1, d2, d3, c0, c1, c2, c3] => (
+                    compression_type,
+                    Some(PalworldChunk {
+                        chunk_type,
+                        decompressed_length: Some(u32::from_le_bytes([d0, d1, d2, d3])),
+                        compressed_length: Some(u32::from_le_bytes([c0, c1, c2, c3])),
+                    }),
+                ),
+                _ => Err(SerializeError::invalid_value(format!(
+                    "Invalid {} metadata {metadata:?}",
+                    Self::NAME
+                )))?,
+            },
+            _ => Err(SerializeError::invalid_value(format!(
+                "{} can't write {game_version:?}",
+                Self::NAME
+            )))?,
+        };
+
+        let data = match compression_type {
+            PLM_UNCOMPRESSED => payload.to_vec(),
+            _ => self.codec.compress(payload)?,
+        };
+
+        write_palworld_header(&mut writer, payload.len(), data.len(), PLM_MAGIC, chunk)?;
+        writer.write_u8(compression_type)?;
+        writer.write_all(&data)?;
+        Ok(())
+    }
+}
+
+/// Unreal `FArchive::SerializeCompressed` container
+///
+/// The package file tag and chunk size, the compressed and uncompressed size of the whole stream
+/// and a table with the sizes of every chunk, followed by one zlib stream per chunk.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SerializeCompressed;
+
+impl SerializeCompressed {
+    /// Default maximum uncompressed size of a chunk
+    pub const DEFAULT_CHUNK_SIZE: u64 = 0x20000;
+}
+
+impl SaveContainer for SerializeCompressed {
+    #[inline]
+    fn name(&self) -> &str {
+        "SerializeCompressed"
+    }
+
+    #[inline]
+    fn detect(&self, prefix: &[u8]) -> bool {
+        prefix.starts_with(&PACKAGE_FILE_TAG.to_le_bytes())
+    }
+
+    fn unwrap(
+        &self,
+        mut reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let (tag, chunk_size) = read_chunk_info(&mut reader)?;
+        if tag != PACKAGE_FILE_TAG {
+            Err(DeserializeError::InvalidHeader(
+                format!("Invalid package file tag {tag:#x}").into_boxed_str(),
+            ))?
+        }
+        if chunk_size == 0 {
+            Err(DeserializeError::InvalidHeader("Chunk size 0".into()))?
+        }
+
+        let (_compressed_size, uncompressed_size) = read_chunk_info(&mut reader)?;
+        limits.check_decompressed_size(uncompressed_size, &mut reader)?;
+        let chunk_count = uncompressed_size.div_ceil(chunk_size);
+        limits.check_element_count(u32::try_from(chunk_count).unwrap_or(u32::MAX), &mut reader)?;
+
+        let mut chunks = Vec::with_capacity(bounded_capacity(chunk_count as usize));
+        for _ in 0..chunk_count {
+            chunks.push(read_chunk_info(&mut reader)?);
+        }
+
+        let mut data = Vec::with_capacity(bounded_capacity(uncompressed_size as usize));
+        let mut level = None;
+        for (compressed_size, chunk_uncompressed_size) in chunks {
+            if chunk_uncompressed_size > chunk_size {
+                Err(DeserializeError::InvalidValueSize(
+                    chunk_size,
+                    chunk_uncompressed_size,
+                    reader.stream_position()?,
+                ))?
+            }
+            let compressed = reader.read_bytes(compressed_size as usize)?;
+            if data.is_empty() {
+                level = ZlibParameters::detect(&compressed).level;
+            }
+            let start = data.len();
+            ZlibDecoder::new(&compressed[..])
+                .take(chunk_uncompressed_size)
+                .read_to_end(&mut data)?;
+            if (data.len() - start) as u64 != chunk_uncompressed_size {
+                Err(DeserializeError::InvalidValueSize(
+                    chunk_uncompressed_size,
+                    (data.len() - start) as u64,
+                    reader.stream_position()?,
+                ))?
+            }
+        }
+        if data.len() as u64 != uncompressed_size {
+            Err(DeserializeError::InvalidValueSize(
+                uncompressed_size,
+                data.len() as u64,
+                reader.stream_position()?,
+            ))?
+        }
+
+        Ok((
+            data,
+            DeserializedGameVersion::SerializeCompressed { chunk_size, level },
+        ))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        mut writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let DeserializedGameVersion::SerializeCompressed { chunk_size, level } = *game_version
+        else {
+            Err(SerializeError::invalid_value(format!(
+                "SerializeCompressed can't write {game_version:?}"
+            )))?
+        };
+        if chunk_size == 0 {
+            Err(SerializeError::invalid_value("Chunk size 0"))?
+        }
+
+        let level = zlib_level(level)?;
+        let chunks = payload
+            .chunks(chunk_size as usize)
+            .map(|chunk| Ok((compress(chunk, level)?, chunk.len())))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let compressed_size = chunks.iter().map(|(data, _)| data.len() as u64).sum();
+
+        write_chunk_info(&mut writer, PACKAGE_FILE_TAG, chunk_size)?;
+        write_chunk_info(&mut writer, compressed_size, payload.len() as u64)?;
+        for (data, uncompressed_size) in &chunks {
+            write_chunk_info(&mut writer, data.len() as u64, *uncompressed_size as u64)?;
+        }
+        for (data, _) in &chunks {
+            writer.write_all(data)?;
+        }
+        Ok(())
+    }
+}
+
+/// AES-256-ECB container
+///
+/// The GVAS data encrypted with a key compiled into the game, padded to the AES block size. The
+/// padding scheme is kept as metadata, [`AesEcb::ZERO_PADDING`] or [`AesEcb::PKCS7_PADDING`], so
+/// the file is written back with the same padding.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gvas::{container::AesEcb, error::Error, GvasFile};
+/// use std::{collections::HashMap, fs::File};
+///
+/// let container = AesEcb::new([0x42; 32]);
+/// let mut file = File::open("save.sav")?;
+/// let gvas_file = GvasFile::read_with_containers(&mut file, &[&container], &HashMap::new())?;
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "aes")]
+#[derive(Clone)]
+pub struct AesEcb {
+    cipher: aes::Aes256,
+}
+
+#[cfg(feature = "aes")]
+impl AesEcb {
+    /// Name of the container, stored in [`DeserializedGameVersion::Custom`]
+    pub const NAME: &'static str = "AesEcb";
+    /// Padding with zero bytes up to the next block
+    pub const ZERO_PADDING: u8 = 0;
+    /// PKCS#7 padding, always adding between 1 and 16 bytes
+    pub const PKCS7_PADDING: u8 = 1;
+
+    const BLOCK_SIZE: usize = 16;
+
+    /// Creates a new `AesEcb` instance
+    #[inline]
+    pub fn new(key: [u8; 32]) -> Self {
+        use aes::cipher::KeyInit;
+        AesEcb {
+            cipher: aes::Aes256::new(&key.into()),
+        }
+    }
+
+    /// Returns the padding scheme and length of decrypted `data`
+    ///
+    /// GVAS data ends with zero bytes, so PKCS#7 padding is recognized by its non-zero bytes.
+    /// Zero padding is whatever follows the `None` terminator and the zero after it.
+    fn padding(data: &[u8]) -> (u8, usize) {
+        if let Some(&last) = data.last() {
+            let length = last as usize;
+            if (1..=Self::BLOCK_SIZE).contains(&length)
+                && data.len() >= length
+                && data[data.len() - length..].iter().all(|&b| b == last)
+            {
+                return (Self::PKCS7_PADDING, length);
+            }
+        }
+        let zeros = data.iter().rev().take_while(|&&b| b == 0).count();
+        // The terminator of "None" and the u32 following it
+        let length = zeros.saturating_sub(5).min(Self::BLOCK_SIZE - 1);
+        (Self::ZERO_PADDING, length)
+    }
+}
+
+#[cfg(feature = "aes")]
+impl std::fmt::Debug for AesEcb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AesEcb").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "aes")]
+impl SaveContainer for AesEcb {
+    #[inline]
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn detect(&self, prefix: &[u8]) -> bool {
+        use aes::cipher::BlockDecrypt;
+        let Some(block) = prefix.get(..Self::BLOCK_SIZE) else {
+            return false;
+        };
+        let mut block = aes::Block::clone_from_slice(block);
+        self.cipher.decrypt_block(&mut block);
+        block.starts_with(b"GVAS")
+    }
+
+    fn unwrap(
+        &self,
+        mut reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        use aes::cipher::BlockDecrypt;
+
+        let mut data = Vec::new();
+        (&mut reader)
+            .take(limits.max_decompressed_size + 1)
+            .read_to_end(&mut data)?;
+        limits.check_decompressed_size(data.len() as u64, &mut reader)?;
+        if data.len() % Self::BLOCK_SIZE != 0 {
+            Err(DeserializeError::InvalidHeader(
+                format!(
+                    "Encrypted size {} is not a multiple of the block size",
+                    data.len()
+                )
+                .into_boxed_str(),
+            ))?
+        }
+
+        for block in data.chunks_exact_mut(Self::BLOCK_SIZE) {
+            self.cipher.decrypt_block(block.into());
+        }
+
+        let (padding, length) = Self::padding(&data);
+        data.truncate(data.len() - length);
+        Ok((
+            data,
+            DeserializedGameVersion::custom(Self::NAME, vec![padding]),
+        ))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        use aes::cipher::BlockEncrypt;
+
+        let padding = match game_version {
+            DeserializedGameVersion::Custom {
+                container,
+                metadata,
+            } if container == Self::NAME => match metadata[..] {
+                [padding @ (Self::ZERO_PADDING | Self::PKCS7_PADDING)] => padding,
+                _ => Err(SerializeError::invalid_value(format!(
+                    "Invalid {} metadata {metadata:?}",
+                    Self::NAME
+                )))?,
+            },
+            _ => Err(SerializeError::invalid_value(format!(
+                "{} can't write {game_version:?}",
+                Self::NAME
+            )))?,
+        };
+
+        let mut data = payload.to_vec();
+        let remainder = Self::BLOCK_SIZE - data.len() % Self::BLOCK_SIZE;
+        match padding {
+            Self::PKCS7_PADDING => data.resize(data.len() + remainder, remainder as u8),
+            _ => data.resize(data.len() + remainder % Self::BLOCK_SIZE, 0),
+        }
+
+        for block in data.chunks_exact_mut(Self::BLOCK_SIZE) {
+            self.cipher.encrypt_block(block.into());
+        }
+        writer.write_all(&data)?;
+        Ok(())
+    }
+}
+
+/// Checksum algorithm used by [`Checksum`]
+#[cfg(feature = "checksum")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32, stored as a little endian u32
+    Crc32,
+    /// MD5
+    Md5,
+    /// SHA-1
+    Sha1,
+}
+
+#[cfg(feature = "checksum")]
+impl ChecksumAlgorithm {
+    /// Size of the checksum in bytes
+    #[inline]
+    pub fn size(self) -> usize {
+        match self {
+            ChecksumAlgorithm::Crc32 => 4,
+            ChecksumAlgorithm::Md5 => 16,
+            ChecksumAlgorithm::Sha1 => 20,
+        }
+    }
+
+    /// Computes the checksum of `data`
+    pub fn compute(self, data: &[u8]) -> Vec<u8> {
+        use sha1::Digest;
+        match self {
+            ChecksumAlgorithm::Crc32 => {
+                let mut crc = flate2::Crc::new();
+                crc.update(data);
+                crc.sum().to_le_bytes().to_vec()
+            }
+            ChecksumAlgorithm::Md5 => md5::Md5::digest(data).to_vec(),
+            ChecksumAlgorithm::Sha1 => sha1::Sha1::digest(data).to_vec(),
+        }
+    }
+}
+
+/// Position of the checksum stored by [`Checksum`]
+#[cfg(feature = "checksum")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChecksumPosition {
+    /// Before the GVAS data
+    Prefix,
+    /// After the GVAS data, not verified when detecting the container, see [`Checksum`]
+    Suffix,
+}
+
+/// Checksum container
+///
+/// The GVAS data with a checksum of it stored before or after it. The checksum is verified when
+/// reading, and recomputed over the new data when writing.
+///
+/// [`SaveContainer::detect`] only sees the start of the file, so a checksum after the data isn't
+/// verified when detecting the container. With [`ChecksumPosition::Suffix`] every file starting
+/// with `GVAS` is claimed, including plain GVAS files, so such a container must not be used for
+/// auto-detection among files that may not have a checksum.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gvas::{
+///     container::{Checksum, ChecksumAlgorithm, ChecksumPosition},
+///     error::Error,
+///     GvasFile,
+/// };
+/// use std::{collections::HashMap, fs::File};
+///
+/// let container = Checksum::new(ChecksumAlgorithm::Md5, ChecksumPosition::Suffix);
+/// let mut file = File::open("save.sav")?;
+/// let (gvas_file, diagnostics) =
+///     GvasFile::read_lenient_with_containers(&mut file, &[&container], &HashMap::new())?;
+/// for diagnostic in diagnostics {
+///     println!("{}", diagnostic.error);
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "checksum")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    /// Checksum algorithm
+    pub algorithm: ChecksumAlgorithm,
+    /// Position of the checksum
+    pub position: ChecksumPosition,
+    /// Number of bytes at the start of the GVAS data the checksum doesn't cover
+    pub skip_start: usize,
+    /// Number of bytes at the end of the GVAS data the checksum doesn't cover
+    pub skip_end: usize,
+}
+
+#[cfg(feature = "checksum")]
+impl Checksum {
+    /// Name of the container, stored in [`DeserializedGameVersion::Custom`]
+    pub const NAME: &'static str = "Checksum";
+
+    /// Creates a new `Checksum` instance covering all of the GVAS data
+    #[inline]
+    pub fn new(algorithm: ChecksumAlgorithm, position: ChecksumPosition) -> Self {
+        Checksum {
+            algorithm,
+            position,
+            skip_start: 0,
+            skip_end: 0,
+        }
+    }
+
+    /// Returns the part of `data` covered by the checksum
+    #[inline]
+    fn covered<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        data.get(self.skip_start..data.len().checked_sub(self.skip_end)?)
+    }
+
+    /// Reads the container, returning the checksum mismatch separately
+    fn read_checked(
+        &self,
+        mut reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, Option<Diagnostic>), Error> {
+        let mut data = Vec::new();
+        (&mut reader)
+            .take(limits.max_decompressed_size + 1)
+            .read_to_end(&mut data)?;
+        limits.check_decompressed_size(data.len() as u64, &mut reader)?;
+
+        let length = self.algorithm.size();
+        if data.len() < length {
+            Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof))?
+        }
+        let (position, stored) = match self.position {
+            ChecksumPosition::Prefix => (0, data.drain(..length).collect::<Vec<_>>()),
+            ChecksumPosition::Suffix => (data.len() - length, data.split_off(data.len() - length)),
+        };
+
+        let Some(covered) = self.covered(&data) else {
+            Err(DeserializeError::InvalidHeader(
+                format!("Checksum range exceeds the {} bytes of data", data.len()).into_boxed_str(),
+            ))?
+        };
+        let computed = self.algorithm.compute(covered);
+        let diagnostic = match stored == computed {
+            true => None,
+            false => Some(Diagnostic {
+                path: String::new(),
+                property_type: String::new(),
+                position: position as u64,
+                error: DeserializeError::ChecksumMismatch(
+                    to_hex(&stored).into_boxed_str(),
+                    to_hex(&computed).into_boxed_str(),
+                    position as u64,
+                )
+                .into(),
+            }),
+        };
+        Ok((data, diagnostic))
+    }
+}
+
+#[cfg(feature = "checksum")]
+impl SaveContainer for Checksum {
+    #[inline]
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn detect(&self, prefix: &[u8]) -> bool {
+        let offset = match self.position {
+            ChecksumPosition::Prefix => self.algorithm.size(),
+            ChecksumPosition::Suffix => 0,
+        };
+        prefix
+            .get(offset..)
+            .is_some_and(|data| data.starts_with(b"GVAS"))
+    }
+
+    fn unwrap(
+        &self,
+        reader: &mut dyn ReadSeek,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let (data, diagnostic) = self.read_checked(reader, limits)?;
+        if let Some(diagnostic) = diagnostic {
+            Err(diagnostic.error)?
+        }
+        Ok((
+            data,
+            DeserializedGameVersion::custom(Self::NAME, Vec::new()),
+        ))
+    }
+
+    fn unwrap_lenient(
+        &self,
+        reader: &mut dyn ReadSeek,
+        limits: &Limits,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let (data, diagnostic) = self.read_checked(reader, limits)?;
+        diagnostics.extend(diagnostic);
+        Ok((
+            data,
+            DeserializedGameVersion::custom(Self::NAME, Vec::new()),
+        ))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        if game_version.container_name() != Some(Self::NAME) {
+            Err(SerializeError::invalid_value(format!(
+                "{} can't write {game_version:?}",
+                Self::NAME
+            )))?
+        }
+        let Some(covered) = self.covered(payload) else {
+            Err(SerializeError::invalid_value(format!(
+                "Checksum range exceeds the {} bytes of data",
+                payload.len()
+            )))?
+        };
+
+        let checksum = self.algorithm.compute(covered);
+        match self.position {
+            ChecksumPosition::Prefix => {
+                writer.write_all(&checksum)?;
+                writer.write_all(payload)?;
+            }
+            ChecksumPosition::Suffix => {
+                writer.write_all(payload)?;
+                writer.write_all(&checksum)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "checksum")]
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Reads the compressed and uncompressed size of a `FCompressedChunkInfo`
+#[inline]
+fn read_chunk_info<R: Read + Seek>(reader: &mut R) -> Result<(u64, u64), Error> {
+    Ok((read_chunk_size(reader)?, read_chunk_size(reader)?))
+}
+
+#[inline]
+fn read_chunk_size<R: Read + Seek>(reader: &mut R) -> Result<u64, Error> {
+    let size = reader.read_i64::<LittleEndian>()?;
+    match u64::try_from(size) {
+        Ok(size) => Ok(size),
+        Err(_) => Err(DeserializeError::InvalidHeader(
+            format!(
+                "Invalid chunk size {size} at position {:#x}",
+                reader.stream_position()?
+            )
+            .into_boxed_str(),
+        ))?,
+    }
+}
+
+/// Writes the compressed and uncompressed size of a `FCompressedChunkInfo`
+#[inline]
+fn write_chunk_info<W: Write>(
+    writer: &mut W,
+    compressed_size: u64,
+    uncompressed_size: u64,
+) -> Result<(), Error> {
+    writer.write_i64::<LittleEndian>(compressed_size as i64)?;
+    writer.write_i64::<LittleEndian>(uncompressed_size as i64)?;
+    Ok(())
+}
+
+/// Reads the lengths and magic of a Palworld header, and the save type of a `CNK` header before it
+fn read_palworld_header<R: Read + Seek>(
+    reader: &mut R,
+    expected_magic: &[u8; 3],
+    limits: &Limits,
+) -> Result<(u32, u32, Option<PalworldChunk>), Error> {
+    let (mut decompresed_length, mut compressed_length, mut magic) =
+        read_palworld_lengths(reader, limits)?;
+
+    let chunk = match &magic == CNK_MAGIC {
+        true => {
+            let chunk_type = reader.read_u8()?;
+            let (chunk_decompresed_length, chunk_compressed_length) =
+                (decompresed_length, compressed_length);
+            (decompresed_length, compressed_length, magic) = read_palworld_lengths(reader, limits)?;
+            Some(PalworldChunk {
+                chunk_type,
+                decompressed_length: Some(chunk_decompresed_length)
+                    .filter(|length| *length != decompresed_length),
+                compressed_length: Some(chunk_compressed_length)
+                    .filter(|length| *length != compressed_length),
+            })
+        }
+        false => None,
+    };
+
+    if &magic != expected_magic {
+        Err(DeserializeError::InvalidHeader(
+            format!(
+                "Invalid {} magic {magic:?}",
+                String::from_utf8_lossy(expected_magic)
+            )
+            .into_boxed_str(),
+        ))?
+    }
+
+    Ok((decompresed_length, compressed_length, chunk))
+}
+
+#[inline]
+fn read_palworld_lengths<R: Read + Seek>(
+    reader: &mut R,
+    limits: &Limits,
+) -> Result<(u32, u32, [u8; 3]), Error> {
+    let decompresed_length = reader.read_u32::<LittleEndian>()?;
+    limits.check_decompressed_size(decompresed_length.into(), reader)?;
+    let compressed_length = reader.read_u32::<LittleEndian>()?;
+    let mut magic = [0u8; 3];
+    reader.read_exact(&mut magic)?;
+    Ok((decompresed_length, compressed_length, magic))
+}
+
+/// Writes the lengths and magic of a Palworld header, preceded by a `CNK` header if `chunk` is
+/// set
+fn write_palworld_header<W: Write>(
+    writer: &mut W,
+    decompresed_length: usize,
+    compressed_length: usize,
+    magic: &[u8; 3],
+    chunk: Option<PalworldChunk>,
+) -> Result<(), Error> {
+    if let Some(chunk) = chunk {
+        write_palworld_header(
+            writer,
+            chunk
+                .decompressed_length
+                .map_or(decompresed_length, |length| length as usize),
+            chunk
+                .compressed_length
+                .map_or(compressed_length, |length| length as usize),
+            CNK_MAGIC,
+            None,
+        )?;
+        writer.write_u8(chunk.chunk_type)?;
+    }
+    writer.write_u32::<LittleEndian>(decompresed_length as u32)?;
+    writer.write_u32::<LittleEndian>(compressed_length as u32)?;
+    writer.write_all(magic)?;
+    Ok(())
+}
+
+#[inline]
+fn compress(data: &[u8], level: Compression) -> Result<Vec<u8>, Error> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), level);
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
@@ -1,18 +1,34 @@
-use std::io::{Read, Seek, Write};
+use std::io::{self, Read, Seek, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
     error::{DeserializeError, Error},
+    limits::Limits,
     types::Guid,
 };
 
+/// Upper bound on how many elements are preallocated for a count read from the stream.
+const MAX_PREALLOCATION: usize = 4096;
+
+/// Limits a count read from the stream to a sane preallocation size.
+#[inline]
+pub(crate) fn bounded_capacity(count: usize) -> usize {
+    count.min(MAX_PREALLOCATION)
+}
+
 /// Extensions for `Read`.
 pub trait ReadExt {
     /// Reads a GVAS string.
     fn read_string(&mut self) -> Result<String, Error>;
     /// Reads a GVAS string.
     fn read_fstring(&mut self) -> Result<Option<String>, Error>;
+    /// Reads a GVAS string of at most `max_length` characters.
+    fn read_string_limited(&mut self, max_length: u32) -> Result<String, Error>;
+    /// Reads a GVAS string of at most `max_length` characters.
+    fn read_fstring_limited(&mut self, max_length: u32) -> Result<Option<String>, Error>;
+    /// Reads `len` raw bytes, growing the buffer as data arrives instead of trusting `len` upfront.
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, Error>;
     /// Reads a GUID.
     fn read_guid(&mut self) -> Result<Guid, Error>;
     /// Reads an 8bit boolean value.
@@ -46,26 +62,42 @@ pub trait WriteExt {
 impl<R: Read + Seek> ReadExt for R {
     #[inline]
     fn read_string(&mut self) -> Result<String, Error> {
-        match self.read_fstring()? {
+        self.read_string_limited(Limits::DEFAULT.max_string_length)
+    }
+
+    #[inline]
+    fn read_fstring(&mut self) -> Result<Option<String>, Error> {
+        self.read_fstring_limited(Limits::DEFAULT.max_string_length)
+    }
+
+    #[inline]
+    fn read_string_limited(&mut self, max_length: u32) -> Result<String, Error> {
+        match self.read_fstring_limited(max_length)? {
             Some(str) => Ok(str),
             None => Err(DeserializeError::InvalidString(0, self.stream_position()?))?,
         }
     }
 
-    fn read_fstring(&mut self) -> Result<Option<String>, Error> {
+    fn read_fstring_limited(&mut self, max_length: u32) -> Result<Option<String>, Error> {
         let start_position = self.stream_position()?;
         let len = self.read_i32::<LittleEndian>()?;
-
-        if !(-131072..=131072).contains(&len) {
-            Err(DeserializeError::InvalidString(
-                len,
-                self.stream_position()?,
+        let chars = len.unsigned_abs();
+
+        if chars > max_length {
+            Err(DeserializeError::limit_exceeded(
+                "string length",
+                chars.into(),
+                max_length.into(),
+                self,
             ))?
         } else if len == 0 {
             Ok(None)
         } else if len < 0 {
-            let mut buf = vec![0u16; -len as usize - 1];
-            self.read_u16_into::<LittleEndian>(&mut buf)?;
+            let bytes = self.read_bytes((chars as usize - 1) * 2)?;
+            let buf: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
 
             let terminator = self.read_u16::<LittleEndian>()?;
             if terminator != 0 {
@@ -80,8 +112,7 @@ impl<R: Read + Seek> ReadExt for R {
 
             Ok(Some(string))
         } else {
-            let mut buf = vec![0u8; len as usize - 1];
-            self.read_exact(&mut buf)?;
+            let buf = self.read_bytes(chars as usize - 1)?;
 
             let terminator = self.read_u8()?;
             if terminator != 0 {
@@ -98,6 +129,16 @@ impl<R: Read + Seek> ReadExt for R {
         }
     }
 
+    #[inline]
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::with_capacity(bounded_capacity(len));
+        self.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            Err(io::Error::from(io::ErrorKind::UnexpectedEof))?
+        }
+        Ok(buf)
+    }
+
     #[inline]
     fn read_guid(&mut self) -> Result<Guid, Error> {
         let mut guid = Guid::default();
@@ -3,7 +3,7 @@
 use crate::cursor_ext::{ReadExt, WriteExt};
 use crate::engine_version::EngineVersion;
 use crate::error::Error;
-use crate::types::Guid;
+use crate::types::{map::HashableIndexMap, Guid};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use num_enum::IntoPrimitive;
 use std::io::{Read, Seek, Write};
@@ -55,6 +55,54 @@ pub trait CustomVersionTrait {
     const FRIENDLY_NAME: &'static str;
     /// Custom version guid
     const GUID: Guid;
+
+    /// Returns the version number of this custom version in files saved by `engine_version`
+    ///
+    /// Engine versions newer than the newest mapping resolve to the newest mapping, and engine
+    /// versions older than all mappings resolve to `None`.
+    fn version_for(engine_version: EngineVersion) -> Option<i32> {
+        Self::VERSION_MAPPINGS
+            .iter()
+            .filter(|(version, _)| *version <= engine_version)
+            .max_by_key(|(version, _)| *version)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Returns the custom versions known to this crate, as saved by `engine_version`
+///
+/// Custom versions that didn't exist yet in `engine_version` are left out.
+pub(crate) fn custom_versions_for(engine_version: EngineVersion) -> HashableIndexMap<Guid, u32> {
+    HashableIndexMap(
+        known_custom_versions_for(engine_version)
+            .into_iter()
+            .filter_map(|(guid, version)| Some((guid, version?)))
+            .collect(),
+    )
+}
+
+/// Number of custom versions known to this crate
+pub(crate) const KNOWN_CUSTOM_VERSION_COUNT: usize = 2;
+
+/// Returns each custom version known to this crate with its version as saved by
+/// `engine_version`, `None` if it didn't exist yet
+pub(crate) fn known_custom_versions_for(
+    engine_version: EngineVersion,
+) -> [(Guid, Option<u32>); KNOWN_CUSTOM_VERSION_COUNT] {
+    [
+        (
+            FEditorObjectVersion::GUID,
+            FEditorObjectVersion::version_for(engine_version),
+        ),
+        (
+            FUE5ReleaseStreamObjectVersion::GUID,
+            FUE5ReleaseStreamObjectVersion::version_for(engine_version),
+        ),
+    ]
+    .map(|(guid, version)| match version {
+        Some(version @ 1..) => (guid, Some(version as u32)),
+        _ => (guid, None),
+    })
 }
 
 macro_rules! impl_custom_version_trait {
@@ -433,4 +481,12 @@ impl_custom_version_trait!(
     FUE5ReleaseStreamObjectVersion,
     "FUE5ReleaseStreamObjectVersion",
     Guid::from_u32([0xD89B5E42, 0x24BD4D46, 0x8412ACA8, 0xDF641779]),
+    VER_UE5_6: OrthographicAutoNearFarPlane,
+    VER_UE5_5: OrthographicAutoNearFarPlane,
+    VER_UE5_4: OrthographicAutoNearFarPlane,
+    VER_UE5_3: OpenColorIODisabledDisplayConfigurationDefault,
+    VER_UE5_2: RigVMSaveDebugMapInGraphFunctionData,
+    VER_UE5_1: LinkedAnimGraphMemberReference,
+    VER_UE5_0: SerializeFloatPinDefaultValuesAsSinglePrecision,
+    VER_UE4_OLDEST_LOADABLE_PACKAGE: BeforeCustomVersionWasAdded
 );
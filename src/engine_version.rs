@@ -1,7 +1,11 @@
 //! Engine version information
 
 use crate::cursor_ext::{ReadExt, WriteExt};
+use crate::custom_version::{known_custom_versions_for, KNOWN_CUSTOM_VERSION_COUNT};
 use crate::error::Error;
+use crate::limits::Limits;
+use crate::object_version::FPackageFileVersion;
+use crate::types::{map::HashableIndexMap, Guid};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt::Display;
 use std::io::{Read, Seek, Write};
@@ -47,12 +51,12 @@ impl FEngineVersion {
 
     /// Read FEngineVersion from a binary file
     #[inline]
-    pub(crate) fn read<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
+    pub(crate) fn read<R: Read + Seek>(cursor: &mut R, limits: &Limits) -> Result<Self, Error> {
         let major = cursor.read_u16::<LittleEndian>()?;
         let minor = cursor.read_u16::<LittleEndian>()?;
         let patch = cursor.read_u16::<LittleEndian>()?;
         let change_list = cursor.read_u32::<LittleEndian>()?;
-        let branch = cursor.read_string()?;
+        let branch = cursor.read_string_limited(limits.max_string_length)?;
         Ok(FEngineVersion {
             major,
             minor,
@@ -76,45 +80,57 @@ impl FEngineVersion {
 
     /// Get [`EngineVersion`]
     pub fn get_version(&self) -> EngineVersion {
-        match (self.major, self.minor) {
-            (4, 0) => EngineVersion::VER_UE4_0,
-            (4, 1) => EngineVersion::VER_UE4_1,
-            (4, 2) => EngineVersion::VER_UE4_2,
-            (4, 3) => EngineVersion::VER_UE4_3,
-            (4, 4) => EngineVersion::VER_UE4_4,
-            (4, 5) => EngineVersion::VER_UE4_5,
-            (4, 6) => EngineVersion::VER_UE4_6,
-            (4, 7) => EngineVersion::VER_UE4_7,
-            (4, 8) => EngineVersion::VER_UE4_8,
-            (4, 9) => EngineVersion::VER_UE4_9,
-            (4, 10) => EngineVersion::VER_UE4_10,
-            (4, 11) => EngineVersion::VER_UE4_11,
-            (4, 12) => EngineVersion::VER_UE4_12,
-            (4, 13) => EngineVersion::VER_UE4_13,
-            (4, 14) => EngineVersion::VER_UE4_14,
-            (4, 15) => EngineVersion::VER_UE4_15,
-            (4, 16) => EngineVersion::VER_UE4_16,
-            (4, 17) => EngineVersion::VER_UE4_17,
-            (4, 18) => EngineVersion::VER_UE4_18,
-            (4, 19) => EngineVersion::VER_UE4_19,
-            (4, 20) => EngineVersion::VER_UE4_20,
-            (4, 21) => EngineVersion::VER_UE4_21,
-            (4, 22) => EngineVersion::VER_UE4_22,
-            (4, 23) => EngineVersion::VER_UE4_23,
-            (4, 24) => EngineVersion::VER_UE4_24,
-            (4, 25) => EngineVersion::VER_UE4_25,
-            (4, 26) => EngineVersion::VER_UE4_26,
-            (4, 27) => EngineVersion::VER_UE4_27,
-            (5, 0) => EngineVersion::VER_UE5_0,
-            (5, 1) => EngineVersion::VER_UE5_1,
-            (5, 2) => EngineVersion::VER_UE5_2,
-            _ => EngineVersion::UNKNOWN,
-        }
+        RELEASES
+            .iter()
+            .find(|(_, major, minor, _, _)| (*major, *minor) == (self.major, self.minor))
+            .map_or(EngineVersion::UNKNOWN, |(version, ..)| *version)
     }
 }
 
+/// Releases known to this crate, with their major and minor version number and the UE4 and UE5
+/// package file version of files they save
+const RELEASES: &[(EngineVersion, u16, u16, u32, u32)] = &[
+    (EngineVersion::VER_UE4_0, 4, 0, 342, 0),
+    (EngineVersion::VER_UE4_1, 4, 1, 352, 0),
+    (EngineVersion::VER_UE4_2, 4, 2, 363, 0),
+    (EngineVersion::VER_UE4_3, 4, 3, 382, 0),
+    (EngineVersion::VER_UE4_4, 4, 4, 385, 0),
+    (EngineVersion::VER_UE4_5, 4, 5, 401, 0),
+    (EngineVersion::VER_UE4_6, 4, 6, 413, 0),
+    (EngineVersion::VER_UE4_7, 4, 7, 434, 0),
+    (EngineVersion::VER_UE4_8, 4, 8, 451, 0),
+    (EngineVersion::VER_UE4_9, 4, 9, 482, 0),
+    (EngineVersion::VER_UE4_10, 4, 10, 482, 0),
+    (EngineVersion::VER_UE4_11, 4, 11, 498, 0),
+    (EngineVersion::VER_UE4_12, 4, 12, 504, 0),
+    (EngineVersion::VER_UE4_13, 4, 13, 505, 0),
+    (EngineVersion::VER_UE4_14, 4, 14, 508, 0),
+    (EngineVersion::VER_UE4_15, 4, 15, 510, 0),
+    (EngineVersion::VER_UE4_16, 4, 16, 513, 0),
+    (EngineVersion::VER_UE4_17, 4, 17, 513, 0),
+    (EngineVersion::VER_UE4_18, 4, 18, 514, 0),
+    (EngineVersion::VER_UE4_19, 4, 19, 516, 0),
+    (EngineVersion::VER_UE4_20, 4, 20, 516, 0),
+    (EngineVersion::VER_UE4_21, 4, 21, 517, 0),
+    (EngineVersion::VER_UE4_22, 4, 22, 517, 0),
+    (EngineVersion::VER_UE4_23, 4, 23, 517, 0),
+    (EngineVersion::VER_UE4_24, 4, 24, 517, 0),
+    (EngineVersion::VER_UE4_25, 4, 25, 518, 0),
+    (EngineVersion::VER_UE4_26, 4, 26, 519, 0),
+    (EngineVersion::VER_UE4_27, 4, 27, 522, 0),
+    (EngineVersion::VER_UE5_0, 5, 0, 522, 1004),
+    (EngineVersion::VER_UE5_1, 5, 1, 522, 1008),
+    (EngineVersion::VER_UE5_2, 5, 2, 522, 1009),
+    (EngineVersion::VER_UE5_3, 5, 3, 522, 1009),
+    (EngineVersion::VER_UE5_4, 5, 4, 522, 1012),
+    (EngineVersion::VER_UE5_5, 5, 5, 522, 1013),
+    (EngineVersion::VER_UE5_6, 5, 6, 522, 1017),
+];
+
 /// UE4 Engine version enum
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+///
+/// Ordered by release, so later engine versions compare greater.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[allow(non_camel_case_types)]
 pub enum EngineVersion {
     /// Unknown
@@ -185,9 +201,114 @@ pub enum EngineVersion {
     VER_UE5_1,
     /// 5.2
     VER_UE5_2,
+    /// 5.3
+    VER_UE5_3,
+    /// 5.4
+    VER_UE5_4,
+    /// 5.5
+    VER_UE5_5,
+    /// 5.6
+    VER_UE5_6,
 
     /// The newest specified version of the Unreal Engine.
     VER_UE4_AUTOMATIC_VERSION,
     /// Version plus one
     VER_UE4_AUTOMATIC_VERSION_PLUS_ONE,
 }
+
+impl EngineVersion {
+    /// Returns the major and minor version number of a release, `None` for the other variants
+    #[inline]
+    pub fn release(self) -> Option<(u16, u16)> {
+        self.find_release()
+            .map(|(_, major, minor, _, _)| (*major, *minor))
+    }
+
+    /// Returns the package file version of files saved by a release, `None` for the other
+    /// variants
+    ///
+    /// The UE5 version is zero for UE4 releases.
+    #[inline]
+    pub fn package_file_version(self) -> Option<FPackageFileVersion> {
+        self.find_release()
+            .map(|(_, _, _, ue4, ue5)| FPackageFileVersion::new(*ue4, *ue5))
+    }
+
+    #[inline]
+    fn find_release(self) -> Option<&'static (EngineVersion, u16, u16, u32, u32)> {
+        RELEASES.iter().find(|(version, ..)| *version == self)
+    }
+}
+
+/// Range of releases that likely saved a file, estimated from its package file version and custom
+/// versions
+///
+/// See [`GvasHeader::estimate_engine_version`](crate::GvasHeader::estimate_engine_version).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EngineVersionEstimate {
+    /// Oldest release matching the versions of the file best
+    pub oldest: EngineVersion,
+    /// Newest release matching the versions of the file best
+    pub newest: EngineVersion,
+    /// Fraction of the versions of the file matched by the best releases, from 0 to 1
+    pub confidence: f32,
+}
+
+impl EngineVersionEstimate {
+    /// Checks if `engine_version` is within the estimated range
+    #[inline]
+    pub fn contains(&self, engine_version: EngineVersion) -> bool {
+        (self.oldest..=self.newest).contains(&engine_version)
+    }
+}
+
+/// Estimates the releases that saved a file with `package_file_version` and `custom_versions`
+///
+/// Each release is scored by how many of the UE4 package file version, UE5 package file version
+/// and custom versions known to this crate it would have saved. A UE4 package file version of 0
+/// means the file is unversioned, and empty custom versions mean the game stripped them, so those
+/// are left out. Returns `None` if there's nothing left to compare.
+pub(crate) fn estimate_engine_version(
+    package_file_version: FPackageFileVersion,
+    custom_versions: &HashableIndexMap<Guid, u32>,
+) -> Option<EngineVersionEstimate> {
+    let versioned = package_file_version.file_version_ue4 != 0;
+    let has_custom_versions = !custom_versions.is_empty();
+
+    let evidence =
+        usize::from(versioned) * 2 + usize::from(has_custom_versions) * KNOWN_CUSTOM_VERSION_COUNT;
+    if evidence == 0 {
+        return None;
+    }
+
+    let mut best: Option<(usize, EngineVersion, EngineVersion)> = None;
+    for (engine_version, _, _, ue4, ue5) in RELEASES {
+        let mut versions = vec![];
+        if versioned {
+            versions.push(package_file_version.file_version_ue4 == *ue4);
+            versions.push(package_file_version.file_version_ue5 == *ue5);
+        }
+        if has_custom_versions {
+            versions.extend(
+                known_custom_versions_for(*engine_version)
+                    .into_iter()
+                    .map(|(guid, version)| custom_versions.get(&guid).copied() == version),
+            );
+        }
+        let matches = versions.into_iter().filter(|matched| *matched).count();
+
+        best = match best {
+            Some((best_matches, oldest, _)) if matches == best_matches => {
+                Some((matches, oldest, *engine_version))
+            }
+            Some((best_matches, ..)) if matches < best_matches => best,
+            _ => Some((matches, *engine_version, *engine_version)),
+        };
+    }
+
+    best.map(|(matches, oldest, newest)| EngineVersionEstimate {
+        oldest,
+        newest,
+        confidence: matches as f32 / evidence as f32,
+    })
+}
@@ -32,15 +32,30 @@ pub enum DeserializeError {
     /// If a Property creation fails
     #[error("Invalid property {0} at position {1:#x}")]
     InvalidProperty(Box<str>, u64),
+    /// If a property has a different type than its container declares
+    #[error("Expected property type {0}, got {1} at position {2:#x}")]
+    UnexpectedPropertyType(Box<str>, Box<str>, u64),
     /// Invalid enum value
     #[error("No discriminant in enum `{0}` matches the value `{1}` at position {2:#x}")]
     InvalidEnumValue(Box<str>, i8, u64),
-    /// Invalid array index header
-    #[error("Unexpected array_index value {0} at position {1:#x}")]
-    InvalidArrayIndex(u32, u64),
-    /// Invalid terminator
+    /// Invalid terminator, e.g. a HasPropertyGuid value other than 0 or 1
     #[error("Unexpected terminator value {0} at position {1:#x}")]
     InvalidTerminator(u8, u64),
+    /// Invalid or unsupported property tag flags
+    #[error("Unexpected property tag flags {0:#04x} at position {1:#x}")]
+    InvalidTagFlags(u8, u64),
+    /// Invalid or unsupported property tag extensions
+    #[error("Unexpected property tag extensions {0:#04x} at position {1:#x}")]
+    InvalidTagExtensions(u8, u64),
+    /// If a value exceeds one of the configured [`Limits`](crate::limits::Limits)
+    #[error("The {0} {1} exceeds the limit of {2} at position {3:#x}")]
+    LimitExceeded(Box<str>, u64, u64, u64),
+    /// If reading a property failed, with the path and type of the innermost property
+    #[error("at {0} ({1}): {2}")]
+    InProperty(Box<str>, Box<str>, Box<Error>),
+    /// If a stored checksum doesn't match the data
+    #[error("Checksum mismatch, expected {0} got {1} at position {2:#x}")]
+    ChecksumMismatch(Box<str>, Box<str>, u64),
     /// If a string has invalid UTF-16 formatting
     #[error("Invalid UTF-16 string at position {1:#x}")]
     FromUtf16Error(#[source] FromUtf16Error, u64),
@@ -82,6 +97,17 @@ impl DeserializeError {
         let position = stream.stream_position().unwrap_or_default();
         Self::InvalidEnumValue(name.into(), value, position)
     }
+
+    /// A helper for creating `LimitExceeded` errors
+    #[inline]
+    pub fn limit_exceeded<N, S>(name: N, value: u64, limit: u64, stream: &mut S) -> Self
+    where
+        N: Into<Box<str>>,
+        S: io::Seek,
+    {
+        let position = stream.stream_position().unwrap_or_default();
+        Self::LimitExceeded(name.into(), value, limit, position)
+    }
 }
 
 /// Gets thrown when there is a serialization error
@@ -114,6 +140,23 @@ impl SerializeError {
     }
 }
 
+/// A problem that was recovered from when reading leniently
+///
+/// Usually a property that failed to parse and was kept as an
+/// [`UnknownProperty`](crate::properties::unknown_property::UnknownProperty), or a problem with
+/// the container, e.g. a checksum mismatch.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Path of the recovered property, empty for container problems
+    pub path: String,
+    /// Type of the recovered property, empty for container problems
+    pub property_type: String,
+    /// Position of the recovered property tag, or of the problem in the container
+    pub position: u64,
+    /// The error that occurred while parsing the property
+    pub error: Error,
+}
+
 /// A wrapper for the various error types this crate can emit
 #[derive(Error, Debug)]
 pub enum Error {
@@ -127,3 +170,20 @@ pub enum Error {
     #[error(transparent)]
     Io(#[from] io::Error),
 }
+
+impl Error {
+    /// Wraps the error with the path and type of the property being read
+    ///
+    /// Errors that already carry a property path are left unchanged, so the innermost path wins.
+    pub(crate) fn in_property(self, path: &[String], property_type: &str) -> Self {
+        match self {
+            Error::Deserialize(DeserializeError::InProperty(..)) => self,
+            _ => DeserializeError::InProperty(
+                path.join(".").into_boxed_str(),
+                property_type.into(),
+                Box::new(self),
+            )
+            .into(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 //! Game version enumeration
 
+use std::fmt::{self, Debug, Formatter};
+
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
+use crate::container::{Palworld, SaveContainer, SerializeCompressed};
+
 /// Game version enumeration
 ///
 /// Used for specifying game versions if a game has custom serialization
@@ -11,6 +15,20 @@ pub enum GameVersion {
     Default,
     /// Palworld serialization
     Palworld,
+    /// Unreal `FArchive::SerializeCompressed` stream
+    SerializeCompressed,
+}
+
+impl GameVersion {
+    /// Returns the container used by this game version, if any
+    #[inline]
+    pub(crate) fn container(self) -> Option<&'static dyn SaveContainer> {
+        match self {
+            GameVersion::Default => None,
+            GameVersion::Palworld => Some(&Palworld::DEFAULT),
+            GameVersion::SerializeCompressed => Some(&SerializeCompressed),
+        }
+    }
 }
 
 /// Palworld compression type
@@ -26,16 +44,168 @@ pub enum PalworldCompressionType {
     ZlibTwice = 0x32,
 }
 
+/// `CNK` header in front of a Palworld `PlZ` or `PlM` header
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PalworldChunk {
+    /// Save type byte
+    pub chunk_type: u8,
+    /// Decompressed length of the `CNK` header, `None` if it's the one of the inner header
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub decompressed_length: Option<u32>,
+    /// Compressed length of the `CNK` header, `None` if it's the one of the inner header
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub compressed_length: Option<u32>,
+}
+
+impl PalworldChunk {
+    /// Creates a new `PalworldChunk` instance with the lengths of the inner header
+    #[inline]
+    pub fn new(chunk_type: u8) -> Self {
+        PalworldChunk {
+            chunk_type,
+            decompressed_length: None,
+            compressed_length: None,
+        }
+    }
+}
+
+/// Parameters of zlib compressed data
+///
+/// Unchanged data is written with the compressed data it was read with, see
+/// [`Palworld::keep_compressed_data`]. Otherwise it is compressed with the `level` and header
+/// detected when reading. These are a best-effort hint: zlib implementations differ in their
+/// output, so the compressed data may differ from the one a game writes.
+///
+/// The kept compressed data isn't compared, so a file equals its serde round trip.
+#[derive(Debug, Default, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZlibParameters {
+    /// Compression level from 0 to 9, `None` for the default level 6
+    ///
+    /// When reading, this is guessed from the `FLEVEL` bits of the zlib header, which only tell
+    /// the levels 1, 5, 6 and 9 apart.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<u32>,
+    /// The `CMF` and `FLG` bytes starting the zlib data
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub header: Option<[u8; 2]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) original: Option<CompressedData>,
+}
+
+impl PartialEq for ZlibParameters {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.level == other.level && self.header == other.header
+    }
+}
+
+impl ZlibParameters {
+    /// Default compression level
+    pub const DEFAULT_LEVEL: u32 = 6;
+
+    /// Creates the parameters of the zlib data starting with `prefix`
+    pub(crate) fn detect(prefix: &[u8]) -> Self {
+        let header = prefix.get(..2).map(|header| [header[0], header[1]]);
+        ZlibParameters {
+            level: header.map(|header| match header[1] >> 6 {
+                0 => 1,
+                1 => 5,
+                2 => 6,
+                _ => 9,
+            }),
+            header,
+            original: None,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline]
+    fn is_default(&self) -> bool {
+        *self == ZlibParameters::default()
+    }
+}
+
+/// Compressed data as read
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct CompressedData {
+    /// Length of the decompressed data
+    pub(crate) decompressed_length: u64,
+    /// Compressed length stored in the header
+    pub(crate) compressed_length: u32,
+    /// The compressed data
+    pub(crate) data: Vec<u8>,
+}
+
+impl Debug for CompressedData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedData")
+            .field("decompressed_length", &self.decompressed_length)
+            .field("compressed_length", &self.compressed_length)
+            .field("length", &self.data.len())
+            .finish()
+    }
+}
+
 /// Deserialized game version
 ///
 /// Used for storing additional deserialized information about custom serialization
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeserializedGameVersion {
     /// Default GVAS serialization
     Default,
     /// Palworld serialization
-    Palworld(PalworldCompressionType),
+    Palworld {
+        /// Compression type
+        compression_type: PalworldCompressionType,
+        /// `CNK` header in front of the `PlZ` header
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        chunk: Option<PalworldChunk>,
+        /// Parameters of the zlib data
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "ZlibParameters::is_default")
+        )]
+        zlib: ZlibParameters,
+    },
+    /// Unreal `FArchive::SerializeCompressed` stream
+    SerializeCompressed {
+        /// Maximum uncompressed size of a chunk
+        chunk_size: u64,
+        /// Zlib compression level from 0 to 9, `None` for the default level 6
+        ///
+        /// When reading, this is guessed from the zlib header of the first chunk, see
+        /// [`ZlibParameters::level`].
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        level: Option<u32>,
+    },
+    /// Serialization by a user-provided [`SaveContainer`]
+    Custom {
+        /// Name of the container
+        container: String,
+        /// Container specific metadata needed to write the file again
+        metadata: Vec<u8>,
+    },
 }
 
 impl Default for DeserializedGameVersion {
@@ -46,6 +216,55 @@ impl Default for DeserializedGameVersion {
 }
 
 impl DeserializedGameVersion {
+    /// Creates a new `DeserializedGameVersion::Palworld` instance with default parameters
+    #[inline]
+    pub fn palworld(compression_type: PalworldCompressionType) -> Self {
+        DeserializedGameVersion::Palworld {
+            compression_type,
+            chunk: None,
+            zlib: ZlibParameters::default(),
+        }
+    }
+
+    /// Sets the zlib compression level of a Palworld or `SerializeCompressed` save, from 0 to 9
+    ///
+    /// The file is compressed again when writing, even if it is unchanged. Other game versions
+    /// are left as they are.
+    #[inline]
+    pub fn set_compression_level(&mut self, level: u32) {
+        match self {
+            DeserializedGameVersion::Palworld { zlib, .. } => {
+                zlib.level = Some(level);
+                zlib.original = None;
+            }
+            DeserializedGameVersion::SerializeCompressed {
+                level: compression_level,
+                ..
+            } => *compression_level = Some(level),
+            _ => {}
+        }
+    }
+
+    /// Creates a new `DeserializedGameVersion::Custom` instance
+    #[inline]
+    pub fn custom<S: Into<String>>(container: S, metadata: Vec<u8>) -> Self {
+        DeserializedGameVersion::Custom {
+            container: container.into(),
+            metadata,
+        }
+    }
+
+    /// Returns the name of the container this file is wrapped in, if any
+    #[inline]
+    pub fn container_name(&self) -> Option<&str> {
+        match self {
+            DeserializedGameVersion::Default => None,
+            DeserializedGameVersion::Palworld { .. } => Some(Palworld::DEFAULT.name()),
+            DeserializedGameVersion::SerializeCompressed { .. } => Some(SerializeCompressed.name()),
+            DeserializedGameVersion::Custom { container, .. } => Some(container),
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[inline]
     pub(crate) fn is_default(&self) -> bool {
@@ -55,3 +274,18 @@ impl DeserializedGameVersion {
 
 /// Palworld save magic
 pub(crate) const PLZ_MAGIC: &[u8; 3] = b"PlZ";
+
+/// Palworld save magic for Oodle compressed saves
+pub(crate) const PLM_MAGIC: &[u8; 3] = b"PlM";
+
+/// Palworld chunk header magic, followed by a `PlZ` or `PlM` header
+pub(crate) const CNK_MAGIC: &[u8; 3] = b"CNK";
+
+/// Returns the magic of the Palworld header in `prefix`, skipping a `CNK` header
+#[inline]
+pub(crate) fn palworld_magic(prefix: &[u8]) -> Option<&[u8]> {
+    match prefix.get(8..11) {
+        Some(magic) if magic == CNK_MAGIC => prefix.get(20..23),
+        magic => magic,
+    }
+}
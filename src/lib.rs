@@ -59,7 +59,16 @@
 //! println!("{:#?}", gvas_file);
 //! # Ok::<(), Error>(())
 //! ```
+//!
+//! Files saved before UE4 package version 500 (`VER_UE4_INNER_ARRAY_TAG_INFO`) don't store the
+//! element type of arrays either. Those arrays need a hint with the element property type, e.g.
+//! `"Inventory.ArrayProperty"` → `"StructProperty"`, and arrays of structs also the struct type,
+//! e.g. `"Inventory.ArrayProperty.StructProperty"` → `"InventoryItem"`.
 
+/// Builder for new GVAS files.
+pub mod builder;
+/// Save containers.
+pub mod container;
 /// Extensions for `Cursor`.
 pub mod cursor_ext;
 /// Custom version information.
@@ -70,41 +79,56 @@ pub mod engine_version;
 pub mod error;
 /// Game version enumeration.
 pub mod game_version;
+/// Resource limits.
+pub mod limits;
 /// Object version information.
 pub mod object_version;
 /// Extensions for `Ord`.
 mod ord_ext;
 /// Property types.
 pub mod properties;
+/// Retargeting files to another engine version.
+pub mod retarget;
 /// Savegame version information.
 pub mod savegame_version;
 pub(crate) mod scoped_stack_entry;
+pub(crate) mod stream;
 /// Various types.
 pub mod types;
+/// Options for writing files.
+pub mod write_options;
 
-use std::io::{Cursor, SeekFrom};
+use std::io::Cursor;
 use std::{
+    borrow::Cow,
     collections::HashMap,
     fmt::Debug,
     io::{Read, Seek, Write},
+    sync::LazyLock,
 };
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use flate2::read::ZlibDecoder;
-use flate2::write::ZlibEncoder;
-use flate2::Compression;
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    container::{read_prefix, SaveContainer, BUILTIN_CONTAINERS},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     custom_version::FCustomVersion,
-    engine_version::FEngineVersion,
-    error::{DeserializeError, Error},
-    game_version::{DeserializedGameVersion, GameVersion, PalworldCompressionType, PLZ_MAGIC},
-    object_version::EUnrealEngineObjectUE5Version,
+    engine_version::{
+        estimate_engine_version, EngineVersion, EngineVersionEstimate, FEngineVersion,
+    },
+    error::{DeserializeError, Diagnostic, Error, SerializeError},
+    game_version::{palworld_magic, DeserializedGameVersion, GameVersion, PLM_MAGIC},
+    limits::Limits,
+    object_version::{
+        EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version, FPackageFileVersion,
+    },
     ord_ext::OrdExt,
     properties::{Property, PropertyOptions, PropertyTrait},
+    retarget::{convert_properties, retarget_header, LossyConversion},
     savegame_version::SaveGameVersion,
+    stream::{TrackingReader, TrackingWriter},
     types::{map::HashableIndexMap, Guid},
+    write_options::WriteOptions,
 };
 
 /// The four bytes 'GVAS' appear at the beginning of every GVAS file.
@@ -115,6 +139,15 @@ pub const FILE_TYPE_GVAS: u32 = u32::from_le_bytes([b'G', b'V', b'A', b'S']);
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum GvasHeader {
+    /// Version 1, without custom versions
+    Version1 {
+        /// File format version.
+        package_file_version: u32,
+        /// Unreal Engine version.
+        engine_version: FEngineVersion,
+        /// Save game class name.
+        save_game_class_name: String,
+    },
     /// Version 2
     Version2 {
         /// File format version.
@@ -167,7 +200,17 @@ impl GvasHeader {
     /// println!("{:#?}", gvas_header);
     /// # Ok::<(), Error>(())
     /// ```
+    #[inline]
     pub fn read<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
+        Self::read_with_limits(cursor, Limits::default())
+    }
+
+    /// Read GvasHeader from a binary file, rejecting strings and collections beyond `limits`
+    ///
+    /// # Errors
+    ///
+    /// If this function reads an invalid header or one exceeding `limits` it returns [`Error`]
+    pub fn read_with_limits<R: Read + Seek>(cursor: &mut R, limits: Limits) -> Result<Self, Error> {
         let file_type_tag = cursor.read_u32::<LittleEndian>()?;
         if file_type_tag != FILE_TYPE_GVAS {
             Err(DeserializeError::InvalidHeader(
@@ -177,7 +220,7 @@ impl GvasHeader {
 
         let save_game_file_version = cursor.read_u32::<LittleEndian>()?;
         if !save_game_file_version.between(
-            SaveGameVersion::AddedCustomVersions as u32,
+            SaveGameVersion::InitialVersion as u32,
             SaveGameVersion::PackageFileSummaryVersionChange as u32,
         ) {
             Err(DeserializeError::InvalidHeader(
@@ -186,7 +229,10 @@ impl GvasHeader {
         }
 
         let package_file_version = cursor.read_u32::<LittleEndian>()?;
-        if !package_file_version.between(0x205, 0x20D) {
+        if !package_file_version.between(
+            EUnrealEngineObjectUE4Version::OldestLoadablePackage as u32,
+            0x20D,
+        ) {
             Err(DeserializeError::InvalidHeader(
                 format!("Package file version {package_file_version} not supported")
                     .into_boxed_str(),
@@ -200,7 +246,7 @@ impl GvasHeader {
             let version = cursor.read_u32::<LittleEndian>()?;
             if !version.between(
                 EUnrealEngineObjectUE5Version::InitialVersion as u32,
-                EUnrealEngineObjectUE5Version::DataResources as u32,
+                EUnrealEngineObjectUE5Version::OsSubObjectShadowSerialization as u32,
             ) {
                 Err(DeserializeError::InvalidHeader(
                     format!("UE5 Package file version {version} is not supported").into_boxed_str(),
@@ -211,7 +257,18 @@ impl GvasHeader {
             None
         };
 
-        let engine_version = FEngineVersion::read(cursor)?;
+        let engine_version = FEngineVersion::read(cursor, &limits)?;
+
+        // Custom versions were added in the v2 header
+        if save_game_file_version < SaveGameVersion::AddedCustomVersions as u32 {
+            let save_game_class_name = cursor.read_string_limited(limits.max_string_length)?;
+            return Ok(GvasHeader::Version1 {
+                package_file_version,
+                engine_version,
+                save_game_class_name,
+            });
+        }
+
         let custom_version_format = cursor.read_u32::<LittleEndian>()?;
         if custom_version_format != 3 {
             Err(DeserializeError::InvalidHeader(
@@ -221,13 +278,15 @@ impl GvasHeader {
         }
 
         let custom_versions_len = cursor.read_u32::<LittleEndian>()?;
-        let mut custom_versions = HashableIndexMap::with_capacity(custom_versions_len as usize);
+        limits.check_element_count(custom_versions_len, cursor)?;
+        let mut custom_versions =
+            HashableIndexMap::with_capacity(bounded_capacity(custom_versions_len as usize));
         for _ in 0..custom_versions_len {
             let FCustomVersion { key, version } = FCustomVersion::read(cursor)?;
             custom_versions.insert(key, version);
         }
 
-        let save_game_class_name = cursor.read_string()?;
+        let save_game_class_name = cursor.read_string_limited(limits.max_string_length)?;
 
         Ok(match package_file_version_ue5 {
             None => GvasHeader::Version2 {
@@ -269,6 +328,19 @@ impl GvasHeader {
     pub fn write<W: Write>(&self, cursor: &mut W) -> Result<usize, Error> {
         cursor.write_u32::<LittleEndian>(FILE_TYPE_GVAS)?;
         match self {
+            GvasHeader::Version1 {
+                package_file_version,
+                engine_version,
+                save_game_class_name,
+            } => {
+                let mut len = 12;
+                cursor.write_u32::<LittleEndian>(1)?;
+                cursor.write_u32::<LittleEndian>(*package_file_version)?;
+                len += engine_version.write(cursor)?;
+                len += cursor.write_string(save_game_class_name)?;
+                Ok(len)
+            }
+
             GvasHeader::Version2 {
                 package_file_version,
                 engine_version,
@@ -314,8 +386,13 @@ impl GvasHeader {
     }
 
     /// Get custom versions from this header
+    ///
+    /// Version 1 headers have no custom versions.
     pub fn get_custom_versions(&self) -> &HashableIndexMap<Guid, u32> {
+        static NO_CUSTOM_VERSIONS: LazyLock<HashableIndexMap<Guid, u32>> =
+            LazyLock::new(HashableIndexMap::new);
         match self {
+            GvasHeader::Version1 { .. } => &NO_CUSTOM_VERSIONS,
             GvasHeader::Version2 {
                 custom_versions, ..
             } => custom_versions,
@@ -324,6 +401,146 @@ impl GvasHeader {
             } => custom_versions,
         }
     }
+
+    /// Get package file version from this header
+    pub fn get_package_file_version(&self) -> FPackageFileVersion {
+        match self {
+            GvasHeader::Version1 {
+                package_file_version,
+                ..
+            }
+            | GvasHeader::Version2 {
+                package_file_version,
+                ..
+            } => FPackageFileVersion::new(*package_file_version, 0),
+            GvasHeader::Version3 {
+                package_file_version,
+                package_file_version_ue5,
+                ..
+            } => FPackageFileVersion::new(*package_file_version, *package_file_version_ue5),
+        }
+    }
+
+    /// Estimates the releases that saved a file with this header
+    ///
+    /// Some games write a blank or bogus [`FEngineVersion`]. The package file versions and the
+    /// custom versions known to this crate are compared with those saved by each release, and
+    /// the releases matching the most of them are returned as a range, with the fraction they
+    /// matched as confidence. Releases that save the same versions, e.g. 4.21 to 4.24, can't be
+    /// told apart. Returns `None` if the header is unversioned and has no custom versions.
+    pub fn estimate_engine_version(&self) -> Option<EngineVersionEstimate> {
+        estimate_engine_version(self.get_package_file_version(), self.get_custom_versions())
+    }
+
+    /// Get the engine version that saved a file with this header
+    ///
+    /// This is the version of the [`FEngineVersion`] if it's a release within
+    /// [`estimate_engine_version`](Self::estimate_engine_version), and the newest estimated
+    /// release otherwise.
+    pub fn resolve_engine_version(&self) -> EngineVersion {
+        let engine_version = match self {
+            GvasHeader::Version1 { engine_version, .. }
+            | GvasHeader::Version2 { engine_version, .. }
+            | GvasHeader::Version3 { engine_version, .. } => engine_version.get_version(),
+        };
+        match self.estimate_engine_version() {
+            Some(estimate) if !estimate.contains(engine_version) => estimate.newest,
+            _ => engine_version,
+        }
+    }
+}
+
+/// Bytes following the properties of a GVAS file
+///
+/// Some games append checksums, thumbnails or more data after the `None` terminating the
+/// properties. These are kept verbatim and written back unchanged, even if the properties changed.
+/// Usually this is just four zero bytes of padding, which is also the default.
+///
+/// A trailer holding e.g. a checksum of the properties can be created with
+/// [`GvasTrailer::with_body_crc32`], so writing fails instead of keeping it once the properties
+/// change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", cfg_eval::cfg_eval, serde_with::serde_as)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GvasTrailer {
+    /// Bytes after the `None` terminator, empty if the file ends right after it
+    #[cfg_attr(feature = "serde", serde_as(as = "serde_with::hex::Hex"))]
+    pub data: Vec<u8>,
+    /// CRC-32 of the header and properties `data` depends on, see [`GvasFile::body_crc32`]
+    ///
+    /// Writing fails while this is set and the properties changed. Update `data` and clear this
+    /// field, or just clear it to keep `data` as it is.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub body_crc32: Option<u32>,
+}
+
+impl Default for GvasTrailer {
+    #[inline]
+    fn default() -> Self {
+        GvasTrailer::new(GvasTrailer::PADDING.to_vec())
+    }
+}
+
+impl GvasTrailer {
+    /// The usual four zero bytes following the properties
+    pub const PADDING: [u8; 4] = [0; 4];
+
+    /// Creates a new `GvasTrailer` instance that is written regardless of the properties
+    #[inline]
+    pub fn new(data: Vec<u8>) -> Self {
+        GvasTrailer {
+            data,
+            body_crc32: None,
+        }
+    }
+
+    /// Creates a new `GvasTrailer` instance that depends on the header and properties with the
+    /// CRC-32 `body_crc32`
+    ///
+    /// Writing fails once they change, so a stale trailer isn't written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{error::Error, game_version::GameVersion, GvasFile, GvasTrailer};
+    /// use std::fs::File;
+    ///
+    /// let mut file = File::open("save.sav")?;
+    /// let mut gvas_file = GvasFile::read(&mut file, GameVersion::Default)?;
+    ///
+    /// // The trailer holds a checksum of the properties
+    /// let data = std::mem::take(&mut gvas_file.trailer.data);
+    /// gvas_file.trailer = GvasTrailer::with_body_crc32(data, gvas_file.body_crc32()?);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub fn with_body_crc32(data: Vec<u8>, body_crc32: u32) -> Self {
+        GvasTrailer {
+            data,
+            body_crc32: Some(body_crc32),
+        }
+    }
+
+    /// Returns true if this is the usual trailer of four zero bytes
+    #[inline]
+    pub fn is_padding(&self) -> bool {
+        self.data == Self::PADDING && self.body_crc32.is_none()
+    }
+
+    /// Writes the trailer following properties with the CRC-32 `crc`
+    fn write<W: Write>(&self, cursor: &mut W, crc: u32) -> Result<(), Error> {
+        if let Some(body_crc32) = self.body_crc32 {
+            if crc != body_crc32 {
+                Err(SerializeError::invalid_value(format!(
+                    "Trailer was read with properties of CRC-32 {body_crc32:#010x}, \
+                     but they are now {crc:#010x}. Recompute the trailer and clear body_crc32."
+                )))?
+            }
+        }
+        cursor.write_all(&self.data)?;
+        Ok(())
+    }
 }
 
 /// Main UE4 save file struct
@@ -339,7 +556,17 @@ pub struct GvasFile {
     /// GVAS file header.
     pub header: GvasHeader,
     /// GVAS properties.
-    pub properties: HashableIndexMap<String, Property>,
+    ///
+    /// The properties are kept in the order they were read, with the name they were stored under.
+    /// A name repeats for each entry of a static array, and the entries are told apart by their
+    /// `array_index`.
+    pub properties: Vec<(String, Property)>,
+    /// Bytes following the properties
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "GvasTrailer::is_padding")
+    )]
+    pub trailer: GvasTrailer,
 }
 
 impl GvasFile {
@@ -406,88 +633,330 @@ impl GvasFile {
         game_version: GameVersion,
         hints: &HashMap<String, String>,
     ) -> Result<Self, Error> {
-        let deserialized_game_version: DeserializedGameVersion;
-        let mut cursor = match game_version {
-            GameVersion::Default => {
-                deserialized_game_version = DeserializedGameVersion::Default;
-                let mut data = Vec::new();
-                cursor.read_to_end(&mut data)?;
-                Cursor::new(data)
-            }
-            GameVersion::Palworld => {
-                let decompresed_length = cursor.read_u32::<LittleEndian>()?;
-                let _compressed_length = cursor.read_u32::<LittleEndian>()?;
+        Self::read_with_limits(cursor, game_version, hints, Limits::default())
+    }
 
-                let mut magic = [0u8; 3];
-                cursor.read_exact(&mut magic)?;
-                if &magic != PLZ_MAGIC {
+    /// Read GvasFile from a binary file, rejecting input that exceeds the given [`Limits`]
+    ///
+    /// # Errors
+    ///
+    /// If this function reads an invalid file it returns [`Error`]
+    ///
+    /// If the file exceeds one of the limits it returns [`DeserializeError::LimitExceeded`]
+    ///
+    /// [`DeserializeError::LimitExceeded`]: error/enum.DeserializeError.html#variant.LimitExceeded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{error::Error, game_version::GameVersion, limits::Limits, GvasFile};
+    /// use std::{collections::HashMap, fs::File};
+    ///
+    /// let mut file = File::open("save.sav")?;
+    ///
+    /// let limits = Limits {
+    ///     max_decompressed_size: 64 * 1024 * 1024,
+    ///     ..Limits::default()
+    /// };
+    ///
+    /// let gvas_file =
+    ///     GvasFile::read_with_limits(&mut file, GameVersion::Default, &HashMap::new(), limits);
+    ///
+    /// println!("{:#?}", gvas_file);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn read_with_limits<R: Read + Seek>(
+        cursor: &mut R,
+        game_version: GameVersion,
+        hints: &HashMap<String, String>,
+        limits: Limits,
+    ) -> Result<Self, Error> {
+        Self::read_internal(cursor, game_version.container(), hints, limits, None)
+    }
+
+    /// Read GvasFile from a binary file, recovering from properties that fail to parse
+    ///
+    /// Each tagged property that fails to parse is kept as an [`UnknownProperty`] holding its raw
+    /// bytes, and reading continues with the next property. The recovered properties are written
+    /// back unchanged.
+    ///
+    /// Returns the file together with a [`Diagnostic`] for each recovered property.
+    ///
+    /// [`UnknownProperty`]: properties/unknown_property/struct.UnknownProperty.html
+    ///
+    /// # Errors
+    ///
+    /// If this function reads an invalid file that can't be recovered it returns [`Error`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{error::Error, game_version::GameVersion, GvasFile};
+    /// use std::{collections::HashMap, fs::File};
+    ///
+    /// let mut file = File::open("save.sav")?;
+    ///
+    /// let (gvas_file, diagnostics) =
+    ///     GvasFile::read_lenient(&mut file, GameVersion::Default, &HashMap::new())?;
+    ///
+    /// for diagnostic in diagnostics {
+    ///     println!("Kept {} as raw bytes: {}", diagnostic.path, diagnostic.error);
+    /// }
+    /// println!("{:#?}", gvas_file);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn read_lenient<R: Read + Seek>(
+        cursor: &mut R,
+        game_version: GameVersion,
+        hints: &HashMap<String, String>,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let mut diagnostics = Vec::new();
+        let file = Self::read_internal(
+            cursor,
+            game_version.container(),
+            hints,
+            Limits::default(),
+            Some(&mut diagnostics),
+        )?;
+        Ok((file, diagnostics))
+    }
+
+    /// Read GvasFile from a binary file, detecting the container it is wrapped in
+    ///
+    /// Plain GVAS files are recognized by their `GVAS` magic, and wrapped files by the containers
+    /// built into this crate, such as [`Palworld`]. The container that was found is recorded in
+    /// `deserialized_game_version`.
+    ///
+    /// [`Palworld`]: container/struct.Palworld.html
+    ///
+    /// # Errors
+    ///
+    /// If the file is neither plain GVAS nor in a known container it returns
+    /// [`DeserializeError::InvalidHeader`]
+    ///
+    /// If this function reads an invalid file it returns [`Error`]
+    ///
+    /// [`DeserializeError::InvalidHeader`]: error/enum.DeserializeError.html#variant.InvalidHeader
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{error::Error, GvasFile};
+    /// use std::{collections::HashMap, fs::File};
+    ///
+    /// let mut file = File::open("save.sav")?;
+    ///
+    /// let gvas_file = GvasFile::read_auto(&mut file, &HashMap::new())?;
+    ///
+    /// println!("{:?}", gvas_file.deserialized_game_version);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn read_auto<R: Read + Seek>(
+        cursor: &mut R,
+        hints: &HashMap<String, String>,
+    ) -> Result<Self, Error> {
+        let prefix = read_prefix(cursor)?;
+        let container = match prefix.starts_with(&FILE_TYPE_GVAS.to_le_bytes()) {
+            true => None,
+            false => match BUILTIN_CONTAINERS
+                .iter()
+                .copied()
+                .find(|container| container.detect(&prefix))
+            {
+                Some(container) => Some(container),
+                None if palworld_magic(&prefix) == Some(PLM_MAGIC) => {
                     Err(DeserializeError::InvalidHeader(
-                        format!("Invalid PlZ magic {magic:?}").into_boxed_str(),
+                        "Palworld PlM save, read it with a PalworldOodle container".into(),
                     ))?
                 }
+                None => Err(DeserializeError::InvalidHeader(
+                    format!(
+                        "Neither GVAS nor a known container, file starts with {:02x?}",
+                        &prefix[..prefix.len().min(12)]
+                    )
+                    .into_boxed_str(),
+                ))?,
+            },
+        };
+        Self::read_internal(cursor, container, hints, Limits::default(), None)
+    }
 
-                let compression_type = cursor.read_enum()?;
-
-                deserialized_game_version = DeserializedGameVersion::Palworld(compression_type);
+    /// Read GvasFile from a binary file that may be wrapped in one of the given containers
+    ///
+    /// The first container that detects the file is used to unwrap it. Files that no container
+    /// detects are read as plain GVAS data.
+    ///
+    /// # Errors
+    ///
+    /// If this function reads an invalid file it returns [`Error`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{container::Palworld, error::Error, GvasFile};
+    /// use std::{collections::HashMap, fs::File};
+    ///
+    /// let mut file = File::open("save.sav")?;
+    ///
+    /// let gvas_file =
+    ///     GvasFile::read_with_containers(&mut file, &[&Palworld::DEFAULT], &HashMap::new())?;
+    ///
+    /// println!("{:?}", gvas_file.deserialized_game_version);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn read_with_containers<R: Read + Seek>(
+        cursor: &mut R,
+        containers: &[&dyn SaveContainer],
+        hints: &HashMap<String, String>,
+    ) -> Result<Self, Error> {
+        let container = detect_container(cursor, containers)?;
+        Self::read_internal(cursor, container, hints, Limits::default(), None)
+    }
 
-                match compression_type {
-                    PalworldCompressionType::None => {
-                        let mut data = vec![0u8; decompresed_length as usize];
+    /// Read GvasFile from a binary file that may be wrapped in one of the given containers,
+    /// recovering from properties that fail to parse
+    ///
+    /// Combines [`GvasFile::read_with_containers`] and [`GvasFile::read_lenient`]. Problems the
+    /// container recovers from, e.g. a checksum mismatch, are reported as [`Diagnostic`]s too.
+    ///
+    /// # Errors
+    ///
+    /// If this function reads an invalid file that can't be recovered it returns [`Error`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{container::Palworld, error::Error, GvasFile};
+    /// use std::{collections::HashMap, fs::File};
+    ///
+    /// let mut file = File::open("save.sav")?;
+    ///
+    /// let (gvas_file, diagnostics) = GvasFile::read_lenient_with_containers(
+    ///     &mut file,
+    ///     &[&Palworld::DEFAULT],
+    ///     &HashMap::new(),
+    /// )?;
+    ///
+    /// for diagnostic in diagnostics {
+    ///     println!("{}: {}", diagnostic.path, diagnostic.error);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn read_lenient_with_containers<R: Read + Seek>(
+        cursor: &mut R,
+        containers: &[&dyn SaveContainer],
+        hints: &HashMap<String, String>,
+    ) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let container = detect_container(cursor, containers)?;
+        let mut diagnostics = Vec::new();
+        let file = Self::read_internal(
+            cursor,
+            container,
+            hints,
+            Limits::default(),
+            Some(&mut diagnostics),
+        )?;
+        Ok((file, diagnostics))
+    }
 
-                        cursor.read_exact(&mut data)?;
-                        Cursor::new(data)
+    fn read_internal<R: Read + Seek>(
+        cursor: &mut R,
+        container: Option<&dyn SaveContainer>,
+        hints: &HashMap<String, String>,
+        limits: Limits,
+        diagnostics: Option<&mut Vec<Diagnostic>>,
+    ) -> Result<Self, Error> {
+        match (container, diagnostics) {
+            (None, None) => Self::read_stream(&mut TrackingReader::new(cursor), hints, limits),
+            (Some(container), None) => {
+                let mut reader = TrackingReader::new(container.unwrap_stream(cursor, &limits)?);
+                let mut file = Self::read_stream(&mut reader, hints, limits)?;
+                file.deserialized_game_version = reader.into_inner().finish()?;
+                Ok(file)
+            }
+            // Recovering from errors seeks back, so all of the data is read first
+            (container, Some(diagnostics)) => {
+                let (data, deserialized_game_version) = match container {
+                    None => {
+                        let mut data = Vec::new();
+                        cursor.read_to_end(&mut data)?;
+                        (data, DeserializedGameVersion::Default)
                     }
-                    PalworldCompressionType::Zlib => {
-                        let mut zlib_data = vec![0u8; decompresed_length as usize];
+                    Some(container) => container.unwrap_lenient(cursor, &limits, diagnostics)?,
+                };
+                let mut cursor = Cursor::new(data);
+                let (header, properties) =
+                    Self::read_properties(&mut cursor, hints, limits, Some(diagnostics))?;
 
-                        let mut decoder = ZlibDecoder::new(cursor);
-                        decoder.read_exact(&mut zlib_data)?;
+                let data = cursor.get_ref()[cursor.position() as usize..].to_vec();
+                let trailer = GvasTrailer::new(data);
 
-                        Cursor::new(zlib_data)
-                    }
-                    PalworldCompressionType::ZlibTwice => {
-                        let decoder = ZlibDecoder::new(cursor);
-                        let mut decoder = ZlibDecoder::new(decoder);
+                Ok(GvasFile {
+                    deserialized_game_version,
+                    header,
+                    properties,
+                    trailer,
+                })
+            }
+        }
+    }
 
-                        let mut zlib_data = Vec::new();
-                        decoder.read_to_end(&mut zlib_data)?;
+    /// Reads the GVAS data from a stream, with the default game version
+    fn read_stream<S: Read>(
+        reader: &mut TrackingReader<S>,
+        hints: &HashMap<String, String>,
+        limits: Limits,
+    ) -> Result<Self, Error> {
+        let (header, properties) = Self::read_properties(reader, hints, limits, None)?;
 
-                        Cursor::new(zlib_data)
-                    }
-                }
-            }
-        };
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let trailer = GvasTrailer::new(data);
 
-        let header = GvasHeader::read(&mut cursor)?;
+        Ok(GvasFile {
+            deserialized_game_version: DeserializedGameVersion::Default,
+            header,
+            properties,
+            trailer,
+        })
+    }
+
+    /// Reads the header and the properties up to the `None` terminating them
+    fn read_properties<R: Read + Seek>(
+        cursor: &mut R,
+        hints: &HashMap<String, String>,
+        limits: Limits,
+        diagnostics: Option<&mut Vec<Diagnostic>>,
+    ) -> Result<(GvasHeader, Vec<(String, Property)>), Error> {
+        let header = GvasHeader::read_with_limits(cursor, limits)?;
 
         let mut options = PropertyOptions {
             hints,
             properties_stack: &mut vec![],
             custom_versions: header.get_custom_versions(),
+            package_file_version: header.get_package_file_version(),
+            limits,
+            diagnostics,
         };
 
-        let mut properties = HashableIndexMap::new();
+        let mut properties = vec![];
         loop {
-            let property_name = cursor.read_string()?;
+            let property_name = cursor.read_string_limited(options.limits.max_string_length)?;
             if property_name == "None" {
                 break;
             }
 
-            let property_type = cursor.read_string()?;
+            let property_type = cursor.read_string_limited(options.limits.max_string_length)?;
 
             options.properties_stack.push(property_name.clone());
 
-            let property = Property::new(&mut cursor, &property_type, true, &mut options, None)?;
-            properties.insert(property_name, property);
+            let property = Property::new(cursor, &property_type, true, &mut options, None)?;
+            properties.push((property_name, property));
 
             let _ = options.properties_stack.pop();
         }
 
-        Ok(GvasFile {
-            deserialized_game_version,
-            header,
-            properties,
-        })
+        Ok((header, properties))
     }
 
     /// Write GvasFile to a binary file
@@ -515,57 +984,193 @@ impl GvasFile {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn write<W: Write + Seek>(&self, cursor: &mut W) -> Result<(), Error> {
-        let mut writing_cursor = Cursor::new(Vec::new());
+        self.write_with_containers(cursor, &[])
+    }
 
-        self.header.write(&mut writing_cursor)?;
+    /// Write GvasFile to a binary file, wrapping it in one of the given containers
+    ///
+    /// The container is looked up by the name stored in `deserialized_game_version`, among
+    /// `containers` and the containers built into this crate.
+    ///
+    /// # Errors
+    ///
+    /// If the file was modified in a way that makes it invalid this function returns [`Error`]
+    ///
+    /// If no container with the stored name is found it returns [`SerializeError::InvalidValue`]
+    ///
+    /// [`SerializeError::InvalidValue`]: error/enum.SerializeError.html#variant.InvalidValue
+    pub fn write_with_containers<W: Write + Seek>(
+        &self,
+        cursor: &mut W,
+        containers: &[&dyn SaveContainer],
+    ) -> Result<(), Error> {
+        self.write_with_options(
+            cursor,
+            &WriteOptions {
+                containers,
+                ..WriteOptions::default()
+            },
+        )
+    }
 
-        let mut options = PropertyOptions {
+    /// Write GvasFile to a binary file with the given [`WriteOptions`]
+    ///
+    /// # Errors
+    ///
+    /// If the file was modified in a way that makes it invalid this function returns [`Error`]
+    ///
+    /// If no container with the stored name is found it returns [`SerializeError::InvalidValue`]
+    ///
+    /// [`SerializeError::InvalidValue`]: error/enum.SerializeError.html#variant.InvalidValue
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{
+    ///     error::Error, game_version::GameVersion, write_options::WriteOptions, GvasFile,
+    /// };
+    /// use std::fs::File;
+    ///
+    /// let mut file = File::open("save.sav")?;
+    /// let gvas_file = GvasFile::read(&mut file, GameVersion::Palworld)?;
+    ///
+    /// let options = WriteOptions {
+    ///     compression_level: Some(9),
+    ///     ..WriteOptions::default()
+    /// };
+    /// let mut file = File::create("save.sav")?;
+    /// gvas_file.write_with_options(&mut file, &options)?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn write_with_options<W: Write + Seek>(
+        &self,
+        cursor: &mut W,
+        options: &WriteOptions,
+    ) -> Result<(), Error> {
+        let Some(name) = self.deserialized_game_version.container_name() else {
+            self.write_payload(cursor)?;
+            return Ok(());
+        };
+        let Some(container) = options
+            .containers
+            .iter()
+            .chain(BUILTIN_CONTAINERS)
+            .find(|container| container.name() == name)
+        else {
+            Err(SerializeError::invalid_value(format!(
+                "No container named {name} registered"
+            )))?
+        };
+
+        let game_version = match options.compression_level {
+            Some(level) => {
+                let mut game_version = self.deserialized_game_version.clone();
+                game_version.set_compression_level(level);
+                Cow::Owned(game_version)
+            }
+            None => Cow::Borrowed(&self.deserialized_game_version),
+        };
+        let mut stream = container.wrap_stream(&game_version, cursor)?;
+        self.write_payload(&mut stream)?;
+        stream.finish()
+    }
+
+    /// Retargets the file to another engine version
+    ///
+    /// The header is rewritten with the package file versions and custom versions of
+    /// `engine_version`, like [`GvasFileBuilder`](builder::GvasFileBuilder) creates them. Custom
+    /// versions unknown to this crate are kept. Vectors, rotators and quaternions are converted
+    /// to double precision if the engine version uses Large World Coordinates, or to single
+    /// precision if it doesn't.
+    ///
+    /// Returns the values that lost precision in the conversion.
+    ///
+    /// # Errors
+    ///
+    /// If the engine version isn't a release, e.g. [`EngineVersion::UNKNOWN`], or two map keys
+    /// become equal when converting them to single precision, this returns
+    /// [`SerializeError::InvalidValue`] and leaves the file unchanged
+    ///
+    /// [`SerializeError::InvalidValue`]: error/enum.SerializeError.html#variant.InvalidValue
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gvas::{engine_version::EngineVersion, error::Error, GvasFile};
+    /// use std::fs::File;
+    /// use gvas::game_version::GameVersion;
+    ///
+    /// let mut file = File::open("save.sav")?;
+    /// let mut gvas_file = GvasFile::read(&mut file, GameVersion::Default)?;
+    ///
+    /// for lossy in gvas_file.retarget(EngineVersion::VER_UE4_27)? {
+    ///     println!("{} lost precision: {:?}", lossy.path, lossy.original);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn retarget(
+        &mut self,
+        engine_version: EngineVersion,
+    ) -> Result<Vec<LossyConversion>, Error> {
+        let header = retarget_header(&self.header, engine_version)?;
+        let mut properties = self.properties.clone();
+        let lossy_conversions = convert_properties(&header, &mut properties)?;
+        self.header = header;
+        self.properties = properties;
+        Ok(lossy_conversions)
+    }
+
+    /// Returns the CRC-32 of the header and properties as written, without the trailer
+    ///
+    /// # Errors
+    ///
+    /// If the file was modified in a way that makes it invalid this function returns [`Error`]
+    pub fn body_crc32(&self) -> Result<u32, Error> {
+        let mut writer = TrackingWriter::new(std::io::sink());
+        self.write_body(&mut writer)?;
+        Ok(writer.crc32())
+    }
+
+    /// Writes the header, properties and trailer to `writer` and returns it
+    fn write_payload<W: Write>(&self, writer: W) -> Result<W, Error> {
+        let mut writer = TrackingWriter::new(writer);
+        self.write_body(&mut writer)?;
+
+        let body_crc32 = writer.crc32();
+        self.trailer.write(&mut writer, body_crc32)?;
+        Ok(writer.into_inner()?)
+    }
+
+    /// Writes the header and the properties up to the `None` terminating them
+    fn write_body<W: Write>(&self, writer: &mut TrackingWriter<W>) -> Result<(), Error> {
+        self.header.write(writer)?;
+
+        let mut property_options = PropertyOptions {
             hints: &HashMap::new(),
             properties_stack: &mut vec![],
             custom_versions: self.header.get_custom_versions(),
+            package_file_version: self.header.get_package_file_version(),
+            limits: Limits::default(),
+            diagnostics: None,
         };
 
         for (name, property) in &self.properties {
-            writing_cursor.write_string(name)?;
-            property.write(&mut writing_cursor, true, &mut options)?;
-        }
-        writing_cursor.write_string("None")?;
-        writing_cursor.write_i32::<LittleEndian>(0)?; // padding
-
-        match self.deserialized_game_version {
-            DeserializedGameVersion::Default => cursor.write_all(&writing_cursor.into_inner())?,
-            DeserializedGameVersion::Palworld(compression_type) => {
-                let decompressed = writing_cursor.into_inner();
-
-                cursor.write_u32::<LittleEndian>(decompressed.len() as u32)?;
-                let compressed_length_pos = cursor.stream_position()?;
-                cursor.write_u32::<LittleEndian>(0)?; // Compressed length placeholder, will be updated later
-                cursor.write_all(PLZ_MAGIC)?;
-                cursor.write_enum(compression_type)?;
-
-                // Compress and write data directly to the output cursor
-                match compression_type {
-                    PalworldCompressionType::None => cursor.write_all(&decompressed)?,
-                    PalworldCompressionType::Zlib => {
-                        let mut encoder = ZlibEncoder::new(cursor.by_ref(), Compression::new(6));
-                        encoder.write_all(&decompressed)?;
-                        encoder.finish()?;
-                    }
-                    PalworldCompressionType::ZlibTwice => {
-                        let encoder = ZlibEncoder::new(cursor.by_ref(), Compression::default());
-                        let mut encoder = ZlibEncoder::new(encoder, Compression::default());
-                        encoder.write_all(&decompressed)?;
-                        encoder.finish()?;
-                    }
-                }
-
-                // Update compressed length
-                let end_pos = cursor.stream_position()?;
-                cursor.seek(SeekFrom::Start(compressed_length_pos))?;
-                cursor.write_u32::<LittleEndian>((end_pos - (compressed_length_pos + 4)) as u32)?;
-                cursor.seek(SeekFrom::Start(end_pos))?;
-            }
+            writer.write_string(name)?;
+            property.write(writer, true, &mut property_options)?;
         }
+        writer.write_string("None")?;
         Ok(())
     }
 }
+
+/// Returns the first of `containers` that detects the file in `cursor`
+fn detect_container<'a, R: Read + Seek>(
+    cursor: &mut R,
+    containers: &[&'a dyn SaveContainer],
+) -> Result<Option<&'a dyn SaveContainer>, Error> {
+    let prefix = read_prefix(cursor)?;
+    Ok(containers
+        .iter()
+        .copied()
+        .find(|container| container.detect(&prefix)))
+}
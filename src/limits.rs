@@ -0,0 +1,78 @@
+//! Resource limits for reading untrusted files
+
+use std::io::Seek;
+
+use crate::error::{DeserializeError, Error};
+
+/// Resource limits
+///
+/// Used for rejecting files that would otherwise request excessive memory or recursion
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// Maximum number of elements in an array, set, map or other collection
+    pub max_element_count: u32,
+    /// Maximum nesting depth, counted in property path entries and nested texts
+    pub max_depth: usize,
+    /// Maximum string length, in characters including the terminator
+    pub max_string_length: u32,
+    /// Maximum size of decompressed save data, in bytes
+    pub max_decompressed_size: u64,
+}
+
+impl Limits {
+    /// Default limits, generous enough for all known save games
+    pub const DEFAULT: Limits = Limits {
+        max_element_count: 16 * 1024 * 1024,
+        max_depth: 64,
+        max_string_length: 131072,
+        max_decompressed_size: 1024 * 1024 * 1024,
+    };
+
+    #[inline]
+    pub(crate) fn check_element_count<S: Seek>(
+        &self,
+        count: u32,
+        stream: &mut S,
+    ) -> Result<(), Error> {
+        check(
+            "element count",
+            count.into(),
+            self.max_element_count.into(),
+            stream,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn check_depth<S: Seek>(&self, depth: usize, stream: &mut S) -> Result<(), Error> {
+        check("nesting depth", depth as u64, self.max_depth as u64, stream)
+    }
+
+    #[inline]
+    pub(crate) fn check_decompressed_size<S: Seek>(
+        &self,
+        size: u64,
+        stream: &mut S,
+    ) -> Result<(), Error> {
+        check(
+            "decompressed size",
+            size,
+            self.max_decompressed_size,
+            stream,
+        )
+    }
+}
+
+impl Default for Limits {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[inline]
+fn check<S: Seek>(name: &str, value: u64, limit: u64, stream: &mut S) -> Result<(), Error> {
+    if value > limit {
+        Err(DeserializeError::limit_exceeded(name, value, limit, stream))?
+    }
+    Ok(())
+}
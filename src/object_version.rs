@@ -1,5 +1,25 @@
 use num_enum::IntoPrimitive;
 
+/// UE4 object versions that change how save game data is serialized.
+///
+/// Only the versions checked by this crate are listed.
+#[derive(IntoPrimitive)]
+#[repr(u32)]
+pub enum EUnrealEngineObjectUE4Version {
+    /// The oldest package version supported by UE4
+    OldestLoadablePackage = 214,
+
+    /// Struct property tags store the GUID of the struct
+    StructGuidInPropertyTag = 441,
+
+    /// Array property tags store the inner type, and arrays of structs start with a tag for the
+    /// struct
+    InnerArrayTagInfo = 500,
+
+    /// Property tags store an optional property GUID
+    PropertyGuidInPropertyTag = 503,
+}
+
 /// UE5 object versions.
 #[derive(IntoPrimitive)]
 #[repr(u32)]
@@ -44,4 +64,39 @@ pub enum EUnrealEngineObjectUE5Version {
 
     /// Added property tag complete type name and serialization type
     PropertyTagCompleteTypeName,
+
+    /// Changed the asset registry to store the build dependencies of packages
+    AssetregistryPackagebuilddependencies,
+
+    /// Added the meta data serialization offset to the package summary
+    MetadataSerializationOffset,
+
+    /// Added Verse cells to the object graph
+    VerseCells,
+
+    /// Changed the package summary to store the saved hash of the package instead of its GUID
+    PackageSavedHash,
+
+    /// Subobjects are serialized with overridable serialization shadows
+    OsSubObjectShadowSerialization,
+}
+
+/// Package file version, as stored in the GVAS header.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FPackageFileVersion {
+    /// File format version (UE4).
+    pub file_version_ue4: u32,
+    /// File format version (UE5), or zero if the file predates UE5.
+    pub file_version_ue5: u32,
+}
+
+impl FPackageFileVersion {
+    /// Creates a new `FPackageFileVersion` instance.
+    #[inline]
+    pub fn new(file_version_ue4: u32, file_version_ue5: u32) -> Self {
+        FPackageFileVersion {
+            file_version_ue4,
+            file_version_ue5,
+        }
+    }
 }
@@ -7,16 +7,18 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use ordered_float::OrderedFloat;
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     error::{DeserializeError, Error, SerializeError},
+    object_version::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version},
     types::Guid,
 };
 
 use super::{
     enum_property::EnumProperty,
-    impl_read_header, impl_write, impl_write_header_part,
+    impl_read_tag,
     int_property::{BoolProperty, ByteProperty, BytePropertyValue, FloatProperty, IntProperty},
     name_property::NameProperty,
+    property_tag::{EPropertyTagFlags, PropertyTag, PropertyTagData, PropertyTypeName},
     str_property::StrProperty,
     struct_property::{StructProperty, StructPropertyValue},
     Property, PropertyOptions, PropertyTrait,
@@ -33,42 +35,66 @@ use serde_with::{hex::Hex, serde_as};
 pub enum ArrayProperty {
     /// An array of BoolProperty values.
     Bools {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         bools: Vec<bool>,
     },
     /// An array of ByteProperty values.
     Bytes {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         #[cfg_attr(feature = "serde", serde_as(as = "Hex"))]
         bytes: Vec<u8>,
     },
     /// An array of EnumProperty values.
     Enums {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         enums: Vec<String>,
     },
     /// An array of FloatProperty values.
     Floats {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         floats: Vec<OrderedFloat<f32>>,
     },
     /// An array of IntProperty values.
     Ints {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         ints: Vec<i32>,
     },
     /// An array of NameProperty values.
     Names {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         names: Vec<Option<String>>,
     },
     /// An array of StrProperty values.
     Strings {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// An array of values.
         strings: Vec<Option<String>>,
     },
     /// An array of StructProperty values.
     Structs {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// Field name.
         field_name: String,
         /// Type name.
@@ -82,6 +108,9 @@ pub enum ArrayProperty {
     },
     /// Any other Property value
     Properties {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// The type of Property in `properties`.
         property_type: String,
         /// An array of values.
@@ -112,13 +141,17 @@ impl ArrayProperty {
             ("BoolProperty", None) => match properties
                 .iter()
                 .map(|p| match p {
-                    Property::BoolProperty(BoolProperty { value }) => Ok(*value),
+                    Property::BoolProperty(BoolProperty { value, .. }) => Ok(*value),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(bools) => Ok(ArrayProperty::Bools { bools }),
+                Ok(bools) => Ok(ArrayProperty::Bools {
+                    tag: PropertyTagData::default(),
+                    bools,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -130,13 +163,18 @@ impl ArrayProperty {
                     Property::ByteProperty(ByteProperty {
                         name: None,
                         value: BytePropertyValue::Byte(value),
+                        ..
                     }) => Ok(*value),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(bytes) => Ok(ArrayProperty::Bytes { bytes }),
+                Ok(bytes) => Ok(ArrayProperty::Bytes {
+                    tag: PropertyTagData::default(),
+                    bytes,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -148,13 +186,18 @@ impl ArrayProperty {
                     Property::EnumProperty(EnumProperty {
                         enum_type: None,
                         value,
+                        ..
                     }) => Ok(value.to_owned()),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(enums) => Ok(ArrayProperty::Enums { enums }),
+                Ok(enums) => Ok(ArrayProperty::Enums {
+                    tag: PropertyTagData::default(),
+                    enums,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -163,13 +206,17 @@ impl ArrayProperty {
             ("IntProperty", None) => match properties
                 .iter()
                 .map(|p| match p {
-                    Property::IntProperty(IntProperty { value }) => Ok(*value),
+                    Property::IntProperty(IntProperty { value, .. }) => Ok(*value),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(ints) => Ok(ArrayProperty::Ints { ints }),
+                Ok(ints) => Ok(ArrayProperty::Ints {
+                    tag: PropertyTagData::default(),
+                    ints,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -178,13 +225,17 @@ impl ArrayProperty {
             ("FloatProperty", None) => match properties
                 .iter()
                 .map(|p| match p {
-                    Property::FloatProperty(FloatProperty { value }) => Ok(value.to_owned()),
+                    Property::FloatProperty(FloatProperty { value, .. }) => Ok(value.to_owned()),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(floats) => Ok(ArrayProperty::Floats { floats }),
+                Ok(floats) => Ok(ArrayProperty::Floats {
+                    tag: PropertyTagData::default(),
+                    floats,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -193,16 +244,19 @@ impl ArrayProperty {
             ("NameProperty", None) => match properties
                 .iter()
                 .map(|p| match p {
-                    Property::NameProperty(NameProperty {
-                        array_index: 0,
-                        value,
-                    }) => Ok(value.to_owned()),
+                    Property::NameProperty(NameProperty { tag, value }) if tag.array_index == 0 => {
+                        Ok(value.to_owned())
+                    }
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(names) => Ok(ArrayProperty::Names { names }),
+                Ok(names) => Ok(ArrayProperty::Names {
+                    tag: PropertyTagData::default(),
+                    names,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -211,13 +265,17 @@ impl ArrayProperty {
             ("StrProperty", None) => match properties
                 .iter()
                 .map(|p| match p {
-                    Property::StrProperty(StrProperty { value }) => Ok(value.to_owned()),
+                    Property::StrProperty(StrProperty { value, .. }) => Ok(value.to_owned()),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
-                Ok(strings) => Ok(ArrayProperty::Strings { strings }),
+                Ok(strings) => Ok(ArrayProperty::Strings {
+                    tag: PropertyTagData::default(),
+                    strings,
+                }),
                 Err(()) => Ok(ArrayProperty::Properties {
+                    tag: PropertyTagData::default(),
                     property_type,
                     properties,
                 }),
@@ -232,6 +290,7 @@ impl ArrayProperty {
                 .collect::<Result<_, _>>()
             {
                 Ok(structs) => Ok(ArrayProperty::Structs {
+                    tag: PropertyTagData::default(),
                     field_name,
                     type_name,
                     guid,
@@ -248,6 +307,7 @@ impl ArrayProperty {
             ))?,
 
             (_, None) => Ok(ArrayProperty::Properties {
+                tag: PropertyTagData::default(),
                 property_type,
                 properties,
             }),
@@ -256,23 +316,31 @@ impl ArrayProperty {
 
     pub(crate) fn get_property_type(&self) -> Result<String, Error> {
         Ok(match self {
-            ArrayProperty::Bools { bools: _ } => "BoolProperty".to_string(),
-            ArrayProperty::Bytes { bytes: _ } => "ByteProperty".to_string(),
-            ArrayProperty::Enums { enums: _ } => "EnumProperty".to_string(),
-            ArrayProperty::Floats { floats: _ } => "FloatProperty".to_string(),
-            ArrayProperty::Ints { ints: _ } => "IntProperty".to_string(),
-            ArrayProperty::Names { names: _ } => "NameProperty".to_string(),
-            ArrayProperty::Strings { strings: _ } => "StrProperty".to_string(),
+            ArrayProperty::Bools { .. } => "BoolProperty".to_string(),
+            ArrayProperty::Bytes { .. } => "ByteProperty".to_string(),
+            ArrayProperty::Enums { .. } => "EnumProperty".to_string(),
+            ArrayProperty::Floats { .. } => "FloatProperty".to_string(),
+            ArrayProperty::Ints { .. } => "IntProperty".to_string(),
+            ArrayProperty::Names { .. } => "NameProperty".to_string(),
+            ArrayProperty::Strings { .. } => "StrProperty".to_string(),
+            ArrayProperty::Structs { .. } => "StructProperty".to_string(),
+            ArrayProperty::Properties { property_type, .. } => property_type.clone(),
+        })
+    }
+
+    /// Derives the type parameter of a UE 5.4+ property tag from the array.
+    fn get_type_parameter(&self) -> Result<PropertyTypeName, Error> {
+        Ok(match self {
             ArrayProperty::Structs {
-                field_name: _,
-                type_name: _,
-                guid: _,
-                structs: _,
-            } => "StructProperty".to_string(),
-            ArrayProperty::Properties {
-                property_type,
-                properties: _,
-            } => property_type.clone(),
+                type_name, guid, ..
+            } => {
+                let mut parameters = vec![PropertyTypeName::from(type_name.as_str())];
+                if !guid.is_zero() {
+                    parameters.push(PropertyTypeName::from_guid(guid));
+                }
+                PropertyTypeName::new("StructProperty".to_string(), parameters)
+            }
+            _ => PropertyTypeName::from(self.get_property_type()?),
         })
     }
 
@@ -292,7 +360,116 @@ impl ArrayProperty {
         }
     }
 
-    impl_read_header!(options, length, property_type);
+    /// Read GVAS property data from a reader.
+    #[inline]
+    pub fn read_header<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let (tag, property_type) =
+            match options.supports_ue4_version(EUnrealEngineObjectUE4Version::InnerArrayTagInfo) {
+                true => impl_read_tag!(cursor, options, property_type),
+                false => Self::read_legacy_tag(cursor, options)?,
+            };
+        let length = tag.length;
+
+        let start = cursor.stream_position()?;
+        let mut result = match property_type.as_str() {
+            "StructProperty"
+                if options.supports_ue5_version(
+                    EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName,
+                ) =>
+            {
+                // UE 5.4+ struct arrays have no inner tag, the struct type is part of the
+                // complete type name instead.
+                let parameter = tag.get_parameter(0, "property_type", cursor)?;
+                let struct_name = match parameter.get_parameter_name(0) {
+                    Some(struct_name) => struct_name.to_string(),
+                    None => Err(DeserializeError::missing_argument("type_name", cursor))?,
+                };
+                let guid = match parameter.parameters.get(1) {
+                    Some(guid) => guid.to_guid().ok_or_else(|| {
+                        DeserializeError::invalid_property(
+                            format!("Invalid struct guid {}", guid.name),
+                            cursor,
+                        )
+                    })?,
+                    None => Guid::default(),
+                };
+                // The field name is the name of the array property itself.
+                let properties_stack = &options.properties_stack;
+                let field_name = match properties_stack.len().checked_sub(2) {
+                    Some(index) => properties_stack[index].clone(),
+                    None => String::new(),
+                };
+
+                let property_count = cursor.read_u32::<LittleEndian>()?;
+                options.limits.check_element_count(property_count, cursor)?;
+                let properties = Self::read_structs(cursor, options, property_count, &struct_name)?;
+                ArrayProperty::new(
+                    property_type,
+                    Some((field_name, struct_name, guid)),
+                    properties,
+                )?
+            }
+            _ => Self::read_body(cursor, options, length, property_type)?,
+        };
+        let end = cursor.stream_position()?;
+        if end - start != length as u64 {
+            Err(DeserializeError::InvalidValueSize(
+                length as u64,
+                end - start,
+                start,
+            ))?
+        }
+
+        if let Some(data) = result.tag_mut() {
+            *data = tag.data;
+        }
+        Ok(result)
+    }
+
+    /// Reads an array property tag predating UE4 version `InnerArrayTagInfo`
+    ///
+    /// These tags don't store the inner type, so it's taken from the hint for the array path.
+    #[inline]
+    fn read_legacy_tag<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<(PropertyTag, String), Error> {
+        let length = cursor.read_u32::<LittleEndian>()?;
+        let array_index = cursor.read_u32::<LittleEndian>()?;
+        let data = PropertyTagData::read(cursor, options)?;
+
+        let array_path = options.properties_stack.join(".");
+        let Some(property_type) = options.hints.get(&array_path) else {
+            Err(DeserializeError::MissingHint(
+                "ArrayProperty".into(),
+                array_path.into_boxed_str(),
+                cursor.stream_position()?,
+            ))?
+        };
+        Ok((
+            PropertyTag::new(length, array_index, data),
+            property_type.clone(),
+        ))
+    }
+
+    #[inline]
+    fn read_structs<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+        property_count: u32,
+        struct_name: &str,
+    ) -> Result<Vec<Property>, Error> {
+        let mut properties: Vec<Property> =
+            Vec::with_capacity(bounded_capacity(property_count as usize));
+        for _ in 0..property_count {
+            let value = StructProperty::read_body(cursor, struct_name, options)?;
+            properties.push(Property::from(value));
+        }
+        Ok(properties)
+    }
 
     #[inline]
     pub(crate) fn read_body<R: Read + Seek>(
@@ -302,31 +479,70 @@ impl ArrayProperty {
         property_type: String,
     ) -> Result<Self, Error> {
         let property_count = cursor.read_u32::<LittleEndian>()?;
-        let mut properties: Vec<Property> = Vec::with_capacity(property_count as usize);
+        options.limits.check_element_count(property_count, cursor)?;
+        let mut properties: Vec<Property> = Vec::new();
 
         let mut array_struct_info = None;
 
         match property_type.as_str() {
-            "StructProperty" => {
-                let field_name = cursor.read_string()?;
+            "StructProperty"
+                if !options
+                    .supports_ue4_version(EUnrealEngineObjectUE4Version::InnerArrayTagInfo) =>
+            {
+                // Struct arrays predating UE4 version `InnerArrayTagInfo` have no inner tag, the
+                // struct type is taken from the hint for the struct path instead.
+                options.properties_stack.push(property_type.clone());
+                let struct_path = options.properties_stack.join(".");
+                options.properties_stack.pop();
+                let Some(struct_name) = options.hints.get(&struct_path).cloned() else {
+                    Err(DeserializeError::MissingHint(
+                        "StructProperty".into(),
+                        struct_path.into_boxed_str(),
+                        cursor.stream_position()?,
+                    ))?
+                };
+                let properties_stack = &options.properties_stack;
+                let field_name = match properties_stack.len().checked_sub(2) {
+                    Some(index) => properties_stack[index].clone(),
+                    None => String::new(),
+                };
 
-                let property_type = cursor.read_string()?;
-                assert_eq!(property_type, "StructProperty");
+                properties = Self::read_structs(cursor, options, property_count, &struct_name)?;
+                array_struct_info = Some((field_name, struct_name, Guid::default()));
+            }
+            "StructProperty" => {
+                let field_name = cursor.read_string_limited(options.limits.max_string_length)?;
+
+                let inner_type = cursor.read_string_limited(options.limits.max_string_length)?;
+                if inner_type != "StructProperty" {
+                    let position = cursor.stream_position()?;
+                    Err(DeserializeError::UnexpectedPropertyType(
+                        "StructProperty".into(),
+                        inner_type.into_boxed_str(),
+                        position,
+                    ))?
+                }
                 let properties_size = cursor.read_u64::<LittleEndian>()?;
 
-                let struct_name = cursor.read_string()?;
+                let struct_name = cursor.read_string_limited(options.limits.max_string_length)?;
                 let guid = cursor.read_guid()?;
                 let terminator = cursor.read_u8()?;
                 if terminator != 0 {
                     let position = cursor.stream_position()? - 1;
                     Err(DeserializeError::InvalidTerminator(terminator, position))?
                 }
+                if options.supports_ue5_version(
+                    EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization,
+                ) {
+                    let extensions = cursor.read_u8()?;
+                    if extensions != 0 {
+                        let position = cursor.stream_position()? - 1;
+                        Err(DeserializeError::InvalidTagExtensions(extensions, position))?
+                    }
+                }
 
                 let properties_start = cursor.stream_position()?;
-                for _ in 0..property_count {
-                    let value = StructProperty::read_body(cursor, &struct_name, options)?;
-                    properties.push(Property::from(value));
-                }
+                properties = Self::read_structs(cursor, options, property_count, &struct_name)?;
                 let properties_end = cursor.stream_position()?;
                 let actual_size = properties_end - properties_start;
                 validate!(
@@ -338,6 +554,12 @@ impl ArrayProperty {
                 array_struct_info = Some((field_name, struct_name, guid));
             }
             _ => {
+                validate!(
+                    cursor,
+                    property_count <= length.saturating_sub(4),
+                    "{property_count} array elements don't fit in {length} bytes",
+                );
+                properties.reserve(bounded_capacity(property_count as usize));
                 let suggested_length = if property_count > 0 && length >= 4 {
                     Some((length - 4) / property_count)
                 } else {
@@ -360,7 +582,72 @@ impl ArrayProperty {
 }
 
 impl PropertyTrait for ArrayProperty {
-    impl_write!(ArrayProperty, (write_string, fn, get_property_type));
+    #[inline]
+    fn write<W: Write>(
+        &self,
+        writer: &mut W,
+        include_header: bool,
+        options: &mut PropertyOptions,
+    ) -> Result<usize, Error> {
+        if !include_header {
+            return self.write_body(writer, options);
+        }
+
+        let buf = &mut Cursor::new(Vec::new());
+        let mut len = self.write_body(buf, options)?;
+        let buf = buf.get_ref();
+
+        let default_tag = PropertyTagData::default();
+        let tag = self.tag().unwrap_or(&default_tag);
+
+        len += writer.write_string("ArrayProperty")?;
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let parameters = [self.get_type_parameter()?];
+            len += tag.write_complete(writer, &parameters, buf.len(), EPropertyTagFlags::NONE)?;
+        } else {
+            len += 8;
+            writer.write_u32::<LittleEndian>(buf.len() as u32)?;
+            writer.write_u32::<LittleEndian>(tag.array_index)?;
+            if options.supports_ue4_version(EUnrealEngineObjectUE4Version::InnerArrayTagInfo) {
+                len += writer.write_string(&self.get_property_type()?)?;
+            }
+            len += tag.write(writer, options)?;
+        }
+        writer.write_all(buf)?;
+
+        Ok(len)
+    }
+
+    #[inline]
+    fn tag(&self) -> Option<&PropertyTagData> {
+        match self {
+            ArrayProperty::Bools { tag, .. }
+            | ArrayProperty::Bytes { tag, .. }
+            | ArrayProperty::Enums { tag, .. }
+            | ArrayProperty::Floats { tag, .. }
+            | ArrayProperty::Ints { tag, .. }
+            | ArrayProperty::Names { tag, .. }
+            | ArrayProperty::Strings { tag, .. }
+            | ArrayProperty::Structs { tag, .. }
+            | ArrayProperty::Properties { tag, .. } => Some(tag),
+        }
+    }
+
+    #[inline]
+    fn tag_mut(&mut self) -> Option<&mut PropertyTagData> {
+        match self {
+            ArrayProperty::Bools { tag, .. }
+            | ArrayProperty::Bytes { tag, .. }
+            | ArrayProperty::Enums { tag, .. }
+            | ArrayProperty::Floats { tag, .. }
+            | ArrayProperty::Ints { tag, .. }
+            | ArrayProperty::Names { tag, .. }
+            | ArrayProperty::Strings { tag, .. }
+            | ArrayProperty::Structs { tag, .. }
+            | ArrayProperty::Properties { tag, .. } => Some(tag),
+        }
+    }
 
     #[inline]
     fn write_body<W: Write>(
@@ -369,7 +656,7 @@ impl PropertyTrait for ArrayProperty {
         options: &mut PropertyOptions,
     ) -> Result<usize, Error> {
         match self {
-            ArrayProperty::Bools { bools } => {
+            ArrayProperty::Bools { bools, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(bools.len() as u32)?;
                 for b in bools {
@@ -379,7 +666,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Bytes { bytes } => {
+            ArrayProperty::Bytes { bytes, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(bytes.len() as u32)?;
                 for b in bytes {
@@ -389,7 +676,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Enums { enums } => {
+            ArrayProperty::Enums { enums, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(enums.len() as u32)?;
                 for e in enums {
@@ -399,7 +686,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Floats { floats } => {
+            ArrayProperty::Floats { floats, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(floats.len() as u32)?;
                 for f in floats {
@@ -409,7 +696,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Ints { ints } => {
+            ArrayProperty::Ints { ints, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(ints.len() as u32)?;
                 for i in ints {
@@ -419,7 +706,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Names { names } => {
+            ArrayProperty::Names { names, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(names.len() as u32)?;
                 for n in names {
@@ -429,7 +716,7 @@ impl PropertyTrait for ArrayProperty {
                 Ok(len)
             }
 
-            ArrayProperty::Strings { strings } => {
+            ArrayProperty::Strings { strings, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(strings.len() as u32)?;
                 for s in strings {
@@ -444,7 +731,12 @@ impl PropertyTrait for ArrayProperty {
                 type_name,
                 guid,
                 structs,
-            } => {
+                ..
+            } if options.supports_ue4_version(EUnrealEngineObjectUE4Version::InnerArrayTagInfo)
+                && !options.supports_ue5_version(
+                    EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName,
+                ) =>
+            {
                 let mut len = 29;
                 cursor.write_u32::<LittleEndian>(structs.len() as u32)?;
                 len += cursor.write_string(field_name)?;
@@ -460,14 +752,26 @@ impl PropertyTrait for ArrayProperty {
                 len += cursor.write_string(type_name)?;
                 cursor.write_guid(guid)?;
                 cursor.write_u8(0)?;
+                if options.supports_ue5_version(
+                    EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization,
+                ) {
+                    cursor.write_u8(0)?;
+                    len += 1;
+                }
                 cursor.write_all(buf)?;
                 Ok(len)
             }
 
-            ArrayProperty::Properties {
-                property_type: _,
-                properties,
-            } => {
+            ArrayProperty::Structs { structs, .. } => {
+                let mut len = 4;
+                cursor.write_u32::<LittleEndian>(structs.len() as u32)?;
+                for property in structs {
+                    len += property.write(cursor, false, options)?;
+                }
+                Ok(len)
+            }
+
+            ArrayProperty::Properties { properties, .. } => {
                 let mut len = 4;
                 cursor.write_u32::<LittleEndian>(properties.len() as u32)?;
                 for property in properties {
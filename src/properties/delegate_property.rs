@@ -3,11 +3,14 @@ use std::io::{Cursor, Read, Seek, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     error::Error,
 };
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// An Unreal script delegate
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -30,9 +33,12 @@ impl Delegate {
     }
 
     #[inline]
-    pub(crate) fn read<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let object = cursor.read_string()?;
-        let function_name = cursor.read_string()?;
+    pub(crate) fn read<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+    ) -> Result<Self, Error> {
+        let object = cursor.read_string_limited(options.limits.max_string_length)?;
+        let function_name = cursor.read_string_limited(options.limits.max_string_length)?;
         Ok(Delegate {
             object,
             function_name,
@@ -52,6 +58,9 @@ impl Delegate {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DelegateProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Delegate
     pub value: Delegate,
 }
@@ -60,21 +69,28 @@ impl DelegateProperty {
     /// Creates a new `DelegateProperty` instance
     #[inline]
     pub fn new(value: Delegate) -> Self {
-        DelegateProperty { value }
+        DelegateProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = Delegate::read(cursor)?;
-        Ok(DelegateProperty { value })
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = Delegate::read(cursor, options)?;
+        Ok(DelegateProperty::new(value))
     }
 }
 
 impl PropertyTrait for DelegateProperty {
     impl_write!(DelegateProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -103,11 +119,15 @@ impl MulticastScriptDelegate {
     }
 
     #[inline]
-    pub(crate) fn read<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
+    pub(crate) fn read<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+    ) -> Result<Self, Error> {
         let delegates_len = cursor.read_u32::<LittleEndian>()?;
-        let mut delegates = Vec::with_capacity(delegates_len as usize);
+        options.limits.check_element_count(delegates_len, cursor)?;
+        let mut delegates = Vec::with_capacity(bounded_capacity(delegates_len as usize));
         for _ in 0..delegates_len {
-            delegates.push(Delegate::read(cursor)?);
+            delegates.push(Delegate::read(cursor, options)?);
         }
 
         Ok(MulticastScriptDelegate { delegates })
@@ -130,6 +150,9 @@ impl MulticastScriptDelegate {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MulticastInlineDelegateProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Delegate
     pub value: MulticastScriptDelegate,
 }
@@ -138,21 +161,28 @@ impl MulticastInlineDelegateProperty {
     /// Creates a new `MulticastInlineDelegateProperty` instance
     #[inline]
     pub fn new(value: MulticastScriptDelegate) -> Self {
-        MulticastInlineDelegateProperty { value }
+        MulticastInlineDelegateProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    pub(crate) fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = MulticastScriptDelegate::read(cursor)?;
-        Ok(MulticastInlineDelegateProperty { value })
+    pub(crate) fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = MulticastScriptDelegate::read(cursor, options)?;
+        Ok(MulticastInlineDelegateProperty::new(value))
     }
 }
 
 impl PropertyTrait for MulticastInlineDelegateProperty {
     impl_write!(MulticastInlineDelegateProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -169,6 +199,9 @@ impl PropertyTrait for MulticastInlineDelegateProperty {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MulticastSparseDelegateProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Delegate
     pub value: MulticastScriptDelegate,
 }
@@ -177,21 +210,28 @@ impl MulticastSparseDelegateProperty {
     /// Creates a new `MulticastSparseDelegateProperty` instance
     #[inline]
     pub fn new(value: MulticastScriptDelegate) -> Self {
-        MulticastSparseDelegateProperty { value }
+        MulticastSparseDelegateProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    pub(crate) fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = MulticastScriptDelegate::read(cursor)?;
-        Ok(MulticastSparseDelegateProperty { value })
+    pub(crate) fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = MulticastScriptDelegate::read(cursor, options)?;
+        Ok(MulticastSparseDelegateProperty::new(value))
     }
 }
 
 impl PropertyTrait for MulticastSparseDelegateProperty {
     impl_write!(MulticastSparseDelegateProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -7,13 +7,19 @@ use crate::{
     error::Error,
 };
 
-use super::{impl_read_header, impl_write, impl_write_header_part, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read_header, impl_tag, impl_write, impl_write_header_part, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// A property that holds an enum value.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", serde_with::skip_serializing_none)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Enum Type.
     pub enum_type: Option<String>,
     /// Enum Value.
@@ -24,33 +30,43 @@ impl EnumProperty {
     /// Creates a new `EnumProperty` instance.
     #[inline]
     pub fn new(enum_type: Option<String>, value: String) -> Self {
-        EnumProperty { enum_type, value }
+        EnumProperty {
+            tag: PropertyTagData::default(),
+            enum_type,
+            value,
+        }
     }
 
     #[inline]
     pub(crate) fn read<R: Read + Seek>(
         cursor: &mut R,
         include_header: bool,
+        options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
         if include_header {
-            Self::read_header(cursor)
+            Self::read_header(cursor, options)
         } else {
-            Self::read_body(cursor, None)
+            Self::read_body(cursor, options, None)
         }
     }
 
     impl_read_header!(enum_type);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R, enum_type: Option<String>) -> Result<Self, Error> {
-        let value = cursor.read_string()?;
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+        enum_type: Option<String>,
+    ) -> Result<Self, Error> {
+        let value = cursor.read_string_limited(options.limits.max_string_length)?;
 
-        Ok(EnumProperty { enum_type, value })
+        Ok(EnumProperty::new(enum_type, value))
     }
 }
 
 impl PropertyTrait for EnumProperty {
     impl_write!(EnumProperty, (write_fstring, enum_type));
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
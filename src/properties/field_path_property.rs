@@ -3,11 +3,14 @@ use std::io::{Cursor, Read, Seek, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     error::Error,
 };
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// Field path
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -30,14 +33,18 @@ impl FieldPath {
     }
 
     #[inline]
-    pub(crate) fn read<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
+    pub(crate) fn read<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+    ) -> Result<Self, Error> {
         let path_len = cursor.read_u32::<LittleEndian>()?;
-        let mut path = Vec::with_capacity(path_len as usize);
+        options.limits.check_element_count(path_len, cursor)?;
+        let mut path = Vec::with_capacity(bounded_capacity(path_len as usize));
         for _ in 0..path_len {
-            path.push(cursor.read_string()?);
+            path.push(cursor.read_string_limited(options.limits.max_string_length)?);
         }
 
-        let resolved_owner = cursor.read_string()?;
+        let resolved_owner = cursor.read_string_limited(options.limits.max_string_length)?;
 
         Ok(FieldPath {
             path,
@@ -64,6 +71,9 @@ impl FieldPath {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldPathProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Field path
     pub value: FieldPath,
 }
@@ -72,22 +82,29 @@ impl FieldPathProperty {
     /// Creates a new `FieldPathProperty` instance
     #[inline]
     pub fn new(value: FieldPath) -> Self {
-        FieldPathProperty { value }
+        FieldPathProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = FieldPath::read(cursor)?;
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = FieldPath::read(cursor, options)?;
 
-        Ok(FieldPathProperty { value })
+        Ok(FieldPathProperty::new(value))
     }
 }
 
 impl PropertyTrait for FieldPathProperty {
     impl_write!(FieldPathProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
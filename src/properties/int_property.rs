@@ -7,25 +7,52 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use ordered_float::OrderedFloat;
 
 use super::{
-    impl_write,
+    impl_tag, impl_write,
+    property_tag::{EPropertyTagFlags, PropertyTag, PropertyTagData, PropertyTypeName},
     struct_types::{unwrap_value, wrap_type, wrap_value},
     PropertyOptions, PropertyTrait,
 };
 use crate::{
     cursor_ext::{ReadExt, WriteExt},
     error::{DeserializeError, Error},
+    object_version::EUnrealEngineObjectUE5Version,
 };
 
 macro_rules! check_size {
-    ($cursor:ident, $expected:literal) => {
-        let value_size = $cursor.read_u64::<LittleEndian>()?;
+    ($cursor:ident, $expected:literal) => {{
+        let value_size = $cursor.read_u32::<LittleEndian>()?;
+        let array_index = $cursor.read_u32::<LittleEndian>()?;
         if value_size != $expected {
             Err(DeserializeError::InvalidValueSize(
                 $expected,
-                value_size,
+                value_size as u64,
                 $cursor.stream_position()?,
             ))?
         }
+        array_index
+    }};
+}
+
+macro_rules! read_tag {
+    ($cursor:ident, $options:ident, $expected:literal) => {
+        if $options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let tag = PropertyTag::read_complete($cursor, &$options.limits)?;
+            if tag.length != $expected {
+                Err(DeserializeError::InvalidValueSize(
+                    $expected,
+                    tag.length as u64,
+                    $cursor.stream_position()?,
+                ))?
+            }
+            tag.data
+        } else {
+            let array_index = check_size!($cursor, $expected);
+            PropertyTagData {
+                array_index,
+                ..PropertyTagData::read($cursor, $options)?
+            }
+        }
     };
 }
 
@@ -35,6 +62,9 @@ macro_rules! impl_int_property {
         #[derive(Clone, PartialEq, Eq, Hash)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name {
+            /// Property tag data.
+            #[cfg_attr(feature = "serde", serde(flatten))]
+            pub tag: PropertyTagData,
             /// Integer value.
             pub value: wrap_type!($ty),
         }
@@ -44,20 +74,25 @@ macro_rules! impl_int_property {
             #[inline]
             pub fn new(value: $ty) -> Self {
                 let value = wrap_value!($ty, value);
-                Self { value }
+                Self {
+                    tag: PropertyTagData::default(),
+                    value,
+                }
             }
 
             #[inline]
             pub(crate) fn read<R: Read + Seek>(
                 cursor: &mut R,
                 include_header: bool,
+                options: &mut PropertyOptions,
             ) -> Result<Self, Error> {
-                if include_header {
-                    check_size!(cursor, $size);
-                    let separator = cursor.read_u8()?;
-                    assert_eq!(separator, 0);
-                }
-                Ok(Self::new(cursor.$read_method::<LittleEndian>()?))
+                let tag = match include_header {
+                    true => read_tag!(cursor, options, $size),
+                    false => PropertyTagData::default(),
+                };
+                let value = cursor.$read_method::<LittleEndian>()?;
+                let value = wrap_value!($ty, value);
+                Ok(Self { tag, value })
             }
         }
 
@@ -69,6 +104,7 @@ macro_rules! impl_int_property {
 
         impl PropertyTrait for $name {
             impl_write!($name);
+            impl_tag!();
 
             #[inline]
             fn write_body<W: Write>(
@@ -90,6 +126,9 @@ macro_rules! impl_int_property {
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Int8Property {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Integer value.
     pub value: i8,
 }
@@ -98,20 +137,24 @@ impl Int8Property {
     /// Creates a new `Int8Property` instance.
     #[inline]
     pub fn new(value: i8) -> Self {
-        Int8Property { value }
+        Int8Property {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
     #[inline]
     pub(crate) fn read<R: Read + Seek>(
         cursor: &mut R,
         include_header: bool,
+        options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
-        if include_header {
-            check_size!(cursor, 1);
-            let separator = cursor.read_u8()?;
-            assert_eq!(separator, 0);
-        }
+        let tag = match include_header {
+            true => read_tag!(cursor, options, 1),
+            false => PropertyTagData::default(),
+        };
         Ok(Int8Property {
+            tag,
             value: cursor.read_i8()?,
         })
     }
@@ -119,6 +162,7 @@ impl Int8Property {
 
 impl PropertyTrait for Int8Property {
     impl_write!(Int8Property);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -152,6 +196,9 @@ pub enum BytePropertyValue {
 #[cfg_attr(feature = "serde", serde_with::skip_serializing_none)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ByteProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Property name.
     pub name: Option<String>,
     /// Property value.
@@ -163,63 +210,74 @@ impl ByteProperty {
     /// Creates a new `ByteProperty` instance.
     #[inline]
     pub fn new(name: Option<String>, value: BytePropertyValue) -> Self {
-        ByteProperty { name, value }
+        ByteProperty {
+            tag: PropertyTagData::default(),
+            name,
+            value,
+        }
     }
 
     /// Creates a new `ByteProperty` instance for a u8 value
     #[inline]
     pub fn new_byte(name: Option<String>, value: u8) -> Self {
-        ByteProperty {
-            name,
-            value: BytePropertyValue::Byte(value),
-        }
+        Self::new(name, BytePropertyValue::Byte(value))
     }
 
     /// Creates a new `ByteProperty` instance for a namespaced enum value
     #[inline]
     pub fn new_namespaced(name: Option<String>, value: String) -> Self {
-        ByteProperty {
-            name,
-            value: BytePropertyValue::Namespaced(value),
-        }
+        Self::new(name, BytePropertyValue::Namespaced(value))
     }
 
     #[inline]
     pub(crate) fn read<R: Read + Seek>(
         cursor: &mut R,
         include_header: bool,
+        options: &mut PropertyOptions,
         mut suggested_length: Option<u32>,
     ) -> Result<Self, Error> {
         let mut name = None;
-        if include_header {
+        let mut tag = PropertyTagData::default();
+        if include_header
+            && options
+                .supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let complete_tag = PropertyTag::read_complete(cursor, &options.limits)?;
+            suggested_length = Some(complete_tag.length);
+            tag = complete_tag.data;
+
+            // Unlike older tags, the enum name is omitted for plain bytes.
+            let enum_name = tag.type_parameters.first().map(|p| p.name.as_str());
+            name = Some(enum_name.unwrap_or("None").to_string());
+        } else if include_header {
             let length = cursor.read_u32::<LittleEndian>()?;
             let array_index = cursor.read_u32::<LittleEndian>()?;
-            assert_eq!(
-                array_index,
-                0,
-                "Expected array_index value zero @ {:#x}",
-                cursor.stream_position()? - 4
-            );
             suggested_length = Some(length);
 
-            name = Some(cursor.read_string()?);
-            let separator = cursor.read_u8()?;
-            assert_eq!(separator, 0);
+            name = Some(cursor.read_string_limited(options.limits.max_string_length)?);
+            tag = PropertyTagData {
+                array_index,
+                ..PropertyTagData::read(cursor, options)?
+            };
         }
 
         // -1 to account for separator
-        let length = suggested_length.map(|e| e - 1).unwrap_or(1);
+        let length = suggested_length.map(|e| e.saturating_sub(1)).unwrap_or(1);
 
         let value = match length {
             1 | 0 => BytePropertyValue::Byte(cursor.read_u8()?),
-            _ => BytePropertyValue::Namespaced(cursor.read_string()?),
+            _ => BytePropertyValue::Namespaced(
+                cursor.read_string_limited(options.limits.max_string_length)?,
+            ),
         };
 
-        Ok(ByteProperty { name, value })
+        Ok(ByteProperty { tag, name, value })
     }
 }
 
 impl PropertyTrait for ByteProperty {
+    impl_tag!();
+
     #[inline]
     fn write<W: Write>(
         &self,
@@ -231,16 +289,29 @@ impl PropertyTrait for ByteProperty {
             return self.write_body(cursor, options);
         }
 
-        let mut len = 9;
-        len += cursor.write_string("ByteProperty")?;
-
         let buf = &mut Cursor::new(Vec::new());
-        len += self.write_body(buf, options)?;
+        let mut len = self.write_body(buf, options)?;
         let buf = buf.get_ref();
 
-        cursor.write_u64::<LittleEndian>(buf.len() as u64)?;
+        len += cursor.write_string("ByteProperty")?;
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let parameters: Vec<PropertyTypeName> = match self.name.as_deref() {
+                None | Some("None") => Vec::new(),
+                Some(name) => vec![PropertyTypeName::from(name)],
+            };
+            len +=
+                self.tag
+                    .write_complete(cursor, &parameters, buf.len(), EPropertyTagFlags::NONE)?;
+            cursor.write_all(buf)?;
+            return Ok(len);
+        }
+
+        len += 8;
+        cursor.write_u32::<LittleEndian>(buf.len() as u32)?;
+        cursor.write_u32::<LittleEndian>(self.tag.array_index)?;
         len += cursor.write_fstring(self.name.as_deref())?;
-        cursor.write_u8(0)?;
+        len += self.tag.write(cursor, options)?;
         cursor.write_all(buf)?;
 
         Ok(len)
@@ -266,6 +337,9 @@ impl PropertyTrait for ByteProperty {
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Boolean value.
     pub value: bool,
 }
@@ -274,23 +348,51 @@ impl BoolProperty {
     /// Creates a new `BoolProperty` instance.
     #[inline]
     pub fn new(value: bool) -> Self {
-        BoolProperty { value }
+        BoolProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
     #[inline]
     pub(crate) fn read<R: Read + Seek>(
         cursor: &mut R,
         include_header: bool,
+        options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
-        if include_header {
-            check_size!(cursor, 0);
+        if include_header
+            && options
+                .supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            // The value is stored in the tag flags.
+            let tag = PropertyTag::read_complete(cursor, &options.limits)?;
+            if tag.length != 0 {
+                Err(DeserializeError::InvalidValueSize(
+                    0,
+                    tag.length as u64,
+                    cursor.stream_position()?,
+                ))?
+            }
+            let value = tag.flags.contains(EPropertyTagFlags::BOOL_TRUE);
+            return Ok(BoolProperty {
+                tag: tag.data,
+                value,
+            });
         }
+
+        let array_index = match include_header {
+            true => check_size!(cursor, 0),
+            false => 0,
+        };
         let value = cursor.read_bool()?;
+        let mut property = BoolProperty::new(value);
         if include_header {
-            let indicator = cursor.read_u8()?;
-            assert_eq!(indicator, 0);
+            property.tag = PropertyTagData {
+                array_index,
+                ..PropertyTagData::read(cursor, options)?
+            };
         }
-        Ok(BoolProperty { value })
+        Ok(property)
     }
 }
 
@@ -301,30 +403,50 @@ impl Debug for BoolProperty {
 }
 
 impl PropertyTrait for BoolProperty {
+    impl_tag!();
+
     #[inline]
     fn write<W: Write>(
         &self,
         cursor: &mut W,
         include_header: bool,
-        _options: &mut PropertyOptions,
+        options: &mut PropertyOptions,
     ) -> Result<usize, Error> {
+        if include_header
+            && options
+                .supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let mut len = cursor.write_string("BoolProperty")?;
+            let flags = match self.value {
+                true => EPropertyTagFlags::BOOL_TRUE,
+                false => EPropertyTagFlags::NONE,
+            };
+            len += self.tag.write_complete(cursor, &[], 0, flags)?;
+            return Ok(len);
+        }
+
         let mut len = 0;
         if include_header {
             len += cursor.write_string("BoolProperty")?;
             len += 8;
-            cursor.write_u64::<LittleEndian>(0)?;
+            cursor.write_u32::<LittleEndian>(0)?;
+            cursor.write_u32::<LittleEndian>(self.tag.array_index)?;
         }
-        len += 1;
-        cursor.write_bool(self.value)?;
+        len += self.write_body(cursor, options)?;
         if include_header {
-            len += 1;
-            cursor.write_u8(0)?;
+            len += self.tag.write(cursor, options)?;
         }
         Ok(len)
     }
 
-    fn write_body<W: Write>(&self, _: &mut W, _: &mut PropertyOptions) -> Result<usize, Error> {
-        unimplemented!()
+    #[inline]
+    fn write_body<W: Write>(
+        &self,
+        cursor: &mut W,
+        _: &mut PropertyOptions,
+    ) -> Result<usize, Error> {
+        cursor.write_bool(self.value)?;
+        Ok(1)
     }
 }
 
@@ -6,13 +6,14 @@ use std::{
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     error::{DeserializeError, Error},
     properties::{
         enum_property::EnumProperty,
         impl_read_header, impl_write, impl_write_header_part,
         int_property::{BoolProperty, IntProperty},
         name_property::NameProperty,
+        property_tag::PropertyTagData,
         str_property::StrProperty,
         Property, PropertyOptions, PropertyTrait,
     },
@@ -27,16 +28,43 @@ use crate::{
 pub enum MapProperty {
     /// Map<EnumProperty, BoolProperty>
     EnumBool {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         enum_bools: HashableIndexMap<String, bool>,
     },
     /// Map<EnumProperty, IntProperty>
     EnumInt {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         enum_ints: HashableIndexMap<String, i32>,
     },
     /// Map<EnumProperty, Property>
     EnumProperty {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Value type.
         value_type: String,
         /// Map entries.
@@ -44,16 +72,43 @@ pub enum MapProperty {
     },
     /// Map<NameProperty, BoolProperty>
     NameBool {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         name_bools: HashableIndexMap<String, bool>,
     },
     /// Map<NameProperty, IntProperty>
     NameInt {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         name_ints: HashableIndexMap<String, i32>,
     },
     /// Map<NameProperty, Property>
     NameProperty {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Value type.
         value_type: String,
         /// Map entries.
@@ -61,28 +116,62 @@ pub enum MapProperty {
     },
     /// Map<Property, Property>
     Properties {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
         /// Key type name.
         key_type: String,
         /// Value type name.
         value_type: String,
-        /// Allocation flags.
-        allocation_flags: u32,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<Property>,
         /// Map entries.
         #[cfg_attr(feature = "serde", serde(with = "crate::types::map::serde_seq"))]
         value: HashableIndexMap<Property, Property>,
     },
     /// Map<StrProperty, BoolProperty>
     StrBool {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         str_bools: HashableIndexMap<String, bool>,
     },
     /// Map<StrProperty, IntProperty>
     StrInt {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         str_ints: HashableIndexMap<String, i32>,
     },
     /// Map<StrProperty, Property>
     StrProperty {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Value type.
         value_type: String,
         /// Map entries.
@@ -90,6 +179,15 @@ pub enum MapProperty {
     },
     /// Map<StrProperty, StrProperty>
     StrStr {
+        /// Property tag data.
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        tag: PropertyTagData,
+        /// Keys removed from the map.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        keys_to_remove: Vec<String>,
         /// Map entries.
         str_strs: HashableIndexMap<String, Option<String>>,
     },
@@ -101,19 +199,48 @@ impl MapProperty {
     pub fn new(
         key_type: String,
         value_type: String,
-        allocation_flags: u32,
+        keys_to_remove: Vec<Property>,
         value: HashableIndexMap<Property, Property>,
     ) -> Self {
-        match (key_type.as_str(), value_type.as_str(), allocation_flags) {
-            ("EnumProperty", "BoolProperty", 0) => match value
+        let removed_keys = keys_to_remove
+            .iter()
+            .map(|key| match (key_type.as_str(), key) {
+                (
+                    "EnumProperty",
+                    Property::EnumProperty(EnumProperty {
+                        enum_type: None,
+                        value,
+                        ..
+                    }),
+                ) => Some(value.clone()),
+                (
+                    "NameProperty",
+                    Property::NameProperty(NameProperty {
+                        tag,
+                        value: Some(value),
+                    }),
+                ) if tag.array_index == 0 => Some(value.clone()),
+                (
+                    "StrProperty",
+                    Property::StrProperty(StrProperty {
+                        value: Some(value), ..
+                    }),
+                ) => Some(value.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match (key_type.as_str(), value_type.as_str(), removed_keys) {
+            ("EnumProperty", "BoolProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
                         Property::EnumProperty(EnumProperty {
                             enum_type: None,
                             value: key,
+                            ..
                         }),
-                        Property::BoolProperty(BoolProperty { value }),
+                        Property::BoolProperty(BoolProperty { value, .. }),
                     ) => Ok((key.clone(), *value)),
                     // _ => Err(e),
                     _ => Err(()),
@@ -121,6 +248,8 @@ impl MapProperty {
                 .collect::<Result<_, _>>()
             {
                 Ok(enum_bools) => MapProperty::EnumBool {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     enum_bools: HashableIndexMap(enum_bools),
                 },
                 // Err(e) => Err(SerializeError::invalid_value(&format!(
@@ -128,39 +257,44 @@ impl MapProperty {
                 //     key_type, value_type, e
                 // )))?,
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("EnumProperty", "IntProperty", 0) => match value
+            ("EnumProperty", "IntProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
                         Property::EnumProperty(EnumProperty {
                             enum_type: None,
                             value: key,
+                            ..
                         }),
-                        Property::IntProperty(IntProperty { value }),
+                        Property::IntProperty(IntProperty { value, .. }),
                     ) => Ok((key.clone(), *value)),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(enum_ints) => MapProperty::EnumInt {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     enum_ints: HashableIndexMap(enum_ints),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("EnumProperty", _, 0) => {
+            ("EnumProperty", _, Some(removed_keys)) => {
                 match value
                     .iter()
                     .map(|e| match e {
@@ -168,6 +302,7 @@ impl MapProperty {
                             Property::EnumProperty(EnumProperty {
                                 enum_type: None,
                                 value: key,
+                                ..
                             }),
                             value,
                         ) => Ok((key.clone(), value.clone())),
@@ -176,190 +311,224 @@ impl MapProperty {
                     .collect::<Result<_, _>>()
                 {
                     Ok(enum_props) => MapProperty::EnumProperty {
+                        tag: PropertyTagData::default(),
+                        keys_to_remove: removed_keys,
                         value_type,
                         enum_props: HashableIndexMap(enum_props),
                     },
                     Err(_) => MapProperty::Properties {
+                        tag: PropertyTagData::default(),
                         key_type,
                         value_type,
-                        allocation_flags,
+                        keys_to_remove,
                         value,
                     },
                 }
             }
 
-            ("NameProperty", "BoolProperty", 0) => match value
+            ("NameProperty", "BoolProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
                         Property::NameProperty(NameProperty {
-                            array_index: 0,
+                            tag,
                             value: Some(key),
                         }),
-                        Property::BoolProperty(BoolProperty { value }),
-                    ) => Ok((key.clone(), *value)),
+                        Property::BoolProperty(BoolProperty { value, .. }),
+                    ) if tag.array_index == 0 => Ok((key.clone(), *value)),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(name_bools) => MapProperty::NameBool {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     name_bools: HashableIndexMap(name_bools),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("NameProperty", "IntProperty", 0) => match value
+            ("NameProperty", "IntProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
                         Property::NameProperty(NameProperty {
-                            array_index: 0,
+                            tag,
                             value: Some(key),
                         }),
-                        Property::IntProperty(IntProperty { value }),
-                    ) => Ok((key.clone(), *value)),
+                        Property::IntProperty(IntProperty { value, .. }),
+                    ) if tag.array_index == 0 => Ok((key.clone(), *value)),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(name_ints) => MapProperty::NameInt {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     name_ints: HashableIndexMap(name_ints),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("NameProperty", _, 0) => {
+            ("NameProperty", _, Some(removed_keys)) => {
                 match value
                     .iter()
                     .map(|e| match e {
                         (
                             Property::NameProperty(NameProperty {
-                                array_index: 0,
+                                tag,
                                 value: Some(key),
                             }),
                             value,
-                        ) => Ok((key.clone(), value.clone())),
+                        ) if tag.array_index == 0 => Ok((key.clone(), value.clone())),
                         _ => Err(()),
                     })
                     .collect::<Result<_, _>>()
                 {
                     Ok(name_props) => MapProperty::NameProperty {
+                        tag: PropertyTagData::default(),
+                        keys_to_remove: removed_keys,
                         value_type,
                         name_props: HashableIndexMap(name_props),
                     },
                     Err(_) => MapProperty::Properties {
+                        tag: PropertyTagData::default(),
                         key_type,
                         value_type,
-                        allocation_flags,
+                        keys_to_remove,
                         value,
                     },
                 }
             }
 
-            ("StrProperty", "BoolProperty", 0) => match value
+            ("StrProperty", "BoolProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
-                        Property::StrProperty(StrProperty { value: Some(key) }),
-                        Property::BoolProperty(BoolProperty { value }),
+                        Property::StrProperty(StrProperty {
+                            value: Some(key), ..
+                        }),
+                        Property::BoolProperty(BoolProperty { value, .. }),
                     ) => Ok((key.clone(), *value)),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(str_bools) => MapProperty::StrBool {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     str_bools: HashableIndexMap(str_bools),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("StrProperty", "IntProperty", 0) => match value
+            ("StrProperty", "IntProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
-                        Property::StrProperty(StrProperty { value: Some(key) }),
-                        Property::IntProperty(IntProperty { value }),
+                        Property::StrProperty(StrProperty {
+                            value: Some(key), ..
+                        }),
+                        Property::IntProperty(IntProperty { value, .. }),
                     ) => Ok((key.clone(), *value)),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(str_ints) => MapProperty::StrInt {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     str_ints: HashableIndexMap(str_ints),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("StrProperty", "StrProperty", 0) => match value
+            ("StrProperty", "StrProperty", Some(removed_keys)) => match value
                 .iter()
                 .map(|e| match e {
                     (
-                        Property::StrProperty(StrProperty { value: Some(key) }),
-                        Property::StrProperty(StrProperty { value }),
+                        Property::StrProperty(StrProperty {
+                            value: Some(key), ..
+                        }),
+                        Property::StrProperty(StrProperty { value, .. }),
                     ) => Ok((key.clone(), value.clone())),
                     _ => Err(()),
                 })
                 .collect::<Result<_, _>>()
             {
                 Ok(str_strs) => MapProperty::StrStr {
+                    tag: PropertyTagData::default(),
+                    keys_to_remove: removed_keys,
                     str_strs: HashableIndexMap(str_strs),
                 },
                 Err(_) => MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type,
                     value_type,
-                    allocation_flags,
+                    keys_to_remove,
                     value,
                 },
             },
 
-            ("StrProperty", _, 0) => {
+            ("StrProperty", _, Some(removed_keys)) => {
                 match value
                     .iter()
                     .map(|e| match e {
-                        (Property::StrProperty(StrProperty { value: Some(key) }), value) => {
-                            Ok((key.clone(), value.clone()))
-                        }
+                        (
+                            Property::StrProperty(StrProperty {
+                                value: Some(key), ..
+                            }),
+                            value,
+                        ) => Ok((key.clone(), value.clone())),
                         _ => Err(()),
                     })
                     .collect::<Result<_, _>>()
                 {
                     Ok(str_props) => MapProperty::StrProperty {
+                        tag: PropertyTagData::default(),
+                        keys_to_remove: removed_keys,
                         value_type,
                         str_props: HashableIndexMap(str_props),
                     },
                     Err(_) => MapProperty::Properties {
+                        tag: PropertyTagData::default(),
                         key_type,
                         value_type,
-                        allocation_flags,
+                        keys_to_remove,
                         value,
                     },
                 }
             }
 
             _ => MapProperty::Properties {
+                tag: PropertyTagData::default(),
                 key_type,
                 value_type,
-                allocation_flags,
+                keys_to_remove,
                 value,
             },
         }
@@ -378,62 +547,70 @@ impl MapProperty {
     #[inline]
     fn key_type(&self) -> &str {
         match self {
-            MapProperty::EnumBool { enum_bools: _ } => "EnumProperty",
-            MapProperty::EnumInt { enum_ints: _ } => "EnumProperty",
+            MapProperty::EnumBool { enum_bools: _, .. } => "EnumProperty",
+            MapProperty::EnumInt { enum_ints: _, .. } => "EnumProperty",
             MapProperty::EnumProperty {
                 value_type: _,
                 enum_props: _,
+                ..
             } => "EnumProperty",
-            MapProperty::NameBool { name_bools: _ } => "NameProperty",
-            MapProperty::NameInt { name_ints: _ } => "NameProperty",
+            MapProperty::NameBool { name_bools: _, .. } => "NameProperty",
+            MapProperty::NameInt { name_ints: _, .. } => "NameProperty",
             MapProperty::NameProperty {
                 value_type: _,
                 name_props: _,
+                ..
             } => "NameProperty",
             MapProperty::Properties {
                 key_type,
                 value_type: _,
-                allocation_flags: _,
+                keys_to_remove: _,
                 value: _,
+                ..
             } => key_type,
-            MapProperty::StrBool { str_bools: _ } => "StrProperty",
-            MapProperty::StrInt { str_ints: _ } => "StrProperty",
+            MapProperty::StrBool { str_bools: _, .. } => "StrProperty",
+            MapProperty::StrInt { str_ints: _, .. } => "StrProperty",
             MapProperty::StrProperty {
                 value_type: _,
                 str_props: _,
+                ..
             } => "StrProperty",
-            MapProperty::StrStr { str_strs: _ } => "StrProperty",
+            MapProperty::StrStr { str_strs: _, .. } => "StrProperty",
         }
     }
 
     #[inline]
     fn value_type(&self) -> &str {
         match self {
-            MapProperty::EnumBool { enum_bools: _ } => "BoolProperty",
-            MapProperty::EnumInt { enum_ints: _ } => "IntProperty",
+            MapProperty::EnumBool { enum_bools: _, .. } => "BoolProperty",
+            MapProperty::EnumInt { enum_ints: _, .. } => "IntProperty",
             MapProperty::EnumProperty {
                 value_type,
                 enum_props: _,
+                ..
             } => value_type,
-            MapProperty::NameBool { name_bools: _ } => "BoolProperty",
-            MapProperty::NameInt { name_ints: _ } => "IntProperty",
+            MapProperty::NameBool { name_bools: _, .. } => "BoolProperty",
+            MapProperty::NameInt { name_ints: _, .. } => "IntProperty",
             MapProperty::NameProperty {
                 value_type,
                 name_props: _,
+                ..
             } => value_type,
             MapProperty::Properties {
                 key_type: _,
                 value_type,
-                allocation_flags: _,
+                keys_to_remove: _,
                 value: _,
+                ..
             } => value_type,
-            MapProperty::StrBool { str_bools: _ } => "BoolProperty",
-            MapProperty::StrInt { str_ints: _ } => "IntProperty",
+            MapProperty::StrBool { str_bools: _, .. } => "BoolProperty",
+            MapProperty::StrInt { str_ints: _, .. } => "IntProperty",
             MapProperty::StrProperty {
                 value_type,
                 str_props: _,
+                ..
             } => value_type,
-            MapProperty::StrStr { str_strs: _ } => "StrProperty",
+            MapProperty::StrStr { str_strs: _, .. } => "StrProperty",
         }
     }
 
@@ -462,10 +639,23 @@ impl MapProperty {
         key_type: String,
         value_type: String,
     ) -> Result<Self, Error> {
-        let allocation_flags = cursor.read_u32::<LittleEndian>()?;
+        let keys_to_remove_count = cursor.read_u32::<LittleEndian>()?;
+        options
+            .limits
+            .check_element_count(keys_to_remove_count, cursor)?;
+        let mut keys_to_remove =
+            Vec::with_capacity(bounded_capacity(keys_to_remove_count as usize));
+        for _ in 0..keys_to_remove_count {
+            let properties_stack = &mut options.properties_stack;
+            let key_stack_entry = ScopedStackEntry::new(properties_stack, "Key".to_string());
+            keys_to_remove.push(Property::new(cursor, &key_type, false, options, None)?);
+            drop(key_stack_entry);
+        }
+
         let element_count = cursor.read_u32::<LittleEndian>()?;
+        options.limits.check_element_count(element_count, cursor)?;
 
-        let mut map = HashableIndexMap::with_capacity(element_count as usize);
+        let mut map = HashableIndexMap::with_capacity(bounded_capacity(element_count as usize));
         for _ in 0..element_count {
             let properties_stack = &mut options.properties_stack;
             let key_stack_entry = ScopedStackEntry::new(properties_stack, "Key".to_string());
@@ -480,12 +670,7 @@ impl MapProperty {
             map.insert(key, value);
         }
 
-        Ok(MapProperty::new(
-            key_type,
-            value_type,
-            allocation_flags,
-            map,
-        ))
+        Ok(MapProperty::new(key_type, value_type, keys_to_remove, map))
     }
 }
 
@@ -496,6 +681,40 @@ impl PropertyTrait for MapProperty {
         (write_string, fn, get_value_type)
     );
 
+    #[inline]
+    fn tag(&self) -> Option<&PropertyTagData> {
+        match self {
+            MapProperty::EnumBool { tag, .. }
+            | MapProperty::EnumInt { tag, .. }
+            | MapProperty::EnumProperty { tag, .. }
+            | MapProperty::NameBool { tag, .. }
+            | MapProperty::NameInt { tag, .. }
+            | MapProperty::NameProperty { tag, .. }
+            | MapProperty::Properties { tag, .. }
+            | MapProperty::StrBool { tag, .. }
+            | MapProperty::StrInt { tag, .. }
+            | MapProperty::StrProperty { tag, .. }
+            | MapProperty::StrStr { tag, .. } => Some(tag),
+        }
+    }
+
+    #[inline]
+    fn tag_mut(&mut self) -> Option<&mut PropertyTagData> {
+        match self {
+            MapProperty::EnumBool { tag, .. }
+            | MapProperty::EnumInt { tag, .. }
+            | MapProperty::EnumProperty { tag, .. }
+            | MapProperty::NameBool { tag, .. }
+            | MapProperty::NameInt { tag, .. }
+            | MapProperty::NameProperty { tag, .. }
+            | MapProperty::Properties { tag, .. }
+            | MapProperty::StrBool { tag, .. }
+            | MapProperty::StrInt { tag, .. }
+            | MapProperty::StrProperty { tag, .. }
+            | MapProperty::StrStr { tag, .. } => Some(tag),
+        }
+    }
+
     #[inline]
     fn write_body<W: Write>(
         &self,
@@ -505,10 +724,18 @@ impl PropertyTrait for MapProperty {
         match self {
             MapProperty::EnumBool {
                 enum_bools: HashableIndexMap(enum_bools),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| EnumProperty::new(None, key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(enum_bools.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in enum_bools {
                     let k_property = EnumProperty::new(None, key.clone());
                     let v_property = BoolProperty::new(*value);
@@ -520,10 +747,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::EnumInt {
                 enum_ints: HashableIndexMap(enum_ints),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| EnumProperty::new(None, key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(enum_ints.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in enum_ints {
                     let k_property = EnumProperty::new(None, key.clone());
                     let v_property = IntProperty::new(*value);
@@ -536,10 +771,18 @@ impl PropertyTrait for MapProperty {
             MapProperty::EnumProperty {
                 value_type: _,
                 enum_props: HashableIndexMap(enum_props),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| EnumProperty::new(None, key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(enum_props.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in enum_props {
                     let property = EnumProperty::new(None, key.clone());
                     len += property.write(cursor, false, options)?;
@@ -550,10 +793,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::NameBool {
                 name_bools: HashableIndexMap(name_bools),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| NameProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(name_bools.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in name_bools {
                     let k_property = NameProperty::from(key.clone());
                     let v_property = BoolProperty::new(*value);
@@ -565,10 +816,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::NameInt {
                 name_ints: HashableIndexMap(name_ints),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| NameProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(name_ints.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in name_ints {
                     let k_property = NameProperty::from(key.clone());
                     let v_property = IntProperty::new(*value);
@@ -581,10 +840,18 @@ impl PropertyTrait for MapProperty {
             MapProperty::NameProperty {
                 value_type: _,
                 name_props: HashableIndexMap(name_props),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| NameProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(name_props.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in name_props {
                     let property = NameProperty::from(key.clone());
                     len += property.write(cursor, false, options)?;
@@ -596,12 +863,17 @@ impl PropertyTrait for MapProperty {
             MapProperty::Properties {
                 key_type: _,
                 value_type: _,
-                allocation_flags,
+                keys_to_remove,
                 value: HashableIndexMap(value),
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(*allocation_flags)?;
+                cursor.write_u32::<LittleEndian>(keys_to_remove.len() as u32)?;
+                let mut len = 4;
+                for key in keys_to_remove {
+                    len += key.write(cursor, false, options)?;
+                }
                 cursor.write_u32::<LittleEndian>(value.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in value {
                     len += key.write(cursor, false, options)?;
                     len += value.write(cursor, false, options)?;
@@ -611,10 +883,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::StrBool {
                 str_bools: HashableIndexMap(str_bools),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| StrProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(str_bools.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in str_bools {
                     let k_property = StrProperty::from(key.clone());
                     let v_property = BoolProperty::new(*value);
@@ -626,10 +906,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::StrInt {
                 str_ints: HashableIndexMap(str_ints),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| StrProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(str_ints.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in str_ints {
                     let k_property = StrProperty::from(key.clone());
                     let v_property = IntProperty::new(*value);
@@ -642,10 +930,18 @@ impl PropertyTrait for MapProperty {
             MapProperty::StrProperty {
                 value_type: _,
                 str_props: HashableIndexMap(str_props),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| StrProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(str_props.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in str_props {
                     let property = StrProperty::from(key.clone());
                     len += property.write(cursor, false, options)?;
@@ -656,10 +952,18 @@ impl PropertyTrait for MapProperty {
 
             MapProperty::StrStr {
                 str_strs: HashableIndexMap(str_strs),
+                keys_to_remove,
+                ..
             } => {
-                cursor.write_u32::<LittleEndian>(0)?;
+                let mut len = write_keys_to_remove(
+                    cursor,
+                    keys_to_remove
+                        .iter()
+                        .map(|key| StrProperty::from(key.clone())),
+                    options,
+                )?;
                 cursor.write_u32::<LittleEndian>(str_strs.len() as u32)?;
-                let mut len = 8;
+                len += 4;
                 for (key, value) in str_strs {
                     let k_property = StrProperty::from(key.clone());
                     let v_property = StrProperty::new(value.clone());
@@ -671,3 +975,22 @@ impl PropertyTrait for MapProperty {
         }
     }
 }
+
+#[inline]
+fn write_keys_to_remove<W, P, I>(
+    cursor: &mut W,
+    keys_to_remove: I,
+    options: &mut PropertyOptions,
+) -> Result<usize, Error>
+where
+    W: Write,
+    P: PropertyTrait,
+    I: ExactSizeIterator<Item = P>,
+{
+    cursor.write_u32::<LittleEndian>(keys_to_remove.len() as u32)?;
+    let mut len = 4;
+    for key in keys_to_remove {
+        len += key.write(cursor, false, options)?;
+    }
+    Ok(len)
+}
@@ -2,14 +2,18 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     hash::Hash,
-    io::{Read, Seek, Write},
+    io::{Read, Seek, SeekFrom, Write},
 };
 
 use enum_dispatch::enum_dispatch;
 
 use crate::{
     custom_version::{CustomVersionTrait, FCustomVersion},
-    error::{DeserializeError, Error},
+    error::{DeserializeError, Diagnostic, Error},
+    limits::Limits,
+    object_version::{
+        EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version, FPackageFileVersion,
+    },
     scoped_stack_entry::ScopedStackEntry,
     types::{map::HashableIndexMap, Guid},
 };
@@ -28,6 +32,7 @@ use self::{
     map_property::MapProperty,
     name_property::NameProperty,
     object_property::ObjectProperty,
+    property_tag::PropertyTagData,
     set_property::SetProperty,
     str_property::StrProperty,
     struct_property::{StructProperty, StructPropertyValue},
@@ -51,6 +56,8 @@ pub mod map_property;
 pub mod name_property;
 /// Module for `ObjectProperty`
 pub mod object_property;
+/// Module for property tag data.
+pub mod property_tag;
 /// Module for `SetProperty`
 pub mod set_property;
 /// Module for `StrProperty`
@@ -71,20 +78,6 @@ pub mod unknown_property;
 /// This macro must be used in conjunction with a suitable `read_header` function, such as one
 /// generated by `impl_read_header!(...)`.
 macro_rules! impl_read {
-    () => {
-        /// Read GVAS property data from a reader.
-        ///
-        /// If `include_header` is true, read the property header first.
-        #[inline]
-        pub fn read<R: Read + Seek>(reader: &mut R, include_header: bool) -> Result<Self, Error> {
-            if include_header {
-                Self::read_header(reader)
-            } else {
-                Self::read_body(reader)
-            }
-        }
-    };
-
     (options) => {
         /// Read GVAS property data from a reader.
         ///
@@ -102,18 +95,32 @@ macro_rules! impl_read {
             }
         }
     };
+}
 
-    (array_index) => {
-        /// Read GVAS property data from a reader.
-        ///
-        /// If `include_header` is true, read the property header first.
-        #[inline]
-        pub fn read<R: Read + Seek>(reader: &mut R, include_header: bool) -> Result<Self, Error> {
-            if include_header {
-                Self::read_header(reader)
-            } else {
-                Self::read_body(reader, 0)
-            }
+/// A helper macro for reading the part of a property tag that follows the property type.
+///
+/// This macro is used inside the `impl_read_header!` macro. Depending on the header version it
+/// reads either a UE 5.4+ tag, taking the type parameters named by `$var` from the complete type
+/// name, or an older tag with the type parameters stored as strings. It evaluates to a tuple of
+/// the `PropertyTag` and the type parameters.
+macro_rules! impl_read_tag {
+    ($reader:ident, $options:ident $(, $var:ident)*) => {
+        if $options.supports_ue5_version(
+            $crate::object_version::EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName,
+        ) {
+            let tag = $crate::properties::property_tag::PropertyTag::read_complete($reader, &$options.limits)?;
+            let [$($var),*] = tag.get_parameter_names([$(stringify!($var)),*], $reader)?;
+            (tag, $($var,)*)
+        } else {
+            let length = $reader.read_u32::<LittleEndian>()?;
+            let array_index = $reader.read_u32::<LittleEndian>()?;
+            $(
+                let $var = $reader.read_string_limited($options.limits.max_string_length)?;
+            )*
+            let data =
+                $crate::properties::property_tag::PropertyTagData::read($reader, $options)?;
+            let tag = $crate::properties::property_tag::PropertyTag::new(length, array_index, data);
+            (tag, $($var,)*)
         }
     };
 }
@@ -129,7 +136,7 @@ macro_rules! impl_read {
 ///
 /// use crate::{
 ///     error::Error,
-///     properties::{impl_read, impl_read_header},
+///     properties::{impl_read, impl_read_header, PropertyOptions},
 /// };
 ///
 /// struct ExampleProperty(
@@ -137,9 +144,12 @@ macro_rules! impl_read {
 /// );
 ///
 /// impl ExampleProperty {
-///     impl_read!();
-///     impl_read_header!();
-///     fn read_body<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+///     impl_read!(options);
+///     impl_read_header!(options);
+///     fn read_body<R: Read + Seek>(
+///         reader: &mut R,
+///         options: &mut PropertyOptions,
+///     ) -> Result<Self, Error> {
 ///         // Read values from reader...
 ///         Ok(Self (
 ///             // ...
@@ -155,28 +165,19 @@ macro_rules! impl_read_header {
             reader: &mut R,
             options: &mut PropertyOptions,
         ) -> Result<Self, Error> {
-            let length = reader.read_u32::<LittleEndian>()?;
-            let array_index = reader.read_u32::<LittleEndian>()?;
-            if array_index != 0 {
-                let position = reader.stream_position()? - 4;
-                Err($crate::error::DeserializeError::InvalidArrayIndex(array_index, position))?
-            }
-            $(
-                let $var = reader.read_string()?;
-            )*
-            let terminator = reader.read_u8()?;
-            if terminator != 0 {
-                let position = reader.stream_position()? - 1;
-                Err($crate::error::DeserializeError::InvalidTerminator(terminator, position))?
-            }
+            let (tag, $($var,)*) = $crate::properties::impl_read_tag!(reader, options $(, $var)*);
+            let length = tag.length;
 
             let start = reader.stream_position()?;
-            let result = Self::read_body(reader, options, length $(, $var)*)?;
+            let mut result = Self::read_body(reader, options, length $(, $var)*)?;
             let end = reader.stream_position()?;
             if end - start != length as u64 {
                 Err($crate::error::DeserializeError::InvalidValueSize(length as u64, end - start, start))?
             }
 
+            if let Some(data) = result.tag_mut() {
+                *data = tag.data;
+            }
             Ok(result)
         }
     };
@@ -188,56 +189,19 @@ macro_rules! impl_read_header {
             reader: &mut R,
             options: &mut PropertyOptions,
         ) -> Result<Self, Error> {
-            let length = reader.read_u32::<LittleEndian>()?;
-            let array_index = reader.read_u32::<LittleEndian>()?;
-            if array_index != 0 {
-                let position = reader.stream_position()? - 4;
-                Err($crate::error::DeserializeError::InvalidArrayIndex(array_index, position))?
-            }
-            $(
-                let $var = reader.read_string()?;
-            )*
-            let terminator = reader.read_u8()?;
-            if terminator != 0 {
-                let position = reader.stream_position()? - 1;
-                Err($crate::error::DeserializeError::InvalidTerminator(terminator, position))?
-            }
+            let (tag, $($var,)*) = $crate::properties::impl_read_tag!(reader, options $(, $var)*);
+            let length = tag.length;
 
             let start = reader.stream_position()?;
-            let result = Self::read_body(reader, options $(, $var)*)?;
+            let mut result = Self::read_body(reader, options $(, $var)*)?;
             let end = reader.stream_position()?;
             if end - start != length as u64 {
                 Err($crate::error::DeserializeError::InvalidValueSize(length as u64, end - start, start))?
             }
 
-            Ok(result)
-        }
-    };
-
-    (array_index $(, $var:ident)*) => {
-        /// Read GVAS property data from a reader.
-        #[inline]
-        pub fn read_header<R: Read + Seek>(
-            reader: &mut R,
-        ) -> Result<Self, Error> {
-            let length = reader.read_u32::<LittleEndian>()?;
-            let array_index = reader.read_u32::<LittleEndian>()?;
-            $(
-                let $var = reader.read_string()?;
-            )*
-            let terminator = reader.read_u8()?;
-            if terminator != 0 {
-                let position = reader.stream_position()? - 1;
-                Err($crate::error::DeserializeError::InvalidTerminator(terminator, position))?
-            }
-
-            let start = reader.stream_position()?;
-            let result = Self::read_body(reader, array_index $(, Some($var))*)?;
-            let end = reader.stream_position()?;
-            if end - start != length as u64 {
-                Err($crate::error::DeserializeError::InvalidValueSize(length as u64, end - start, start))?
+            if let Some(data) = result.tag_mut() {
+                *data = tag.data;
             }
-
             Ok(result)
         }
     };
@@ -247,29 +211,21 @@ macro_rules! impl_read_header {
         #[inline]
         pub fn read_header<R: Read + Seek>(
             reader: &mut R,
+            options: &mut PropertyOptions,
         ) -> Result<Self, Error> {
-            let length = reader.read_u32::<LittleEndian>()?;
-            let array_index = reader.read_u32::<LittleEndian>()?;
-            if array_index != 0 {
-                let position = reader.stream_position()? - 4;
-                Err($crate::error::DeserializeError::InvalidArrayIndex(array_index, position))?
-            }
-            $(
-                let $var = reader.read_string()?;
-            )*
-            let terminator = reader.read_u8()?;
-            if terminator != 0 {
-                let position = reader.stream_position()? - 1;
-                Err($crate::error::DeserializeError::InvalidTerminator(terminator, position))?
-            }
+            let (tag, $($var,)*) = $crate::properties::impl_read_tag!(reader, options $(, $var)*);
+            let length = tag.length;
 
             let start = reader.stream_position()?;
-            let result = Self::read_body(reader $(, Some($var))*)?;
+            let mut result = Self::read_body(reader, options $(, Some($var))*)?;
             let end = reader.stream_position()?;
             if end - start != length as u64 {
                 Err($crate::error::DeserializeError::InvalidValueSize(length as u64, end - start, start))?
             }
 
+            if let Some(data) = result.tag_mut() {
+                *data = tag.data;
+            }
             Ok(result)
         }
     };
@@ -277,10 +233,15 @@ macro_rules! impl_read_header {
 
 pub(crate) use impl_read;
 pub(crate) use impl_read_header;
+pub(crate) use impl_read_tag;
 
 /// This macro generates a `write` function for writing the property data to a writer.
 /// If `include_header` is true, it will write the property header first.
 ///
+/// Files with a UE5 version of `PropertyTagCompleteTypeName` or later get a UE 5.4+ tag, with
+/// the complete type name taken from the property's `PropertyTagData` or derived from the header
+/// parts.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -313,36 +274,6 @@ pub(crate) use impl_read_header;
 ///
 /// This macro must be used in conjunction with a suitable `write_body` function.
 macro_rules! impl_write {
-    ($property:ident, array_index $(, $header_property:tt)*) => {
-        #[inline]
-        fn write<W: Write>(
-            &self,
-            writer: &mut W,
-            include_header: bool,
-            options: &mut PropertyOptions,
-        ) -> Result<usize, Error> {
-            if !include_header {
-                return self.write_body(writer, options);
-            }
-
-            let mut len = 9;
-            let buf = &mut Cursor::new(Vec::new());
-            len += self.write_body(buf, options)?;
-            let buf = buf.get_ref();
-
-            writer.write_string(stringify!($property))?;
-            writer.write_u32::<LittleEndian>(buf.len() as u32)?;
-            writer.write_u32::<LittleEndian>(self.array_index)?;
-            $(
-                len += impl_write_header_part!(self, writer, $header_property);
-            )*
-            writer.write_u8(0)?;
-            writer.write_all(buf)?;
-
-            Ok(len)
-        }
-    };
-
     ($property:ident $(, $header_property:tt)*) => {
         #[inline]
         fn write<W: Write>(
@@ -355,18 +286,37 @@ macro_rules! impl_write {
                 return self.write_body(writer, options);
             }
 
-            let mut len = 9;
+            let mut len = 0;
             let buf = &mut Cursor::new(Vec::new());
             len += self.write_body(buf, options)?;
             let buf = buf.get_ref();
 
+            let default_tag = $crate::properties::property_tag::PropertyTagData::default();
+            let tag = self.tag().unwrap_or(&default_tag);
+
             len += writer.write_string(stringify!($property))?;
-            writer.write_u32::<LittleEndian>(buf.len() as u32)?;
-            writer.write_u32::<LittleEndian>(0)?;
-            $(
-                len += impl_write_header_part!(self, writer, $header_property);
-            )*
-            writer.write_u8(0)?;
+            if options.supports_ue5_version(
+                $crate::object_version::EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName,
+            ) {
+                let parameters: &[Option<$crate::properties::property_tag::PropertyTypeName>] = &[
+                    $($crate::properties::impl_type_parameter!(self, $header_property)),*
+                ];
+                let parameters: Vec<_> = parameters.iter().flatten().cloned().collect();
+                len += tag.write_complete(
+                    writer,
+                    &parameters,
+                    buf.len(),
+                    $crate::properties::property_tag::EPropertyTagFlags::NONE,
+                )?;
+            } else {
+                len += 8;
+                writer.write_u32::<LittleEndian>(buf.len() as u32)?;
+                writer.write_u32::<LittleEndian>(tag.array_index)?;
+                $(
+                    len += impl_write_header_part!(self, writer, options, $header_property);
+                )*
+                len += tag.write(writer, options)?;
+            }
             writer.write_all(buf)?;
 
             Ok(len)
@@ -378,24 +328,89 @@ macro_rules! impl_write {
 ///
 /// This macro is used inside the `impl_write!` macro to write individual parts of a property header.
 macro_rules! impl_write_header_part {
-    ($self:ident, $writer:ident, (write_fstring, $member:ident)) => {
+    ($self:ident, $writer:ident, $options:ident, (write_fstring, $member:ident)) => {
         $writer.write_fstring($self.$member.as_deref())?
     };
 
-    ($self:ident, $writer:ident, (write_guid, $member:ident)) => {{
-        $writer.write_guid(&$self.$member)?;
-        16
+    ($self:ident, $writer:ident, $options:ident, (write_guid, $member:ident)) => {{
+        if $options.supports_ue4_version(
+            $crate::object_version::EUnrealEngineObjectUE4Version::StructGuidInPropertyTag,
+        ) {
+            $writer.write_guid(&$self.$member)?;
+            16
+        } else if $self.$member.is_zero() {
+            0
+        } else {
+            Err($crate::error::SerializeError::invalid_value(format!(
+                "Struct GUID {} requires UE4 version {}",
+                $self.$member,
+                $crate::object_version::EUnrealEngineObjectUE4Version::StructGuidInPropertyTag
+                    as u32
+            )))?
+        }
     }};
 
-    ($self:ident, $writer:ident, ($write_fn:ident, $member:ident)) => {
+    ($self:ident, $writer:ident, $options:ident, ($write_fn:ident, $member:ident)) => {
         $writer.$write_fn(&$self.$member)?
     };
 
-    ($self:ident, $writer:ident, ($write_fn:ident, fn, $member:ident)) => {
+    ($self:ident, $writer:ident, $options:ident, ($write_fn:ident, fn, $member:ident)) => {
         $writer.$write_fn(&$self.$member()?)?
     };
 }
 
+/// A helper macro for deriving complete type name parameters from property header parts.
+///
+/// This macro is used inside the `impl_write!` macro to build the type parameters of a UE 5.4+
+/// property tag. It evaluates to an `Option<PropertyTypeName>`.
+macro_rules! impl_type_parameter {
+    ($self:ident, (write_fstring, $member:ident)) => {
+        $self
+            .$member
+            .as_deref()
+            .map($crate::properties::property_tag::PropertyTypeName::from)
+    };
+
+    ($self:ident, (write_guid, $member:ident)) => {
+        match $self.$member.is_zero() {
+            true => None,
+            false => {
+                Some($crate::properties::property_tag::PropertyTypeName::from_guid(&$self.$member))
+            }
+        }
+    };
+
+    ($self:ident, ($write_fn:ident, $member:ident)) => {
+        Some($crate::properties::property_tag::PropertyTypeName::from(
+            $self.$member.as_str(),
+        ))
+    };
+
+    ($self:ident, ($write_fn:ident, fn, $member:ident)) => {
+        Some($crate::properties::property_tag::PropertyTypeName::from(
+            $self.$member()?.to_string(),
+        ))
+    };
+}
+
+/// This macro generates the `tag` and `tag_mut` functions of `PropertyTrait` for a property with
+/// a `tag` field.
+macro_rules! impl_tag {
+    () => {
+        #[inline]
+        fn tag(&self) -> Option<&$crate::properties::property_tag::PropertyTagData> {
+            Some(&self.tag)
+        }
+
+        #[inline]
+        fn tag_mut(&mut self) -> Option<&mut $crate::properties::property_tag::PropertyTagData> {
+            Some(&mut self.tag)
+        }
+    };
+}
+
+pub(crate) use impl_tag;
+pub(crate) use impl_type_parameter;
 pub(crate) use impl_write;
 pub(crate) use impl_write_header_part;
 
@@ -441,6 +456,15 @@ pub struct PropertyOptions<'a> {
     pub properties_stack: &'a mut Vec<String>,
     /// Custom versions
     pub custom_versions: &'a HashableIndexMap<Guid, u32>,
+    /// Package file version
+    pub package_file_version: FPackageFileVersion,
+    /// Resource limits
+    pub limits: Limits,
+    /// Diagnostics for recovered properties
+    ///
+    /// If set, tagged properties that fail to parse are kept as an [`UnknownProperty`] with
+    /// their raw bytes, and reading continues with the next property.
+    pub diagnostics: Option<&'a mut Vec<Diagnostic>>,
 }
 
 impl PropertyOptions<'_> {
@@ -463,6 +487,22 @@ impl PropertyOptions<'_> {
     {
         self.get_custom_version::<T>().version >= required.into()
     }
+
+    /// Check for UE4 package file version support
+    ///
+    /// A UE4 version of zero, as in [`FPackageFileVersion::default`], means the version is
+    /// unknown and supports everything.
+    #[inline]
+    pub fn supports_ue4_version(&self, required: EUnrealEngineObjectUE4Version) -> bool {
+        let version = self.package_file_version.file_version_ue4;
+        version == 0 || version >= required as u32
+    }
+
+    /// Check for UE5 package file version support
+    #[inline]
+    pub fn supports_ue5_version(&self, required: EUnrealEngineObjectUE5Version) -> bool {
+        self.package_file_version.file_version_ue5 >= required as u32
+    }
 }
 
 /// Property traits.
@@ -482,6 +522,18 @@ pub trait PropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
         cursor: &mut W,
         options: &mut PropertyOptions,
     ) -> Result<usize, Error>;
+
+    /// Property tag data, if this property is stored with a tag.
+    #[inline]
+    fn tag(&self) -> Option<&PropertyTagData> {
+        None
+    }
+
+    /// Mutable property tag data, if this property is stored with a tag.
+    #[inline]
+    fn tag_mut(&mut self) -> Option<&mut PropertyTagData> {
+        None
+    }
 }
 
 /// GVAS property types.
@@ -556,34 +608,111 @@ impl Property {
         options: &mut PropertyOptions,
         suggested_length: Option<u32>,
     ) -> Result<Self, Error> {
+        let depth = options.properties_stack.len();
+        let recovery = match &options.diagnostics {
+            Some(diagnostics) if include_header => {
+                Some((cursor.stream_position()?, diagnostics.len()))
+            }
+            _ => None,
+        };
         let _stack_entry = ScopedStackEntry::new(options.properties_stack, value_type.to_string());
+        let result = options
+            .limits
+            .check_depth(depth, cursor)
+            .and_then(|()| {
+                Self::read_value(
+                    cursor,
+                    value_type,
+                    include_header,
+                    options,
+                    suggested_length,
+                )
+            })
+            .map_err(|e| e.in_property(options.properties_stack, value_type));
+
+        match (result, recovery) {
+            (Err(error), Some((position, diagnostics_len))) => Self::recover(
+                cursor,
+                value_type,
+                options,
+                position,
+                diagnostics_len,
+                error,
+            ),
+            (result, _) => result,
+        }
+    }
+
+    /// Rereads a tagged property that failed to parse as an [`UnknownProperty`].
+    ///
+    /// Diagnostics of properties nested inside it are replaced by its own. If the tag itself is
+    /// unreadable, the original error is returned.
+    #[inline]
+    fn recover<R: Read + Seek>(
+        cursor: &mut R,
+        value_type: &str,
+        options: &mut PropertyOptions,
+        position: u64,
+        diagnostics_len: usize,
+        error: Error,
+    ) -> Result<Self, Error> {
+        cursor.seek(SeekFrom::Start(position))?;
+        let Ok(property) = UnknownProperty::read_recovered(cursor, value_type.to_string(), options)
+        else {
+            return Err(error);
+        };
+
+        if let Some(diagnostics) = options.diagnostics.as_deref_mut() {
+            diagnostics.truncate(diagnostics_len);
+            diagnostics.push(Diagnostic {
+                path: options.properties_stack.join("."),
+                property_type: value_type.to_string(),
+                position,
+                error,
+            });
+        }
+        Ok(property.into())
+    }
+
+    #[inline]
+    fn read_value<R: Read + Seek>(
+        cursor: &mut R,
+        value_type: &str,
+        include_header: bool,
+        options: &mut PropertyOptions,
+        suggested_length: Option<u32>,
+    ) -> Result<Self, Error> {
         match value_type {
-            "Int8Property" => Ok(Int8Property::read(cursor, include_header)?.into()),
+            "Int8Property" => Ok(Int8Property::read(cursor, include_header, options)?.into()),
             "ByteProperty" => {
-                Ok(ByteProperty::read(cursor, include_header, suggested_length)?.into())
+                Ok(ByteProperty::read(cursor, include_header, options, suggested_length)?.into())
             }
-            "Int16Property" => Ok(Int16Property::read(cursor, include_header)?.into()),
-            "UInt16Property" => Ok(UInt16Property::read(cursor, include_header)?.into()),
-            "IntProperty" => Ok(IntProperty::read(cursor, include_header)?.into()),
-            "UInt32Property" => Ok(UInt32Property::read(cursor, include_header)?.into()),
-            "Int64Property" => Ok(Int64Property::read(cursor, include_header)?.into()),
-            "UInt64Property" => Ok(UInt64Property::read(cursor, include_header)?.into()),
-            "FloatProperty" => Ok(FloatProperty::read(cursor, include_header)?.into()),
-            "DoubleProperty" => Ok(DoubleProperty::read(cursor, include_header)?.into()),
-            "BoolProperty" => Ok(BoolProperty::read(cursor, include_header)?.into()),
-            "EnumProperty" => Ok(EnumProperty::read(cursor, include_header)?.into()),
-            "StrProperty" => Ok(StrProperty::read(cursor, include_header)?.into()),
+            "Int16Property" => Ok(Int16Property::read(cursor, include_header, options)?.into()),
+            "UInt16Property" => Ok(UInt16Property::read(cursor, include_header, options)?.into()),
+            "IntProperty" => Ok(IntProperty::read(cursor, include_header, options)?.into()),
+            "UInt32Property" => Ok(UInt32Property::read(cursor, include_header, options)?.into()),
+            "Int64Property" => Ok(Int64Property::read(cursor, include_header, options)?.into()),
+            "UInt64Property" => Ok(UInt64Property::read(cursor, include_header, options)?.into()),
+            "FloatProperty" => Ok(FloatProperty::read(cursor, include_header, options)?.into()),
+            "DoubleProperty" => Ok(DoubleProperty::read(cursor, include_header, options)?.into()),
+            "BoolProperty" => Ok(BoolProperty::read(cursor, include_header, options)?.into()),
+            "EnumProperty" => Ok(EnumProperty::read(cursor, include_header, options)?.into()),
+            "StrProperty" => Ok(StrProperty::read(cursor, include_header, options)?.into()),
             "TextProperty" => Ok(TextProperty::read(cursor, include_header, options)?.into()),
-            "NameProperty" => Ok(NameProperty::read(cursor, include_header)?.into()),
-            "ObjectProperty" => Ok(ObjectProperty::read(cursor, include_header)?.into()),
-            "DelegateProperty" => Ok(DelegateProperty::read(cursor, include_header)?.into()),
+            "NameProperty" => Ok(NameProperty::read(cursor, include_header, options)?.into()),
+            "ObjectProperty" => Ok(ObjectProperty::read(cursor, include_header, options)?.into()),
+            "DelegateProperty" => {
+                Ok(DelegateProperty::read(cursor, include_header, options)?.into())
+            }
             "MulticastInlineDelegateProperty" => {
-                Ok(MulticastInlineDelegateProperty::read(cursor, include_header)?.into())
+                Ok(MulticastInlineDelegateProperty::read(cursor, include_header, options)?.into())
             }
             "MulticastSparseDelegateProperty" => {
-                Ok(MulticastSparseDelegateProperty::read(cursor, include_header)?.into())
+                Ok(MulticastSparseDelegateProperty::read(cursor, include_header, options)?.into())
+            }
+            "FieldPathProperty" => {
+                Ok(FieldPathProperty::read(cursor, include_header, options)?.into())
             }
-            "FieldPathProperty" => Ok(FieldPathProperty::read(cursor, include_header)?.into()),
             "StructProperty" => match include_header {
                 true => Ok(StructProperty::read(cursor, include_header, options)?.into()),
                 false => {
@@ -603,9 +732,12 @@ impl Property {
             "MapProperty" => Ok(MapProperty::read(cursor, include_header, options)?.into()),
             _ => {
                 if include_header {
-                    return Ok(
-                        UnknownProperty::read_with_header(cursor, value_type.to_string())?.into(),
-                    );
+                    return Ok(UnknownProperty::read_with_header(
+                        cursor,
+                        value_type.to_string(),
+                        options,
+                    )?
+                    .into());
                 }
 
                 if let Some(suggested_length) = suggested_length {
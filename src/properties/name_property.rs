@@ -4,26 +4,23 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{cursor_ext::ReadExt, cursor_ext::WriteExt, error::Error};
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// A property that holds a name.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", serde_with::skip_serializing_none)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NameProperty {
-    /// Array Index
-    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_zero"))]
-    #[cfg_attr(feature = "serde", serde(default))]
-    pub array_index: u32,
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Name value.
     pub value: Option<String>,
 }
 
-#[cfg(feature = "serde")]
-fn is_zero(num: &u32) -> bool {
-    *num == 0
-}
-
 impl From<&str> for NameProperty {
     #[inline]
     fn from(value: &str) -> Self {
@@ -41,24 +38,30 @@ impl From<String> for NameProperty {
 impl From<Option<String>> for NameProperty {
     #[inline]
     fn from(value: Option<String>) -> Self {
-        let array_index: u32 = 0;
-        NameProperty { array_index, value }
+        NameProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 }
 
 impl NameProperty {
-    impl_read!(array_index);
-    impl_read_header!(array_index);
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R, array_index: u32) -> Result<Self, Error> {
-        let value = cursor.read_fstring()?;
-        Ok(NameProperty { array_index, value })
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = cursor.read_fstring_limited(options.limits.max_string_length)?;
+        Ok(NameProperty::from(value))
     }
 }
 
 impl PropertyTrait for NameProperty {
-    impl_write!(NameProperty, array_index);
+    impl_write!(NameProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -7,12 +7,18 @@ use crate::{
     error::Error,
 };
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// A property that describes a reference variable to another object which may be nil.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Object reference
     pub value: String,
 }
@@ -28,21 +34,28 @@ impl ObjectProperty {
     /// Creates a new `ObjectProperty` instance
     #[inline]
     pub fn new(value: String) -> Self {
-        ObjectProperty { value }
+        ObjectProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = cursor.read_string()?;
-        Ok(ObjectProperty { value })
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = cursor.read_string_limited(options.limits.max_string_length)?;
+        Ok(ObjectProperty::new(value))
     }
 }
 
 impl PropertyTrait for ObjectProperty {
     impl_write!(ObjectProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -0,0 +1,660 @@
+use std::{
+    fmt::Display,
+    io::{Read, Seek, Write},
+    ops::{BitOr, BitOrAssign},
+};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{
+    cursor_ext::{ReadExt, WriteExt},
+    error::{DeserializeError, Error, SerializeError},
+    limits::Limits,
+    object_version::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version},
+    types::Guid,
+};
+
+use super::PropertyOptions;
+
+/// A node of a complete property type name.
+///
+/// Since UE 5.4 property tags store the full type of a property as a tree of names, for example
+/// `StructProperty(Vector(/Script/CoreUObject))` or `MapProperty(NameProperty,IntProperty)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyTypeName {
+    /// Name of this node.
+    pub name: String,
+    /// Type parameters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub parameters: Vec<PropertyTypeName>,
+}
+
+impl PropertyTypeName {
+    /// Creates a new `PropertyTypeName` instance.
+    #[inline]
+    pub fn new(name: String, parameters: Vec<PropertyTypeName>) -> Self {
+        PropertyTypeName { name, parameters }
+    }
+
+    /// Creates a new `PropertyTypeName` instance holding a GUID, formatted the way Unreal does.
+    #[inline]
+    pub fn from_guid(guid: &Guid) -> Self {
+        let [a, b, c, d] = guid.to_u32();
+        Self::from(format!("{a:08X}{b:08X}{c:08X}{d:08X}"))
+    }
+
+    /// Parses the name of this node as a GUID.
+    pub fn to_guid(&self) -> Option<Guid> {
+        if self.name.len() != 32 {
+            return None;
+        }
+        let mut value = [0u32; 4];
+        for (i, part) in value.iter_mut().enumerate() {
+            let digits = self.name.get(i * 8..i * 8 + 8)?;
+            *part = u32::from_str_radix(digits, 16).ok()?;
+        }
+        Some(Guid::from_u32(value))
+    }
+
+    /// Returns true if `other` has the same name as this node, and its parameters start with ones
+    /// matching the parameters of this node.
+    ///
+    /// Parameters derived from a property lack some details, such as the package of a struct, so
+    /// this is used to check whether the parameters read from a file still describe the property.
+    pub fn is_prefix_of(&self, other: &PropertyTypeName) -> bool {
+        self.name == other.name && Self::are_prefixes_of(&self.parameters, &other.parameters)
+    }
+
+    #[inline]
+    fn are_prefixes_of(parameters: &[Self], other: &[Self]) -> bool {
+        parameters.len() <= other.len()
+            && parameters
+                .iter()
+                .zip(other)
+                .all(|(parameter, other)| parameter.is_prefix_of(other))
+    }
+
+    /// Retrieves the name of a type parameter.
+    #[inline]
+    pub fn get_parameter_name(&self, index: usize) -> Option<&str> {
+        self.parameters.get(index).map(|p| p.name.as_str())
+    }
+
+    /// Reads the type parameters of a complete type name, following its name.
+    ///
+    /// Names nested deeper than [`Limits::max_depth`] are rejected, since dropping, comparing and
+    /// formatting a `PropertyTypeName` recurse into its parameters.
+    pub(crate) fn read_parameters<R: Read + Seek>(
+        reader: &mut R,
+        limits: &Limits,
+    ) -> Result<Vec<Self>, Error> {
+        // Nodes are stored in pre-order as (name, parameter count) pairs. Read them without
+        // recursion, so that a malformed name can't exhaust the stack.
+        let root = PropertyTypeName::from(String::new());
+        let mut stack = vec![(root, Self::read_parameter_count(reader)?)];
+        while let Some((node, remaining)) = stack.pop() {
+            if remaining == 0 {
+                match stack.last_mut() {
+                    Some((parent, _)) => parent.parameters.push(node),
+                    None => return Ok(node.parameters),
+                }
+            } else {
+                limits.check_depth(stack.len() + 1, reader)?;
+                let name = reader.read_string_limited(limits.max_string_length)?;
+                let count = Self::read_parameter_count(reader)?;
+                stack.push((node, remaining - 1));
+                stack.push((PropertyTypeName::from(name), count));
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    #[inline]
+    fn read_parameter_count<R: Read + Seek>(reader: &mut R) -> Result<u32, Error> {
+        let count = reader.read_i32::<LittleEndian>()?;
+        Ok(u32::try_from(count).map_err(|_| {
+            DeserializeError::invalid_property(
+                format!("Invalid type parameter count {count}"),
+                reader,
+            )
+        })?)
+    }
+
+    #[inline]
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) -> Result<usize, Error> {
+        let mut len = writer.write_string(&self.name)?;
+        len += Self::write_parameters(writer, &self.parameters)?;
+        Ok(len)
+    }
+
+    #[inline]
+    pub(crate) fn write_parameters<W: Write>(
+        writer: &mut W,
+        parameters: &[Self],
+    ) -> Result<usize, Error> {
+        writer.write_i32::<LittleEndian>(parameters.len() as i32)?;
+        let mut len = 4;
+        for parameter in parameters {
+            len += parameter.write(writer)?;
+        }
+        Ok(len)
+    }
+}
+
+impl From<String> for PropertyTypeName {
+    #[inline]
+    fn from(name: String) -> Self {
+        PropertyTypeName::new(name, Vec::new())
+    }
+}
+
+impl From<&str> for PropertyTypeName {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Self::from(name.to_string())
+    }
+}
+
+impl Display for PropertyTypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.parameters.is_empty() {
+            write!(f, "(")?;
+            for (i, parameter) in self.parameters.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{parameter}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Property tag flags, as stored since UE 5.4.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct EPropertyTagFlags(pub u8);
+
+impl EPropertyTagFlags {
+    /// No flags.
+    pub const NONE: Self = Self(0x00);
+    /// The tag is followed by an array index.
+    pub const HAS_ARRAY_INDEX: Self = Self(0x01);
+    /// The tag is followed by a property GUID.
+    pub const HAS_PROPERTY_GUID: Self = Self(0x02);
+    /// The tag is followed by property extensions.
+    pub const HAS_PROPERTY_EXTENSIONS: Self = Self(0x04);
+    /// The value was serialized by a binary or native serializer.
+    pub const HAS_BINARY_OR_NATIVE_SERIALIZE: Self = Self(0x08);
+    /// The value of a `BoolProperty` is true.
+    pub const BOOL_TRUE: Self = Self(0x10);
+    /// The value was skipped during serialization.
+    pub const SKIPPED_SERIALIZE: Self = Self(0x20);
+
+    /// Flags that are derived from the property when writing.
+    const DERIVED: Self = Self(0x01 | 0x02 | 0x04 | 0x10);
+    /// Flags that this crate can read.
+    const SUPPORTED: Self = Self(0x01 | 0x02 | 0x04 | 0x08 | 0x10 | 0x20);
+
+    /// Returns true if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if no flags are set.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the flags in `self` that are not set in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl BitOr for EPropertyTagFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for EPropertyTagFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Property tag extension flags, as stored since UE 5.4.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct EPropertyTagExtension(pub u8);
+
+impl EPropertyTagExtension {
+    /// No extensions.
+    pub const NO_EXTENSION: Self = Self(0x00);
+    /// Reserved for a future group of extensions.
+    pub const RESERVE_FOR_FUTURE_USE: Self = Self(0x01);
+    /// The extensions are followed by overridable serialization information.
+    pub const OVERRIDABLE_INFORMATION: Self = Self(0x02);
+
+    /// Extensions that are derived from the property tag data when writing.
+    const DERIVED: Self = Self(0x02);
+    /// Extensions that this crate can read.
+    const SUPPORTED: Self = Self(0x01 | 0x02);
+
+    /// Returns true if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if no flags are set.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the flags in `self` that are not set in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl BitOr for EPropertyTagExtension {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for EPropertyTagExtension {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Operation applied to an overridable property.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i8)]
+pub enum EOverriddenPropertyOperation {
+    /// Nothing was overridden.
+    #[default]
+    None = 0,
+    /// A sub property was overridden.
+    Modified,
+    /// The property was replaced.
+    Replaced,
+    /// Elements were added to a container.
+    Add,
+    /// Elements were removed from a container.
+    Remove,
+}
+
+/// Overridable serialization information of a property tag.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverridableInformation {
+    /// Override operation.
+    pub operation: EOverriddenPropertyOperation,
+    /// Whether the property uses the experimental overridable logic.
+    pub experimental_overridable_logic: bool,
+}
+
+/// Property tag data that is not part of a property value.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyTagData {
+    /// Static array index, for properties declared as fixed size arrays such as `int32 Slots[4]`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub array_index: u32,
+    /// Type parameters of the complete type name, e.g. `Vector(/Script/CoreUObject)` for a
+    /// `StructProperty` holding a `Vector`.
+    ///
+    /// This is only read from UE 5.4+ files. If it is empty or no longer matches the property,
+    /// the parameters are derived from the property when writing a UE 5.4+ file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub type_parameters: Vec<PropertyTypeName>,
+    /// Tag flags that can't be derived from the property.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            rename = "tag_flags",
+            skip_serializing_if = "EPropertyTagFlags::is_empty"
+        )
+    )]
+    pub flags: EPropertyTagFlags,
+    /// Property GUID, stored for properties of blueprint-defined structs and classes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub property_guid: Option<Guid>,
+    /// Tag extension flags that can't be derived from the property.
+    ///
+    /// Extensions are stored since UE5 version `PropertyTagExtensionAndOverridableSerialization`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            rename = "tag_extensions",
+            skip_serializing_if = "EPropertyTagExtension::is_empty"
+        )
+    )]
+    pub extensions: EPropertyTagExtension,
+    /// Overridable serialization information.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub overridable: Option<OverridableInformation>,
+}
+
+#[cfg(feature = "serde")]
+#[inline]
+fn is_zero(num: &u32) -> bool {
+    *num == 0
+}
+
+impl PropertyTagData {
+    /// Returns the stored type parameters if they match `parameters`, which are derived from the
+    /// property, otherwise returns `parameters`.
+    #[inline]
+    fn type_parameters_or<'a>(
+        &'a self,
+        parameters: &'a [PropertyTypeName],
+    ) -> &'a [PropertyTypeName] {
+        match PropertyTypeName::are_prefixes_of(parameters, &self.type_parameters) {
+            true => &self.type_parameters,
+            false => parameters,
+        }
+    }
+
+    /// Writes a UE 5.4+ property tag, following its type name.
+    pub(crate) fn write_complete<W: Write>(
+        &self,
+        writer: &mut W,
+        parameters: &[PropertyTypeName],
+        length: usize,
+        flags: EPropertyTagFlags,
+    ) -> Result<usize, Error> {
+        let mut len =
+            PropertyTypeName::write_parameters(writer, self.type_parameters_or(parameters))?;
+
+        let extensions = self.get_extensions();
+        let mut flags = self.flags.difference(EPropertyTagFlags::DERIVED) | flags;
+        if self.array_index != 0 {
+            flags |= EPropertyTagFlags::HAS_ARRAY_INDEX;
+        }
+        if self.property_guid.is_some() {
+            flags |= EPropertyTagFlags::HAS_PROPERTY_GUID;
+        }
+        if !extensions.is_empty() {
+            flags |= EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS;
+        }
+        writer.write_u32::<LittleEndian>(length as u32)?;
+        writer.write_u8(flags.0)?;
+        len += 5;
+
+        if self.array_index != 0 {
+            writer.write_u32::<LittleEndian>(self.array_index)?;
+            len += 4;
+        }
+        if let Some(property_guid) = &self.property_guid {
+            writer.write_guid(property_guid)?;
+            len += 16;
+        }
+        if !extensions.is_empty() {
+            len += self.write_extensions(writer, extensions)?;
+        }
+        Ok(len)
+    }
+
+    /// Reads the end of an older property tag: since UE4 version `PropertyGuidInPropertyTag` the
+    /// HasPropertyGuid flag, followed by the property GUID and, since UE5 version `PropertyTagExtensionAndOverridableSerialization`, the tag
+    /// extensions.
+    pub(crate) fn read<R: Read + Seek>(
+        reader: &mut R,
+        options: &PropertyOptions,
+    ) -> Result<Self, Error> {
+        if !options.supports_ue4_version(EUnrealEngineObjectUE4Version::PropertyGuidInPropertyTag) {
+            return Ok(PropertyTagData::default());
+        }
+
+        let property_guid = match reader.read_u8()? {
+            0 => None,
+            1 => Some(reader.read_guid()?),
+            value => {
+                let position = reader.stream_position()? - 1;
+                Err(DeserializeError::InvalidTerminator(value, position))?
+            }
+        };
+
+        let mut data = PropertyTagData {
+            property_guid,
+            ..Default::default()
+        };
+        if options.supports_ue5_version(
+            EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization,
+        ) {
+            data.read_extensions(reader)?;
+        }
+        Ok(data)
+    }
+
+    /// Writes the end of an older property tag: since UE4 version `PropertyGuidInPropertyTag` the
+    /// HasPropertyGuid flag, followed by the property GUID and, since UE5 version `PropertyTagExtensionAndOverridableSerialization`, the tag
+    /// extensions.
+    pub(crate) fn write<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &PropertyOptions,
+    ) -> Result<usize, Error> {
+        if !options.supports_ue4_version(EUnrealEngineObjectUE4Version::PropertyGuidInPropertyTag) {
+            return match &self.property_guid {
+                Some(property_guid) => Err(SerializeError::invalid_value(format!(
+                    "Property GUID {property_guid} requires UE4 version {}",
+                    EUnrealEngineObjectUE4Version::PropertyGuidInPropertyTag as u32
+                )))?,
+                None => Ok(0),
+            };
+        }
+
+        let mut len = 1;
+        match &self.property_guid {
+            Some(property_guid) => {
+                writer.write_u8(1)?;
+                writer.write_guid(property_guid)?;
+                len += 16;
+            }
+            None => writer.write_u8(0)?,
+        }
+
+        let extensions = self.get_extensions();
+        if options.supports_ue5_version(
+            EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization,
+        ) {
+            len += self.write_extensions(writer, extensions)?;
+        } else if !extensions.is_empty() {
+            Err(SerializeError::invalid_value(format!(
+                "Property tag extensions {:#04x} require UE5 version {}",
+                extensions.0,
+                EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization
+                    as u32
+            )))?
+        }
+        Ok(len)
+    }
+
+    /// Returns the tag extensions, including the ones derived from the tag data.
+    #[inline]
+    fn get_extensions(&self) -> EPropertyTagExtension {
+        let mut extensions = self.extensions.difference(EPropertyTagExtension::DERIVED);
+        if self.overridable.is_some() {
+            extensions |= EPropertyTagExtension::OVERRIDABLE_INFORMATION;
+        }
+        extensions
+    }
+
+    /// Reads tag extensions, followed by the overridable serialization information.
+    fn read_extensions<R: Read + Seek>(&mut self, reader: &mut R) -> Result<(), Error> {
+        let extensions = EPropertyTagExtension(reader.read_u8()?);
+        if !EPropertyTagExtension::SUPPORTED.contains(extensions) {
+            let position = reader.stream_position()? - 1;
+            Err(DeserializeError::InvalidTagExtensions(
+                extensions.0,
+                position,
+            ))?
+        }
+
+        if extensions.contains(EPropertyTagExtension::OVERRIDABLE_INFORMATION) {
+            let operation = reader.read_enum()?;
+            let experimental_overridable_logic = reader.read_b32()?;
+            self.overridable = Some(OverridableInformation {
+                operation,
+                experimental_overridable_logic,
+            });
+        }
+        self.extensions = extensions.difference(EPropertyTagExtension::DERIVED);
+        Ok(())
+    }
+
+    /// Writes tag extensions, followed by the overridable serialization information.
+    fn write_extensions<W: Write>(
+        &self,
+        writer: &mut W,
+        extensions: EPropertyTagExtension,
+    ) -> Result<usize, Error> {
+        writer.write_u8(extensions.0)?;
+        match &self.overridable {
+            Some(overridable) => {
+                writer.write_enum(overridable.operation)?;
+                writer.write_b32(overridable.experimental_overridable_logic)?;
+                Ok(6)
+            }
+            None => Ok(1),
+        }
+    }
+}
+
+/// The fields of a property tag that follow its type name.
+pub(crate) struct PropertyTag {
+    /// Size of the property value in bytes.
+    pub(crate) length: u32,
+    /// Tag flags, including the ones derived from the property.
+    pub(crate) flags: EPropertyTagFlags,
+    /// Tag data stored with the property.
+    pub(crate) data: PropertyTagData,
+}
+
+impl PropertyTag {
+    /// Creates a new `PropertyTag` instance for a tag without UE 5.4+ tag flags.
+    #[inline]
+    pub(crate) fn new(length: u32, array_index: u32, data: PropertyTagData) -> Self {
+        PropertyTag {
+            length,
+            flags: EPropertyTagFlags::NONE,
+            data: PropertyTagData {
+                array_index,
+                ..data
+            },
+        }
+    }
+
+    /// Reads a UE 5.4+ property tag, following its type name.
+    pub(crate) fn read_complete<R: Read + Seek>(
+        reader: &mut R,
+        limits: &Limits,
+    ) -> Result<Self, Error> {
+        let type_parameters = PropertyTypeName::read_parameters(reader, limits)?;
+        let length = reader.read_u32::<LittleEndian>()?;
+
+        let flags = EPropertyTagFlags(reader.read_u8()?);
+        if !EPropertyTagFlags::SUPPORTED.contains(flags) {
+            let position = reader.stream_position()? - 1;
+            Err(DeserializeError::InvalidTagFlags(flags.0, position))?
+        }
+
+        let array_index = match flags.contains(EPropertyTagFlags::HAS_ARRAY_INDEX) {
+            true => reader.read_u32::<LittleEndian>()?,
+            false => 0,
+        };
+        let property_guid = match flags.contains(EPropertyTagFlags::HAS_PROPERTY_GUID) {
+            true => Some(reader.read_guid()?),
+            false => None,
+        };
+
+        let mut data = PropertyTagData {
+            array_index,
+            type_parameters,
+            flags: flags.difference(EPropertyTagFlags::DERIVED),
+            property_guid,
+            ..Default::default()
+        };
+        if flags.contains(EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS) {
+            data.read_extensions(reader)?;
+        }
+
+        Ok(PropertyTag {
+            length,
+            flags,
+            data,
+        })
+    }
+
+    /// Retrieves a type parameter of a UE 5.4+ property tag.
+    #[inline]
+    pub(crate) fn get_parameter<S: Seek>(
+        &self,
+        index: usize,
+        argument_name: &str,
+        stream: &mut S,
+    ) -> Result<&PropertyTypeName, Error> {
+        match self.data.type_parameters.get(index) {
+            Some(parameter) => Ok(parameter),
+            None => Err(DeserializeError::missing_argument(argument_name, stream))?,
+        }
+    }
+
+    /// Retrieves the names of the first `N` type parameters of a UE 5.4+ property tag.
+    #[inline]
+    pub(crate) fn get_parameter_names<S: Seek, const N: usize>(
+        &self,
+        argument_names: [&str; N],
+        stream: &mut S,
+    ) -> Result<[String; N], Error> {
+        let mut names = std::array::from_fn(|_| String::new());
+        for (index, (name, argument_name)) in names.iter_mut().zip(argument_names).enumerate() {
+            *name = self
+                .get_parameter(index, argument_name, stream)?
+                .name
+                .clone();
+        }
+        Ok(names)
+    }
+}
@@ -3,22 +3,30 @@ use std::io::{Cursor, Read, Seek, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     error::{DeserializeError, Error},
 };
 
 use super::{
-    impl_read_header, impl_write, impl_write_header_part, Property, PropertyOptions, PropertyTrait,
+    impl_read_header, impl_tag, impl_write, impl_write_header_part, property_tag::PropertyTagData,
+    Property, PropertyOptions, PropertyTrait,
 };
 
 /// A property that stores a set of properties.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Property type.
     pub property_type: String,
-    /// Allocation flags.
-    pub allocation_flags: u32,
+    /// Elements removed from the set.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub keys_to_remove: Vec<Property>,
     /// Properties.
     pub properties: Vec<Property>,
 }
@@ -26,10 +34,15 @@ pub struct SetProperty {
 impl SetProperty {
     /// Creates a new `SetProperty` instance.
     #[inline]
-    pub fn new(property_type: String, allocation_flags: u32, properties: Vec<Property>) -> Self {
+    pub fn new(
+        property_type: String,
+        keys_to_remove: Vec<Property>,
+        properties: Vec<Property>,
+    ) -> Self {
         SetProperty {
+            tag: PropertyTagData::default(),
             property_type,
-            allocation_flags,
+            keys_to_remove,
             properties,
         }
     }
@@ -59,14 +72,31 @@ impl SetProperty {
         length: u32,
         property_type: String,
     ) -> Result<Self, Error> {
-        let allocation_flags = cursor.read_u32::<LittleEndian>()?;
+        let keys_to_remove_count = cursor.read_u32::<LittleEndian>()?;
+        options
+            .limits
+            .check_element_count(keys_to_remove_count, cursor)?;
+        let keys_start = cursor.stream_position()?;
+        let mut keys_to_remove: Vec<Property> =
+            Vec::with_capacity(bounded_capacity(keys_to_remove_count as usize));
+        for _ in 0..keys_to_remove_count {
+            keys_to_remove.push(Property::new(cursor, &property_type, false, options, None)?);
+        }
+        let keys_length = (cursor.stream_position()? - keys_start) as u32;
 
         let element_count = cursor.read_u32::<LittleEndian>()?;
-        let mut properties: Vec<Property> = Vec::with_capacity(element_count as usize);
-
-        if element_count > 0 {
-            let total_bytes_per_property = (length - 8) / element_count;
+        options.limits.check_element_count(element_count, cursor)?;
+        let elements_length = length.saturating_sub(8).saturating_sub(keys_length);
+        if element_count > elements_length {
+            Err(DeserializeError::invalid_property(
+                format!("{element_count} set elements don't fit in {elements_length} bytes"),
+                cursor,
+            ))?
+        }
+        let mut properties: Vec<Property> =
+            Vec::with_capacity(bounded_capacity(element_count as usize));
 
+        if let Some(total_bytes_per_property) = elements_length.checked_div(element_count) {
             for _ in 0..element_count {
                 properties.push(Property::new(
                     cursor,
@@ -78,16 +108,13 @@ impl SetProperty {
             }
         }
 
-        Ok(SetProperty {
-            property_type,
-            allocation_flags,
-            properties,
-        })
+        Ok(SetProperty::new(property_type, keys_to_remove, properties))
     }
 }
 
 impl PropertyTrait for SetProperty {
     impl_write!(SetProperty, (write_string, property_type));
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -95,9 +122,14 @@ impl PropertyTrait for SetProperty {
         cursor: &mut W,
         options: &mut PropertyOptions,
     ) -> Result<usize, Error> {
-        cursor.write_u32::<LittleEndian>(self.allocation_flags)?;
+        cursor.write_u32::<LittleEndian>(self.keys_to_remove.len() as u32)?;
+        let mut len = 4;
+        for key in &self.keys_to_remove {
+            len += key.write(cursor, false, options)?;
+        }
+
         cursor.write_u32::<LittleEndian>(self.properties.len() as u32)?;
-        let mut len = 8;
+        len += 4;
         for property in &self.properties {
             len += property.write(cursor, false, options)?;
         }
@@ -7,13 +7,19 @@ use crate::{
     error::Error,
 };
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// A property that holds a GVAS string value.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", serde_with::skip_serializing_none)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StrProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Value of the GVAS string.
     pub value: Option<String>,
 }
@@ -36,21 +42,28 @@ impl StrProperty {
     /// Creates a new `StrProperty` instance.
     #[inline]
     pub fn new(value: Option<String>) -> Self {
-        StrProperty { value }
+        StrProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
-    impl_read!();
-    impl_read_header!();
+    impl_read!(options);
+    impl_read_header!(options);
 
     #[inline]
-    fn read_body<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
-        let value = cursor.read_fstring()?;
-        Ok(StrProperty { value })
+    fn read_body<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let value = cursor.read_fstring_limited(options.limits.max_string_length)?;
+        Ok(StrProperty::new(value))
     }
 }
 
 impl PropertyTrait for StrProperty {
     impl_write!(StrProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
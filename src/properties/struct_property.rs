@@ -5,19 +5,20 @@ use std::{
 };
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use indexmap::IndexMap;
 
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
     custom_version::FUE5ReleaseStreamObjectVersion,
     error::{DeserializeError, Error, SerializeError},
-    properties::{name_property::NameProperty, struct_types::LinearColor},
+    object_version::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version},
+    properties::struct_types::LinearColor,
     scoped_stack_entry::ScopedStackEntry,
-    types::{map::HashableIndexMap, Guid},
+    types::Guid,
 };
 
 use super::{
-    impl_write, impl_write_header_part, make_matcher,
+    impl_tag, impl_write, impl_write_header_part, make_matcher,
+    property_tag::{PropertyTag, PropertyTagData},
     struct_types::{
         DateTime, IntPoint, QuatD, QuatF, RotatorD, RotatorF, Timespan, Vector2D, Vector2F,
         VectorD, VectorF,
@@ -39,6 +40,9 @@ macro_rules! validate {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// The unique identifier of the property.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Guid::is_zero"))]
     #[cfg_attr(feature = "serde", serde(default))]
@@ -83,7 +87,11 @@ pub enum StructPropertyValue {
     /// A `GameplayTagContainer` value.
     GameplayTagContainer(Vec<String>),
     /// A custom struct value.
-    CustomStruct(HashableIndexMap<String, Vec<Property>>),
+    ///
+    /// The properties are kept in the order they were read, see [`GvasFile::properties`].
+    ///
+    /// [`GvasFile::properties`]: crate::GvasFile::properties
+    CustomStruct(Vec<(String, Property)>),
 }
 
 impl StructProperty {
@@ -91,6 +99,7 @@ impl StructProperty {
     #[inline]
     pub fn new(guid: Guid, type_name: String, value: StructPropertyValue) -> Self {
         StructProperty {
+            tag: PropertyTagData::default(),
             guid,
             type_name,
             value,
@@ -118,23 +127,28 @@ impl StructProperty {
         cursor: &mut R,
         options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            return Self::read_complete_header(cursor, options);
+        }
+
         let length = cursor.read_u32::<LittleEndian>()?;
 
         let array_index = cursor.read_u32::<LittleEndian>()?;
-        if array_index != 0 {
-            let position = cursor.stream_position()? - 4;
-            Err(DeserializeError::InvalidArrayIndex(array_index, position))?
-        }
 
-        let type_name = cursor.read_string()?;
+        let type_name = cursor.read_string_limited(options.limits.max_string_length)?;
 
-        let guid = cursor.read_guid()?;
+        let guid = match options
+            .supports_ue4_version(EUnrealEngineObjectUE4Version::StructGuidInPropertyTag)
+        {
+            true => cursor.read_guid()?,
+            false => Guid::default(),
+        };
 
-        let terminator = cursor.read_u8()?;
-        if terminator != 0 {
-            let position = cursor.stream_position()? - 1;
-            Err(DeserializeError::InvalidTerminator(terminator, position))?
-        }
+        let tag = PropertyTagData {
+            array_index,
+            ..PropertyTagData::read(cursor, options)?
+        };
 
         let start = cursor.stream_position()?;
         let value = Self::read_body(cursor, &type_name, options)?;
@@ -147,7 +161,42 @@ impl StructProperty {
             ))?
         }
 
+        let mut property = StructProperty::new(guid, type_name, value);
+        property.tag = tag;
+        Ok(property)
+    }
+
+    #[inline]
+    fn read_complete_header<R: Read + Seek>(
+        cursor: &mut R,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        let tag = PropertyTag::read_complete(cursor, &options.limits)?;
+
+        let type_name = tag.get_parameter(0, "type_name", cursor)?.name.clone();
+        let guid = match tag.data.type_parameters.get(1) {
+            Some(parameter) => parameter.to_guid().ok_or_else(|| {
+                DeserializeError::invalid_property(
+                    format!("Invalid struct guid {}", parameter.name),
+                    cursor,
+                )
+            })?,
+            None => Guid::default(),
+        };
+
+        let start = cursor.stream_position()?;
+        let value = Self::read_body(cursor, &type_name, options)?;
+        let end = cursor.stream_position()?;
+        if end - start != tag.length as u64 {
+            Err(DeserializeError::InvalidValueSize(
+                tag.length as u64,
+                end - start,
+                start,
+            ))?
+        }
+
         Ok(StructProperty {
+            tag: tag.data,
             guid,
             type_name,
             value,
@@ -170,7 +219,9 @@ impl StructProperty {
             "LinearColor" => StructPropertyValue::read_linearcolor(cursor)?,
             "IntPoint" => StructPropertyValue::read_intpoint(cursor)?,
             "Guid" => StructPropertyValue::read_guid(cursor)?,
-            "GameplayTagContainer" => StructPropertyValue::read_gameplaytagcontainer(cursor)?,
+            "GameplayTagContainer" => {
+                StructPropertyValue::read_gameplaytagcontainer(cursor, options)?
+            }
             _ => StructPropertyValue::read_custom(cursor, options)?,
         };
         Ok(value)
@@ -182,29 +233,13 @@ impl StructProperty {
     }
 }
 
-fn insert_property(map: &mut IndexMap<String, Vec<Property>>, key: String, property: Property) {
-    let entry = map.entry(key).or_default();
-    #[cfg(debug_assertions)]
-    {
-        let array_index = match property {
-            // TODO: Move array_index to the Property layer
-            Property::NameProperty(NameProperty { array_index, .. }) => array_index,
-            _ => 0,
-        };
-        let actual_array_index = entry.len() as u32;
-        // Ensure that the position in the array matches the array_index value,
-        // otherwise this conversion would cause data loss.
-        assert_eq!(actual_array_index, array_index);
-    }
-    entry.push(property);
-}
-
 impl PropertyTrait for StructProperty {
     impl_write!(
         StructProperty,
         (write_string, fn, get_property_type),
         (write_guid, guid)
     );
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -357,11 +392,9 @@ impl PropertyTrait for StructPropertyValue {
             }
             StructPropertyValue::CustomStruct(properties) => {
                 let mut len = 0;
-                for (key, values) in properties {
-                    for value in values {
-                        len += cursor.write_string(key)?;
-                        len += value.write(cursor, true, options)?;
-                    }
+                for (key, value) in properties {
+                    len += cursor.write_string(key)?;
+                    len += value.write(cursor, true, options)?;
                 }
                 len += cursor.write_string("None")?;
                 Ok(len)
@@ -375,27 +408,33 @@ impl StructPropertyValue {
         cursor: &mut R,
         options: &mut PropertyOptions,
     ) -> Result<StructPropertyValue, Error> {
-        let mut properties = HashableIndexMap::new();
+        let mut properties = vec![];
         loop {
-            let property_name = cursor.read_string()?;
+            let property_name = cursor.read_string_limited(options.limits.max_string_length)?;
             if property_name == "None" {
                 break;
             }
-            let property_type = cursor.read_string()?;
+            let property_type = cursor.read_string_limited(options.limits.max_string_length)?;
             let _property_stack_entry =
                 ScopedStackEntry::new(options.properties_stack, property_name.clone());
 
             let property = Property::new(cursor, &property_type, true, options, None)?;
-            insert_property(&mut properties, property_name, property);
+            properties.push((property_name, property));
         }
         Ok(StructPropertyValue::CustomStruct(properties))
     }
 
-    fn read_gameplaytagcontainer<R: Read + Seek>(cursor: &mut R) -> Result<Self, Error> {
+    fn read_gameplaytagcontainer<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+    ) -> Result<Self, Error> {
         let len = cursor.read_i32::<LittleEndian>()?;
-        let mut tags: Vec<String> = Vec::with_capacity(len as usize);
+        options
+            .limits
+            .check_element_count(len.max(0) as u32, cursor)?;
+        let mut tags: Vec<String> = Vec::with_capacity(bounded_capacity(len as usize));
         for _ in 0..len {
-            tags.push(cursor.read_string()?);
+            tags.push(cursor.read_string_limited(options.limits.max_string_length)?);
         }
         Ok(Self::GameplayTagContainer(tags))
     }
@@ -515,7 +554,7 @@ impl StructPropertyValue {
 
     /// Retrieves the enum value as a `CustomStruct`.
     #[inline]
-    pub fn get_custom_struct(&self) -> Option<&HashableIndexMap<String, Vec<Property>>> {
+    pub fn get_custom_struct(&self) -> Option<&Vec<(String, Property)>> {
         match self {
             Self::CustomStruct(properties) => Some(properties),
             _ => None,
@@ -524,9 +563,7 @@ impl StructPropertyValue {
 
     /// Retrieves the mutable enum value as a `CustomStruct`.
     #[inline]
-    pub fn get_custom_struct_mut(
-        &mut self,
-    ) -> Option<&mut HashableIndexMap<String, Vec<Property>>> {
+    pub fn get_custom_struct_mut(&mut self) -> Option<&mut Vec<(String, Property)>> {
         match self {
             Self::CustomStruct(properties) => Some(properties),
             _ => None,
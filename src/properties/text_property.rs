@@ -9,20 +9,25 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use ordered_float::OrderedFloat;
 
 use crate::custom_version::{FEditorObjectVersion, FUE5ReleaseStreamObjectVersion};
-use crate::properties::int_property::UInt64Property;
 use crate::properties::struct_types::DateTime;
 use crate::types::map::HashableIndexMap;
 use crate::{
-    cursor_ext::{ReadExt, WriteExt},
-    error::Error,
+    cursor_ext::{bounded_capacity, ReadExt, WriteExt},
+    error::{DeserializeError, Error, SerializeError},
 };
 
-use super::{impl_read, impl_read_header, impl_write, PropertyOptions, PropertyTrait};
+use super::{
+    impl_read, impl_read_header, impl_tag, impl_write, property_tag::PropertyTagData,
+    PropertyOptions, PropertyTrait,
+};
 
 /// A property that stores GVAS Text.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextProperty {
+    /// Property tag data.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub tag: PropertyTagData,
     /// Value
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: FText,
@@ -31,7 +36,10 @@ pub struct TextProperty {
 impl TextProperty {
     /// Create a new [`TextProperty`]
     pub fn new(value: FText) -> Self {
-        TextProperty { value }
+        TextProperty {
+            tag: PropertyTagData::default(),
+            value,
+        }
     }
 
     #[inline]
@@ -40,7 +48,7 @@ impl TextProperty {
         options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
         let value = FText::read(cursor, options)?;
-        Ok(TextProperty { value })
+        Ok(TextProperty::new(value))
     }
 
     impl_read!(options);
@@ -49,6 +57,7 @@ impl TextProperty {
 
 impl PropertyTrait for TextProperty {
     impl_write!(TextProperty);
+    impl_tag!();
 
     #[inline]
     fn write_body<W: Write>(
@@ -113,8 +122,21 @@ impl FText {
     /// Read [`FText`] from a cursor
     #[inline]
     pub fn read<R: Read + Seek>(cursor: &mut R, options: &PropertyOptions) -> Result<Self, Error> {
+        Self::read_nested(cursor, options, 0)
+    }
+
+    /// Read [`FText`] nested `depth` levels deep in another text
+    #[inline]
+    pub(crate) fn read_nested<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+        depth: usize,
+    ) -> Result<Self, Error> {
+        options
+            .limits
+            .check_depth(options.properties_stack.len() + depth, cursor)?;
         let flags = cursor.read_u32::<LittleEndian>()?;
-        let history = FTextHistory::read(cursor, options)?;
+        let history = FTextHistory::read_nested(cursor, options, depth)?;
 
         Ok(FText { flags, history })
     }
@@ -291,12 +313,37 @@ pub enum FTextHistory {
         /// Key
         key: String,
     },
+    /// Text generator
+    TextGenerator {
+        /// Generator type id, `None` if the text has no generator
+        generator_type: String,
+        /// Opaque generator payload, only serialized when a generator type is set
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        generator_contents: Vec<u8>,
+    },
+    /// Raw text, Back 4 Blood specific serialization
+    RawText {
+        /// Source string
+        source_string: Option<String>,
+    },
 }
 
 impl FTextHistory {
     /// Read [`FTextHistory`] from a cursor
     #[inline]
     pub fn read<R: Read + Seek>(cursor: &mut R, options: &PropertyOptions) -> Result<Self, Error> {
+        Self::read_nested(cursor, options, 0)
+    }
+
+    #[inline]
+    fn read_nested<R: Read + Seek>(
+        cursor: &mut R,
+        options: &PropertyOptions,
+        depth: usize,
+    ) -> Result<Self, Error> {
         let history_type = cursor.read_enum()?;
 
         Ok(match history_type {
@@ -306,7 +353,8 @@ impl FTextHistory {
                 ) {
                     let has_culture_invariant_string = cursor.read_b32()?;
                     if has_culture_invariant_string {
-                        let culture_invariant_string = cursor.read_fstring()?;
+                        let culture_invariant_string =
+                            cursor.read_fstring_limited(options.limits.max_string_length)?;
                         FTextHistory::None {
                             culture_invariant_string,
                         }
@@ -318,9 +366,10 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::Base => {
-                let namespace = cursor.read_fstring()?;
-                let key = cursor.read_fstring()?;
-                let source_string = cursor.read_fstring()?;
+                let namespace = cursor.read_fstring_limited(options.limits.max_string_length)?;
+                let key = cursor.read_fstring_limited(options.limits.max_string_length)?;
+                let source_string =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
 
                 FTextHistory::Base {
                     namespace,
@@ -329,14 +378,18 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::NamedFormat => {
-                let source_format = Box::new(FText::read(cursor, options)?);
+                let source_format = Box::new(FText::read_nested(cursor, options, depth + 1)?);
 
                 let argument_count = cursor.read_i32::<LittleEndian>()?;
-                let mut arguments = HashableIndexMap::with_capacity(argument_count as usize);
+                options
+                    .limits
+                    .check_element_count(argument_count.max(0) as u32, cursor)?;
+                let mut arguments =
+                    HashableIndexMap::with_capacity(bounded_capacity(argument_count as usize));
 
                 for _ in 0..argument_count {
-                    let key = cursor.read_string()?;
-                    let value = FormatArgumentValue::read(cursor, options)?;
+                    let key = cursor.read_string_limited(options.limits.max_string_length)?;
+                    let value = FormatArgumentValue::read(cursor, options, depth)?;
                     arguments.insert(key, value);
                 }
 
@@ -346,13 +399,16 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::OrderedFormat => {
-                let source_format = Box::new(FText::read(cursor, options)?);
+                let source_format = Box::new(FText::read_nested(cursor, options, depth + 1)?);
 
                 let count = cursor.read_i32::<LittleEndian>()?;
-                let mut arguments = Vec::with_capacity(count as usize);
+                options
+                    .limits
+                    .check_element_count(count.max(0) as u32, cursor)?;
+                let mut arguments = Vec::with_capacity(bounded_capacity(count as usize));
 
                 for _ in 0..count {
-                    arguments.push(FormatArgumentValue::read(cursor, options)?);
+                    arguments.push(FormatArgumentValue::read(cursor, options, depth)?);
                 }
 
                 FTextHistory::OrderedFormat {
@@ -361,13 +417,17 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::ArgumentFormat => {
-                let source_format = Box::new(FText::read(cursor, options)?);
+                let source_format = Box::new(FText::read_nested(cursor, options, depth + 1)?);
                 let count = cursor.read_i32::<LittleEndian>()?;
-                let mut arguments = HashableIndexMap::with_capacity(count as usize);
+                options
+                    .limits
+                    .check_element_count(count.max(0) as u32, cursor)?;
+                let mut arguments =
+                    HashableIndexMap::with_capacity(bounded_capacity(count as usize));
 
                 for _ in 0..count {
-                    let key = cursor.read_string()?;
-                    let value = FormatArgumentValue::read(cursor, options)?;
+                    let key = cursor.read_string_limited(options.limits.max_string_length)?;
+                    let value = FormatArgumentValue::read(cursor, options, depth)?;
                     arguments.insert(key, value);
                 }
 
@@ -377,7 +437,7 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::AsNumber => {
-                let source_value = Box::new(FormatArgumentValue::read(cursor, options)?);
+                let source_value = Box::new(FormatArgumentValue::read(cursor, options, depth)?);
 
                 let has_format_options = cursor.read_b32()?;
                 let format_options = if has_format_options {
@@ -386,7 +446,8 @@ impl FTextHistory {
                     None
                 };
 
-                let target_culture = cursor.read_fstring()?;
+                let target_culture =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsNumber {
                     source_value,
@@ -395,7 +456,7 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::AsPercent => {
-                let source_value = Box::new(FormatArgumentValue::read(cursor, options)?);
+                let source_value = Box::new(FormatArgumentValue::read(cursor, options, depth)?);
 
                 let has_format_options = cursor.read_b32()?;
                 let format_options = if has_format_options {
@@ -404,7 +465,8 @@ impl FTextHistory {
                     None
                 };
 
-                let target_culture = cursor.read_fstring()?;
+                let target_culture =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsPercent {
                     source_value,
@@ -413,9 +475,10 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::AsCurrency => {
-                let currency_code = cursor.read_fstring()?;
+                let currency_code =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
 
-                let source_value = Box::new(FormatArgumentValue::read(cursor, options)?);
+                let source_value = Box::new(FormatArgumentValue::read(cursor, options, depth)?);
 
                 let has_format_options = cursor.read_b32()?;
                 let format_options = if has_format_options {
@@ -424,7 +487,8 @@ impl FTextHistory {
                     None
                 };
 
-                let target_culture = cursor.read_fstring()?;
+                let target_culture =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsCurrency {
                     currency_code,
@@ -435,10 +499,11 @@ impl FTextHistory {
             }
             TextHistoryType::AsDate => {
                 let date_time = DateTime {
-                    ticks: UInt64Property::read(cursor, false)?.value,
+                    ticks: cursor.read_u64::<LittleEndian>()?,
                 };
                 let date_style = cursor.read_enum()?;
-                let target_culture = cursor.read_string()?;
+                let target_culture =
+                    cursor.read_string_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsDate {
                     date_time,
@@ -448,11 +513,12 @@ impl FTextHistory {
             }
             TextHistoryType::AsTime => {
                 let source_date_time = DateTime {
-                    ticks: UInt64Property::read(cursor, false)?.value,
+                    ticks: cursor.read_u64::<LittleEndian>()?,
                 };
                 let time_style = cursor.read_enum()?;
-                let time_zone = cursor.read_string()?;
-                let target_culture = cursor.read_string()?;
+                let time_zone = cursor.read_string_limited(options.limits.max_string_length)?;
+                let target_culture =
+                    cursor.read_string_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsTime {
                     source_date_time,
@@ -463,12 +529,13 @@ impl FTextHistory {
             }
             TextHistoryType::AsDateTime => {
                 let source_date_time = DateTime {
-                    ticks: UInt64Property::read(cursor, false)?.value,
+                    ticks: cursor.read_u64::<LittleEndian>()?,
                 };
                 let date_style = cursor.read_enum()?;
                 let time_style = cursor.read_enum()?;
-                let time_zone = cursor.read_string()?;
-                let target_culture = cursor.read_string()?;
+                let time_zone = cursor.read_string_limited(options.limits.max_string_length)?;
+                let target_culture =
+                    cursor.read_string_limited(options.limits.max_string_length)?;
 
                 FTextHistory::AsDateTime {
                     source_date_time,
@@ -479,7 +546,7 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::Transform => {
-                let source_text = Box::new(FText::read(cursor, options)?);
+                let source_text = Box::new(FText::read_nested(cursor, options, depth + 1)?);
                 let transform_type = cursor.read_enum()?;
 
                 FTextHistory::Transform {
@@ -488,12 +555,38 @@ impl FTextHistory {
                 }
             }
             TextHistoryType::StringTableEntry => {
-                let table_id = cursor.read_string()?;
-                let key = cursor.read_string()?;
+                let table_id = cursor.read_string_limited(options.limits.max_string_length)?;
+                let key = cursor.read_string_limited(options.limits.max_string_length)?;
 
                 FTextHistory::StringTableEntry { table_id, key }
             }
-            _ => unimplemented!("unimplemented history type: {:?}", history_type),
+            TextHistoryType::TextGenerator => {
+                let generator_type =
+                    cursor.read_string_limited(options.limits.max_string_length)?;
+                let mut generator_contents = Vec::new();
+                if generator_type != "None" {
+                    let size = cursor.read_i32::<LittleEndian>()?;
+                    let Ok(size) = usize::try_from(size) else {
+                        Err(DeserializeError::InvalidValueSize(
+                            0,
+                            size as u64,
+                            cursor.stream_position()?,
+                        ))?
+                    };
+                    generator_contents = cursor.read_bytes(size)?;
+                }
+
+                FTextHistory::TextGenerator {
+                    generator_type,
+                    generator_contents,
+                }
+            }
+            TextHistoryType::RawText => {
+                let source_string =
+                    cursor.read_fstring_limited(options.limits.max_string_length)?;
+
+                FTextHistory::RawText { source_string }
+            }
         })
     }
 
@@ -708,6 +801,32 @@ impl FTextHistory {
                 len += cursor.write_string(key)?;
                 Ok(len)
             }
+
+            FTextHistory::TextGenerator {
+                generator_type,
+                generator_contents,
+            } => {
+                cursor.write_enum(TextHistoryType::TextGenerator)?;
+                let mut len = 1;
+                len += cursor.write_string(generator_type)?;
+                if generator_type != "None" {
+                    cursor.write_i32::<LittleEndian>(generator_contents.len() as i32)?;
+                    cursor.write_all(generator_contents)?;
+                    len += 4 + generator_contents.len();
+                } else if !generator_contents.is_empty() {
+                    Err(SerializeError::invalid_value(
+                        "Text generator contents require a generator type",
+                    ))?
+                }
+                Ok(len)
+            }
+
+            FTextHistory::RawText { source_string } => {
+                cursor.write_enum(TextHistoryType::RawText)?;
+                let mut len = 1;
+                len += cursor.write_fstring(source_string.as_deref())?;
+                Ok(len)
+            }
         }
     }
 }
@@ -726,7 +845,7 @@ pub enum FormatArgumentType {
     Double,
     /// FText
     Text,
-    /// ?
+    /// Grammatical gender
     Gender,
 }
 
@@ -748,6 +867,8 @@ pub enum FormatArgumentValue {
     Int64(i64),
     /// 64-bit unsigned integer
     UInt64(u64),
+    /// Grammatical gender
+    Gender(TextGender),
 }
 
 impl FormatArgumentValue {
@@ -756,6 +877,7 @@ impl FormatArgumentValue {
     pub(crate) fn read<R: Read + Seek>(
         cursor: &mut R,
         options: &PropertyOptions,
+        depth: usize,
     ) -> Result<Self, Error> {
         let format_argument_type = cursor.read_enum()?;
 
@@ -778,8 +900,10 @@ impl FormatArgumentValue {
             FormatArgumentType::Double => {
                 FormatArgumentValue::Double(cursor.read_f64::<LittleEndian>()?.into())
             }
-            FormatArgumentType::Text => FormatArgumentValue::Text(FText::read(cursor, options)?),
-            FormatArgumentType::Gender => unimplemented!(),
+            FormatArgumentType::Text => {
+                FormatArgumentValue::Text(FText::read_nested(cursor, options, depth + 1)?)
+            }
+            FormatArgumentType::Gender => FormatArgumentValue::Gender(cursor.read_enum()?),
         })
     }
 
@@ -792,45 +916,49 @@ impl FormatArgumentValue {
     ) -> Result<usize, Error> {
         match self {
             FormatArgumentValue::Int(value) => {
-                assert!(
-                    !options.supports_version(
-                        FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
-                    ),
-                    "FormatArgumentValue::Int is not compatible with TextFormatArgumentData64bitSupport"
-                );
+                if options.supports_version(
+                    FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
+                ) {
+                    Err(SerializeError::invalid_value(
+                        "FormatArgumentValue::Int is not compatible with TextFormatArgumentData64bitSupport",
+                    ))?
+                }
                 cursor.write_enum(FormatArgumentType::Int)?;
                 cursor.write_i32::<LittleEndian>(*value)?;
                 Ok(5)
             }
             FormatArgumentValue::Int64(value) => {
-                assert!(
-                    options.supports_version(
-                        FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
-                    ),
-                    "FormatArgumentValue::Int64 requires TextFormatArgumentData64bitSupport"
-                );
+                if !options.supports_version(
+                    FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
+                ) {
+                    Err(SerializeError::invalid_value(
+                        "FormatArgumentValue::Int64 requires TextFormatArgumentData64bitSupport",
+                    ))?
+                }
                 cursor.write_enum(FormatArgumentType::Int)?;
                 cursor.write_i64::<LittleEndian>(*value)?;
                 Ok(9)
             }
             FormatArgumentValue::UInt(value) => {
-                assert!(
-                    !options.supports_version(
-                        FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
-                    ),
-                    "FormatArgumentValue::UInt is not compatible with TextFormatArgumentData64bitSupport"
-                );
+                if options.supports_version(
+                    FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
+                ) {
+                    Err(SerializeError::invalid_value(
+                        "FormatArgumentValue::UInt is not compatible with TextFormatArgumentData64bitSupport",
+                    ))?
+                }
                 cursor.write_enum(FormatArgumentType::UInt)?;
                 cursor.write_u32::<LittleEndian>(*value)?;
                 Ok(5)
             }
             FormatArgumentValue::UInt64(value) => {
-                assert!(
-                    options.supports_version(
-                        FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
-                    ),
-                    "FormatArgumentValue::UInt64 requires TextFormatArgumentData64bitSupport"
-                );
+                if !options.supports_version(
+                    FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport,
+                ) {
+                    Err(SerializeError::invalid_value(
+                        "FormatArgumentValue::UInt64 requires TextFormatArgumentData64bitSupport",
+                    ))?
+                }
                 cursor.write_enum(FormatArgumentType::UInt)?;
                 cursor.write_u64::<LittleEndian>(*value)?;
                 Ok(9)
@@ -851,10 +979,28 @@ impl FormatArgumentValue {
                 len += value.write(cursor, options)?;
                 Ok(len)
             }
+            FormatArgumentValue::Gender(value) => {
+                cursor.write_enum(FormatArgumentType::Gender)?;
+                cursor.write_enum(*value)?;
+                Ok(2)
+            }
         }
     }
 }
 
+/// Text gender
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i8)]
+pub enum TextGender {
+    /// Masculine
+    Masculine,
+    /// Feminine
+    Feminine,
+    /// Neuter
+    Neuter,
+}
+
 /// Rounding mode
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
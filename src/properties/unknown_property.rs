@@ -1,16 +1,31 @@
-use std::io::{Cursor, Read, Seek, Write};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::{cursor_ext::WriteExt, error::Error};
+use crate::{
+    cursor_ext::{ReadExt, WriteExt},
+    error::Error,
+    object_version::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version},
+};
 
-use super::{PropertyOptions, PropertyTrait};
+use super::{
+    impl_tag,
+    property_tag::{EPropertyTagFlags, PropertyTag, PropertyTagData},
+    PropertyOptions, PropertyTrait,
+};
 
 /// This struct is read when a property is unknown to the deserializer
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnknownProperty {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    tag: PropertyTagData,
     property_name: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    header_parameters: Vec<u8>,
     raw: Vec<u8>,
 }
 
@@ -18,7 +33,12 @@ impl UnknownProperty {
     /// Creates a new `UnknownProperty` instance.
     #[inline]
     pub fn new(property_name: String, raw: Vec<u8>) -> Self {
-        UnknownProperty { property_name, raw }
+        UnknownProperty {
+            tag: PropertyTagData::default(),
+            property_name,
+            header_parameters: Vec::new(),
+            raw,
+        }
     }
 
     #[inline]
@@ -27,36 +47,102 @@ impl UnknownProperty {
         property_name: String,
         length: u32,
     ) -> Result<Self, Error> {
-        let mut data = vec![0u8; length as usize];
-        cursor.read_exact(&mut data)?;
+        let data = cursor.read_bytes(length as usize)?;
 
-        Ok(UnknownProperty {
-            property_name,
-            raw: data,
-        })
+        Ok(UnknownProperty::new(property_name, data))
     }
 
     #[inline]
     pub(crate) fn read_with_header<R: Read + Seek>(
         cursor: &mut R,
         property_name: String,
+        options: &mut PropertyOptions,
+    ) -> Result<Self, Error> {
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            let tag = PropertyTag::read_complete(cursor, &options.limits)?;
+            let mut property =
+                UnknownProperty::read_with_length(cursor, property_name, tag.length)?;
+            property.tag = tag.data;
+            return Ok(property);
+        }
+
+        let length = cursor.read_u32::<LittleEndian>()?;
+        let array_index = cursor.read_u32::<LittleEndian>()?;
+        let tag = PropertyTagData {
+            array_index,
+            ..PropertyTagData::read(cursor, options)?
+        };
+
+        let mut property = UnknownProperty::read_with_length(cursor, property_name, length)?;
+        property.tag = tag;
+        Ok(property)
+    }
+
+    /// Reads a known property type that failed to parse, keeping the raw bytes of the type
+    /// specific header parameters of older tags so that it is written back unchanged.
+    #[inline]
+    pub(crate) fn read_recovered<R: Read + Seek>(
+        cursor: &mut R,
+        property_name: String,
+        options: &mut PropertyOptions,
     ) -> Result<Self, Error> {
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            return Self::read_with_header(cursor, property_name, options);
+        }
+
         let length = cursor.read_u32::<LittleEndian>()?;
         let array_index = cursor.read_u32::<LittleEndian>()?;
-        assert_eq!(
+
+        let max_length = options.limits.max_string_length;
+        let start = cursor.stream_position()?;
+        match property_name.as_str() {
+            "StructProperty" => {
+                cursor.read_fstring_limited(max_length)?;
+                if options
+                    .supports_ue4_version(EUnrealEngineObjectUE4Version::StructGuidInPropertyTag)
+                {
+                    cursor.read_guid()?;
+                }
+            }
+            "ArrayProperty"
+                if options
+                    .supports_ue4_version(EUnrealEngineObjectUE4Version::InnerArrayTagInfo) =>
+            {
+                cursor.read_fstring_limited(max_length)?;
+            }
+            "ByteProperty" | "EnumProperty" | "SetProperty" => {
+                cursor.read_fstring_limited(max_length)?;
+            }
+            "MapProperty" => {
+                cursor.read_fstring_limited(max_length)?;
+                cursor.read_fstring_limited(max_length)?;
+            }
+            "BoolProperty" => {
+                cursor.read_u8()?;
+            }
+            _ => {}
+        }
+        let end = cursor.stream_position()?;
+        cursor.seek(SeekFrom::Start(start))?;
+        let header_parameters = cursor.read_bytes((end - start) as usize)?;
+
+        let tag = PropertyTagData {
             array_index,
-            0,
-            "Expected array_index value zero @ {:#x}",
-            cursor.stream_position()? - 4
-        );
-        let separator = cursor.read_u8()?;
-        assert_eq!(separator, 0);
-
-        UnknownProperty::read_with_length(cursor, property_name, length)
+            ..PropertyTagData::read(cursor, options)?
+        };
+
+        let mut property = UnknownProperty::read_with_length(cursor, property_name, length)?;
+        property.tag = tag;
+        property.header_parameters = header_parameters;
+        Ok(property)
     }
 }
 
 impl PropertyTrait for UnknownProperty {
+    impl_tag!();
+
     #[inline]
     fn write<W: Write>(
         &self,
@@ -73,12 +159,26 @@ impl PropertyTrait for UnknownProperty {
         let buf = buf.get_ref();
 
         let name_len = cursor.write_string(&self.property_name)?;
+        if options.supports_ue5_version(EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName)
+        {
+            // The type parameters of an unknown property can't be derived, so write them as read.
+            let tag_len = self.tag.write_complete(
+                cursor,
+                &self.tag.type_parameters,
+                buf.len(),
+                EPropertyTagFlags::NONE,
+            )?;
+            cursor.write_all(buf)?;
+            return Ok(name_len + tag_len + body_len);
+        }
+
         cursor.write_u32::<LittleEndian>(buf.len() as u32)?;
-        cursor.write_u32::<LittleEndian>(0)?;
-        cursor.write_u8(0)?;
+        cursor.write_u32::<LittleEndian>(self.tag.array_index)?;
+        cursor.write_all(&self.header_parameters)?;
+        let tag_len = self.tag.write(cursor, options)?;
         cursor.write_all(buf)?;
 
-        Ok(9 + name_len + body_len)
+        Ok(8 + name_len + self.header_parameters.len() + tag_len + body_len)
     }
 
     #[inline]
@@ -0,0 +1,300 @@
+//! Retargeting GVAS files to another engine version
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    builder::GvasFileBuilder,
+    custom_version::{CustomVersionTrait, FEditorObjectVersion, FUE5ReleaseStreamObjectVersion},
+    engine_version::EngineVersion,
+    error::{Error, SerializeError},
+    properties::{
+        array_property::ArrayProperty,
+        map_property::MapProperty,
+        struct_property::StructPropertyValue,
+        struct_types::{QuatD, QuatF, RotatorD, RotatorF, Vector2D, Vector2F, VectorD, VectorF},
+        Property, PropertyTrait,
+    },
+    types::Guid,
+    GvasHeader,
+};
+
+/// A struct value that lost precision while converting it to single precision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LossyConversion {
+    /// Path of the converted value
+    ///
+    /// Property names separated by dots, with the index of array, set and map entries, e.g.
+    /// `Player.Waypoints.2.Location`. Entries of static arrays after the first are followed by
+    /// their array index as well.
+    pub path: String,
+    /// The value before the conversion
+    pub original: StructPropertyValue,
+    /// The value after the conversion
+    pub converted: StructPropertyValue,
+}
+
+/// Custom versions replaced when retargeting a header
+const KNOWN_CUSTOM_VERSIONS: [Guid; 2] = [
+    FEditorObjectVersion::GUID,
+    FUE5ReleaseStreamObjectVersion::GUID,
+];
+
+/// Returns `header` rewritten for `engine_version`
+///
+/// The package file versions, engine version and the custom versions known to this crate are
+/// replaced. Other custom versions, the custom version format and the save game class name are
+/// kept. Version 1 headers are upgraded to the header version of `engine_version`.
+pub(crate) fn retarget_header(
+    header: &GvasHeader,
+    engine_version: EngineVersion,
+) -> Result<GvasHeader, Error> {
+    let (old_format, save_game_class_name) = match header {
+        GvasHeader::Version1 {
+            save_game_class_name,
+            ..
+        } => (None, save_game_class_name),
+        GvasHeader::Version2 {
+            custom_version_format,
+            save_game_class_name,
+            ..
+        }
+        | GvasHeader::Version3 {
+            custom_version_format,
+            save_game_class_name,
+            ..
+        } => (Some(*custom_version_format), save_game_class_name),
+    };
+    let old_custom_versions = header.get_custom_versions();
+
+    let mut header =
+        GvasFileBuilder::new(engine_version, save_game_class_name.clone()).build_header()?;
+    match &mut header {
+        GvasHeader::Version1 { .. } => {}
+        GvasHeader::Version2 {
+            custom_version_format,
+            custom_versions,
+            ..
+        }
+        | GvasHeader::Version3 {
+            custom_version_format,
+            custom_versions,
+            ..
+        } => {
+            let known = std::mem::take(&mut custom_versions.0);
+            custom_versions.0 = old_custom_versions
+                .iter()
+                .filter(|(guid, _)| !KNOWN_CUSTOM_VERSIONS.contains(guid))
+                .map(|(guid, version)| (*guid, *version))
+                .chain(known)
+                .collect();
+            if let Some(old_format) = old_format {
+                *custom_version_format = old_format;
+            }
+        }
+    }
+    Ok(header)
+}
+
+/// Converts struct values to the precision used by a file with `header`
+///
+/// Fails if two map keys become equal after the conversion, as one of the entries would be lost.
+pub(crate) fn convert_properties(
+    header: &GvasHeader,
+    properties: &mut [(String, Property)],
+) -> Result<Vec<LossyConversion>, Error> {
+    let large_world_coordinates = header
+        .get_custom_versions()
+        .get(&FUE5ReleaseStreamObjectVersion::GUID)
+        .is_some_and(|version| {
+            *version >= u32::from(FUE5ReleaseStreamObjectVersion::LargeWorldCoordinates)
+        });
+
+    let mut converter = Converter {
+        large_world_coordinates,
+        path: vec![],
+        lossy_conversions: vec![],
+        key_collision: None,
+    };
+    converter.properties(properties);
+    match converter.key_collision {
+        Some(path) => Err(SerializeError::invalid_value(format!(
+            "Map key {path} equals another key after converting it"
+        )))?,
+        None => Ok(converter.lossy_conversions),
+    }
+}
+
+/// Walks a property tree, converting struct values between single and double precision
+struct Converter {
+    large_world_coordinates: bool,
+    path: Vec<String>,
+    lossy_conversions: Vec<LossyConversion>,
+    /// Path of the first map key that became equal to another key
+    key_collision: Option<String>,
+}
+
+impl Converter {
+    fn properties(&mut self, properties: &mut [(String, Property)]) {
+        for (name, property) in properties {
+            match property.tag().map(|tag| tag.array_index) {
+                Some(array_index @ 1..) => self.path.push(format!("{name}.{array_index}")),
+                _ => self.path.push(name.clone()),
+            }
+            self.property(property);
+            self.path.pop();
+        }
+    }
+
+    fn entries<'a>(&mut self, properties: impl Iterator<Item = &'a mut Property>) {
+        for (index, property) in properties.enumerate() {
+            self.path.push(index.to_string());
+            self.property(property);
+            self.path.pop();
+        }
+    }
+
+    fn property(&mut self, property: &mut Property) {
+        match property {
+            Property::StructProperty(property) => self.struct_value(&mut property.value),
+            Property::StructPropertyValue(value) => self.struct_value(value),
+            Property::ArrayProperty(ArrayProperty::Structs { structs, .. }) => {
+                for (index, value) in structs.iter_mut().enumerate() {
+                    self.path.push(index.to_string());
+                    self.struct_value(value);
+                    self.path.pop();
+                }
+            }
+            Property::ArrayProperty(ArrayProperty::Properties { properties, .. }) => {
+                self.entries(properties.iter_mut())
+            }
+            Property::SetProperty(property) => {
+                self.entries(property.keys_to_remove.iter_mut());
+                self.entries(property.properties.iter_mut());
+            }
+            Property::MapProperty(MapProperty::EnumProperty { enum_props, .. }) => {
+                self.entries(enum_props.values_mut())
+            }
+            Property::MapProperty(MapProperty::NameProperty { name_props, .. }) => {
+                self.entries(name_props.values_mut())
+            }
+            Property::MapProperty(MapProperty::StrProperty { str_props, .. }) => {
+                self.entries(str_props.values_mut())
+            }
+            Property::MapProperty(MapProperty::Properties {
+                keys_to_remove,
+                value,
+                ..
+            }) => {
+                self.entries(keys_to_remove.iter_mut());
+                // Keys are hashed, so the map is rebuilt with the converted keys
+                let entries = std::mem::take(&mut value.0);
+                for (index, (mut key, mut entry)) in entries.into_iter().enumerate() {
+                    self.path.push(index.to_string());
+                    self.path.push("Key".to_string());
+                    self.property(&mut key);
+                    if value.0.contains_key(&key) && self.key_collision.is_none() {
+                        self.key_collision = Some(self.path.join("."));
+                    }
+                    self.path.pop();
+                    self.path.push("Value".to_string());
+                    self.property(&mut entry);
+                    self.path.pop();
+                    self.path.pop();
+                    value.0.insert(key, entry);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn struct_value(&mut self, value: &mut StructPropertyValue) {
+        if let StructPropertyValue::CustomStruct(properties) = value {
+            self.properties(properties);
+            return;
+        }
+
+        let converted = match (self.large_world_coordinates, &*value) {
+            (true, StructPropertyValue::Vector2F(vector)) => {
+                Vector2D::new(widen(vector.x), widen(vector.y)).into()
+            }
+            (true, StructPropertyValue::VectorF(vector)) => {
+                VectorD::new(widen(vector.x), widen(vector.y), widen(vector.z)).into()
+            }
+            (true, StructPropertyValue::RotatorF(rotator)) => RotatorD::new(
+                widen(rotator.pitch),
+                widen(rotator.yaw),
+                widen(rotator.roll),
+            )
+            .into(),
+            (true, StructPropertyValue::QuatF(quat)) => {
+                QuatD::new(widen(quat.x), widen(quat.y), widen(quat.z), widen(quat.w)).into()
+            }
+            (false, StructPropertyValue::Vector2D(vector)) => {
+                Vector2F::new(narrow(vector.x), narrow(vector.y)).into()
+            }
+            (false, StructPropertyValue::VectorD(vector)) => {
+                VectorF::new(narrow(vector.x), narrow(vector.y), narrow(vector.z)).into()
+            }
+            (false, StructPropertyValue::RotatorD(rotator)) => RotatorF::new(
+                narrow(rotator.pitch),
+                narrow(rotator.yaw),
+                narrow(rotator.roll),
+            )
+            .into(),
+            (false, StructPropertyValue::QuatD(quat)) => QuatF::new(
+                narrow(quat.x),
+                narrow(quat.y),
+                narrow(quat.z),
+                narrow(quat.w),
+            )
+            .into(),
+            _ => return,
+        };
+
+        let original = std::mem::replace(value, converted);
+        if !self.large_world_coordinates && !widens_to(value, &original) {
+            self.lossy_conversions.push(LossyConversion {
+                path: self.path.join("."),
+                original,
+                converted: value.clone(),
+            });
+        }
+    }
+}
+
+#[inline]
+fn widen(value: OrderedFloat<f32>) -> f64 {
+    f64::from(value.0)
+}
+
+#[inline]
+fn narrow(value: OrderedFloat<f64>) -> f32 {
+    value.0 as f32
+}
+
+/// Checks if converting the single precision `value` back to double precision yields `original`
+fn widens_to(value: &StructPropertyValue, original: &StructPropertyValue) -> bool {
+    let same = |value: OrderedFloat<f32>, original: OrderedFloat<f64>| {
+        OrderedFloat(widen(value)) == original
+    };
+    match (value, original) {
+        (StructPropertyValue::Vector2F(value), StructPropertyValue::Vector2D(original)) => {
+            same(value.x, original.x) && same(value.y, original.y)
+        }
+        (StructPropertyValue::VectorF(value), StructPropertyValue::VectorD(original)) => {
+            same(value.x, original.x) && same(value.y, original.y) && same(value.z, original.z)
+        }
+        (StructPropertyValue::RotatorF(value), StructPropertyValue::RotatorD(original)) => {
+            same(value.pitch, original.pitch)
+                && same(value.yaw, original.yaw)
+                && same(value.roll, original.roll)
+        }
+        (StructPropertyValue::QuatF(value), StructPropertyValue::QuatD(original)) => {
+            same(value.x, original.x)
+                && same(value.y, original.y)
+                && same(value.z, original.z)
+                && same(value.w, original.w)
+        }
+        _ => true,
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+
+use flate2::Crc;
+
+/// Reader over a stream that can't seek, tracking the position
+///
+/// Seeking only moves forward, which is all reading without diagnostics needs.
+pub(crate) struct TrackingReader<R: Read> {
+    reader: BufReader<R>,
+    position: u64,
+}
+
+impl<R: Read> TrackingReader<R> {
+    /// Create new instance of TrackingReader
+    #[inline]
+    pub(crate) fn new(reader: R) -> Self {
+        TrackingReader {
+            reader: BufReader::new(reader),
+            position: 0,
+        }
+    }
+
+    /// Returns the underlying reader, dropping data read ahead into the buffer
+    #[inline]
+    pub(crate) fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+impl<R: Read> Read for TrackingReader<R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: Read> Seek for TrackingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(target) => Some(target),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(_) => None,
+        };
+        match target {
+            Some(target) if target >= self.position => {
+                let skip = target - self.position;
+                if io::copy(&mut self.by_ref().take(skip), &mut io::sink())? != skip {
+                    Err(io::Error::from(io::ErrorKind::UnexpectedEof))?
+                }
+                Ok(self.position)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Can't seek backwards in a stream",
+            )),
+        }
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+}
+
+/// Buffered writer tracking the CRC-32 of the data written
+pub(crate) struct TrackingWriter<W: Write> {
+    writer: BufWriter<W>,
+    crc: Crc,
+}
+
+impl<W: Write> TrackingWriter<W> {
+    /// Create new instance of TrackingWriter
+    #[inline]
+    pub(crate) fn new(writer: W) -> Self {
+        TrackingWriter {
+            writer: BufWriter::new(writer),
+            crc: Crc::new(),
+        }
+    }
+
+    /// CRC-32 of the data written so far
+    #[inline]
+    pub(crate) fn crc32(&self) -> u32 {
+        self.crc.sum()
+    }
+
+    /// Flushes the buffer and returns the underlying writer
+    #[inline]
+    pub(crate) fn into_inner(self) -> io::Result<W> {
+        self.writer
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)
+    }
+}
+
+impl<W: Write> Write for TrackingWriter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.crc.update(&buf[..written]);
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -179,8 +179,8 @@ impl FromStr for Guid {
         }
         let mut guid = Guid(Default::default());
         for i in 0..16 {
-            guid.0[i] =
-                u8::from_str_radix(&cleaned[i * 2..i * 2 + 2], 16).map_err(|_| ParseGuidError)?;
+            let byte = cleaned.get(i * 2..i * 2 + 2).ok_or(ParseGuidError)?;
+            guid.0[i] = u8::from_str_radix(byte, 16).map_err(|_| ParseGuidError)?;
         }
         Ok(guid)
     }
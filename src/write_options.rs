@@ -0,0 +1,17 @@
+//! Options for writing files
+
+use crate::container::SaveContainer;
+
+/// Options for writing files
+///
+/// Used by [`GvasFile::write_with_options`](crate::GvasFile::write_with_options)
+#[derive(Clone, Copy, Default)]
+pub struct WriteOptions<'a> {
+    /// Containers searched for the container of the file before the built-in ones
+    pub containers: &'a [&'a dyn SaveContainer],
+    /// Zlib compression level from 0 to 9, overriding the level a Palworld or
+    /// `SerializeCompressed` save was read with
+    ///
+    /// The file is compressed again, even if it is unchanged.
+    pub compression_level: Option<u32>,
+}
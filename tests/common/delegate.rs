@@ -9,7 +9,7 @@ use gvas::{
         Property,
     },
     types::{map::HashableIndexMap, Guid},
-    GvasFile, GvasHeader,
+    GvasFile, GvasHeader, GvasTrailer,
 };
 use std::str::FromStr;
 
@@ -213,7 +213,7 @@ pub(crate) fn expected() -> GvasFile {
             ]),
             save_game_class_name: String::from("/Script/SaveFileTest.TestSaveGame"),
         },
-        properties: HashableIndexMap::from([
+        properties: vec![
             (
                 String::from("DynamicDelegate"),
                 Property::from(DelegateProperty::new(Delegate::new(
@@ -239,6 +239,7 @@ pub(crate) fn expected() -> GvasFile {
                     )]),
                 )),
             ),
-        ]),
+        ],
+        trailer: GvasTrailer::default(),
     }
 }
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use gvas::properties::Property;
+
 pub mod delegate;
 pub mod features;
 pub mod options;
@@ -33,3 +35,12 @@ pub const TEXT_PROPERTY_NOARRAY: &str = "resources/test/text_property_noarray.bi
 pub const TRANSFORM_PATH: &str = "resources/test/transform.sav";
 pub const VECTOR2D_PATH: &str = "resources/test/vector2d.sav";
 pub const TAGCONTAINER_PATH: &str = "resources/test/tagcontainer.sav";
+
+/// Returns the first property named `name`
+pub fn find_property<'a>(properties: &'a [(String, Property)], name: &str) -> &'a Property {
+    properties
+        .iter()
+        .find(|(property_name, _)| property_name == name)
+        .map(|(_, property)| property)
+        .expect("Find property")
+}
@@ -3,7 +3,7 @@ use gvas::{
     game_version::DeserializedGameVersion,
     properties::{int_property::FloatProperty, Property},
     types::{map::HashableIndexMap, Guid},
-    GvasFile, GvasHeader,
+    GvasFile, GvasHeader, GvasTrailer,
 };
 use std::str::FromStr;
 
@@ -224,7 +224,7 @@ pub(crate) fn expected() -> GvasFile {
             ]),
             save_game_class_name: "/Game/UI/BP_SaveOptions.BP_SaveOptions_C".into(),
         },
-        properties: HashableIndexMap::from([
+        properties: vec![
             (
                 "Slider1".into(),
                 Property::from(FloatProperty::new(0.16610672)),
@@ -233,6 +233,7 @@ pub(crate) fn expected() -> GvasFile {
                 "Slider2".into(),
                 Property::from(FloatProperty::new(0.28251615)),
             ),
-        ]),
+        ],
+        trailer: GvasTrailer::default(),
     }
 }
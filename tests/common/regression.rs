@@ -59,11 +59,14 @@ pub const REGRESSION_01_JSON: &str = r#"{
     },
     "save_game_class_name": "/Script/SaveTest.TestSaveGame"
   },
-  "properties": {
-    "Thing": {
-      "type": "StructProperty",
-      "type_name": "Guid",
-      "Guid": "D49982B3-DF3D-D549-B4AE-57C71D5838E4"
-    }
-  }
+  "properties": [
+    [
+      "Thing",
+      {
+        "type": "StructProperty",
+        "type_name": "Guid",
+        "Guid": "D49982B3-DF3D-D549-B4AE-57C71D5838E4"
+      }
+    ]
+  ]
 }"#;
@@ -8,13 +8,14 @@ use gvas::{
         map_property::MapProperty,
         name_property::NameProperty,
         object_property::ObjectProperty,
+        property_tag::PropertyTagData,
         str_property::StrProperty,
         struct_property::{StructProperty, StructPropertyValue},
         struct_types::DateTime,
         Property,
     },
     types::{map::HashableIndexMap, Guid},
-    GvasFile, GvasHeader,
+    GvasFile, GvasHeader, GvasTrailer,
 };
 
 pub(crate) fn hints() -> HashMap<String, String> {
@@ -271,10 +272,11 @@ pub(crate) fn expected() -> GvasFile {
             ]),
             save_game_class_name: String::from("/Script/CD.CDSave_GameState"),
         },
-        properties: HashableIndexMap::from([
+        properties: vec![
             (
                 String::from("LastSaveTime"),
                 Property::from(StructProperty {
+                    tag: PropertyTagData::default(),
                     type_name: String::from("DateTime"),
                     guid: Guid::default(),
                     value: StructPropertyValue::from(DateTime {
@@ -294,82 +296,72 @@ pub(crate) fn expected() -> GvasFile {
                 Property::from(MapProperty::new(
                     String::from("NameProperty"),
                     String::from("StructProperty"),
-                    0,
+                    vec![],
                     HashableIndexMap::from([
                         (
                             Property::from(NameProperty::from("unlock.welcomescreen.seen")),
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AsFloat"),
-                                        vec![Property::from(FloatProperty::new(0f32))],
-                                    ),
-                                    (
-                                        String::from("AsString"),
-                                        vec![Property::from(StrProperty::new(None))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AsFloat"),
+                                    Property::from(FloatProperty::new(0f32)),
+                                ),
+                                (
+                                    String::from("AsString"),
+                                    Property::from(StrProperty::new(None)),
+                                ),
+                            ])),
                         ),
                         (
                             Property::from(NameProperty::from("game.tutorial.finished")),
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AsFloat"),
-                                        vec![Property::from(FloatProperty::new(1f32))],
-                                    ),
-                                    (
-                                        String::from("AsString"),
-                                        vec![Property::from(StrProperty::new(None))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AsFloat"),
+                                    Property::from(FloatProperty::new(1f32)),
+                                ),
+                                (
+                                    String::from("AsString"),
+                                    Property::from(StrProperty::new(None)),
+                                ),
+                            ])),
                         ),
                         (
                             Property::from(NameProperty::from("game.tutorial.skipped")),
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AsFloat"),
-                                        vec![Property::from(FloatProperty::new(1f32))],
-                                    ),
-                                    (
-                                        String::from("AsString"),
-                                        vec![Property::from(StrProperty::new(None))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AsFloat"),
+                                    Property::from(FloatProperty::new(1f32)),
+                                ),
+                                (
+                                    String::from("AsString"),
+                                    Property::from(StrProperty::new(None)),
+                                ),
+                            ])),
                         ),
                         (
                             Property::from(NameProperty::from("dialogs.messages.seen.Rumiko.0.50")),
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AsFloat"),
-                                        vec![Property::from(FloatProperty::new(1f32))],
-                                    ),
-                                    (
-                                        String::from("AsString"),
-                                        vec![Property::from(StrProperty::new(None))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AsFloat"),
+                                    Property::from(FloatProperty::new(1f32)),
+                                ),
+                                (
+                                    String::from("AsString"),
+                                    Property::from(StrProperty::new(None)),
+                                ),
+                            ])),
                         ),
                         (
                             Property::from(NameProperty::from("codex.Rumiko")),
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AsFloat"),
-                                        vec![Property::from(FloatProperty::new(1f32))],
-                                    ),
-                                    (
-                                        String::from("AsString"),
-                                        vec![Property::from(StrProperty::new(None))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AsFloat"),
+                                    Property::from(FloatProperty::new(1f32)),
+                                ),
+                                (
+                                    String::from("AsString"),
+                                    Property::from(StrProperty::new(None)),
+                                ),
+                            ])),
                         ),
                     ]),
                 )),
@@ -377,60 +369,49 @@ pub(crate) fn expected() -> GvasFile {
             (
                 String::from("PlayerAttributes"),
                 Property::from(MapProperty::Properties {
+                    tag: PropertyTagData::default(),
                     key_type: String::from("StructProperty"),
                     value_type: String::from("FloatProperty"),
-                    allocation_flags: 0,
+                    keys_to_remove: vec![],
                     value: HashableIndexMap::from([
                         (
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AttributeName"),
-                                        vec![Property::from(StrProperty::from(
-                                            "Currency_Blueprints",
-                                        ))],
-                                    ),
-                                    (
-                                        String::from("Attribute"),
-                                        vec![Property::from(FieldPathProperty::new(
-                                            FieldPath::new(
-                                                Vec::from([String::from("Currency_Blueprints")]),
-                                                String::from("/Script/CD.CDPlayerAttributeSet"),
-                                            ),
-                                        ))],
-                                    ),
-                                    (
-                                        String::from("AttributeOwner"),
-                                        vec![Property::from(ObjectProperty::from("None"))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AttributeName"),
+                                    Property::from(StrProperty::from("Currency_Blueprints")),
+                                ),
+                                (
+                                    String::from("Attribute"),
+                                    Property::from(FieldPathProperty::new(FieldPath::new(
+                                        Vec::from([String::from("Currency_Blueprints")]),
+                                        String::from("/Script/CD.CDPlayerAttributeSet"),
+                                    ))),
+                                ),
+                                (
+                                    String::from("AttributeOwner"),
+                                    Property::from(ObjectProperty::from("None")),
+                                ),
+                            ])),
                             Property::from(FloatProperty::new(0f32)),
                         ),
                         (
-                            Property::from(StructPropertyValue::CustomStruct(
-                                HashableIndexMap::from([
-                                    (
-                                        String::from("AttributeName"),
-                                        vec![Property::from(StrProperty::from(
-                                            "Currency_Electrum",
-                                        ))],
-                                    ),
-                                    (
-                                        String::from("Attribute"),
-                                        vec![Property::from(FieldPathProperty::new(
-                                            FieldPath::new(
-                                                Vec::from([String::from("Currency_Electrum")]),
-                                                String::from("/Script/CD.CDPlayerAttributeSet"),
-                                            ),
-                                        ))],
-                                    ),
-                                    (
-                                        String::from("AttributeOwner"),
-                                        vec![Property::from(ObjectProperty::from("None"))],
-                                    ),
-                                ]),
-                            )),
+                            Property::from(StructPropertyValue::CustomStruct(vec![
+                                (
+                                    String::from("AttributeName"),
+                                    Property::from(StrProperty::from("Currency_Electrum")),
+                                ),
+                                (
+                                    String::from("Attribute"),
+                                    Property::from(FieldPathProperty::new(FieldPath::new(
+                                        Vec::from([String::from("Currency_Electrum")]),
+                                        String::from("/Script/CD.CDPlayerAttributeSet"),
+                                    ))),
+                                ),
+                                (
+                                    String::from("AttributeOwner"),
+                                    Property::from(ObjectProperty::from("None")),
+                                ),
+                            ])),
                             Property::from(FloatProperty::new(0f32)),
                         ),
                     ]),
@@ -442,7 +423,8 @@ pub(crate) fn expected() -> GvasFile {
                     "/Game/Weapons/RocketLauncher/Blueprints/BP_RocketLauncher.BP_RocketLauncher_C",
                 )),
             ),
-        ]),
+        ],
+        trailer: GvasTrailer::default(),
     }
 }
 
@@ -516,187 +498,220 @@ pub(crate) const SAVESLOT_03_JSON: &str = r#"{
     },
     "save_game_class_name": "/Script/CD.CDSave_GameState"
   },
-  "properties": {
-    "LastSaveTime": {
-      "type": "StructProperty",
-      "type_name": "DateTime",
-      "DateTime": {
-        "ticks": 638160761644140000
-      }
-    },
-    "PlayerClass": {
-      "type": "ObjectProperty",
-      "value": "/Game/Character/Player/Blueprints/BP_Soldier.BP_Soldier_C"
-    },
-    "Version": {
-      "type": "IntProperty",
-      "value": 3
-    },
-    "GameplayDatabase": {
-      "type": "MapProperty",
-      "value_type": "StructProperty",
-      "name_props": {
-        "unlock.welcomescreen.seen": {
-          "type": "StructPropertyValue",
-          "CustomStruct": {
-            "AsFloat": [
-              {
-                "type": "FloatProperty",
-                "value": 0.0
-              }
-            ],
-            "AsString": [
-              {
-                "type": "StrProperty"
-              }
-            ]
-          }
-        },
-        "game.tutorial.finished": {
-          "type": "StructPropertyValue",
-          "CustomStruct": {
-            "AsFloat": [
-              {
-                "type": "FloatProperty",
-                "value": 1.0
-              }
-            ],
-            "AsString": [
-              {
-                "type": "StrProperty"
-              }
-            ]
-          }
-        },
-        "game.tutorial.skipped": {
-          "type": "StructPropertyValue",
-          "CustomStruct": {
-            "AsFloat": [
-              {
-                "type": "FloatProperty",
-                "value": 1.0
-              }
-            ],
-            "AsString": [
-              {
-                "type": "StrProperty"
-              }
-            ]
-          }
-        },
-        "dialogs.messages.seen.Rumiko.0.50": {
-          "type": "StructPropertyValue",
-          "CustomStruct": {
-            "AsFloat": [
-              {
-                "type": "FloatProperty",
-                "value": 1.0
-              }
-            ],
-            "AsString": [
-              {
-                "type": "StrProperty"
-              }
-            ]
-          }
-        },
-        "codex.Rumiko": {
-          "type": "StructPropertyValue",
-          "CustomStruct": {
-            "AsFloat": [
-              {
-                "type": "FloatProperty",
-                "value": 1.0
-              }
-            ],
-            "AsString": [
-              {
-                "type": "StrProperty"
-              }
-            ]
-          }
+  "properties": [
+    [
+      "LastSaveTime",
+      {
+        "type": "StructProperty",
+        "type_name": "DateTime",
+        "DateTime": {
+          "ticks": 638160761644140000
         }
       }
-    },
-    "PlayerAttributes": {
-      "type": "MapProperty",
-      "key_type": "StructProperty",
-      "value_type": "FloatProperty",
-      "allocation_flags": 0,
-      "value": [
-        [
-          {
+    ],
+    [
+      "PlayerClass",
+      {
+        "type": "ObjectProperty",
+        "value": "/Game/Character/Player/Blueprints/BP_Soldier.BP_Soldier_C"
+      }
+    ],
+    [
+      "Version",
+      {
+        "type": "IntProperty",
+        "value": 3
+      }
+    ],
+    [
+      "GameplayDatabase",
+      {
+        "type": "MapProperty",
+        "value_type": "StructProperty",
+        "name_props": {
+          "unlock.welcomescreen.seen": {
             "type": "StructPropertyValue",
-            "CustomStruct": {
-              "AttributeName": [
+            "CustomStruct": [
+              [
+                "AsFloat",
                 {
-                  "type": "StrProperty",
-                  "value": "Currency_Blueprints"
+                  "type": "FloatProperty",
+                  "value": 0.0
                 }
               ],
-              "Attribute": [
+              [
+                "AsString",
                 {
-                  "type": "FieldPathProperty",
-                  "value": {
-                    "path": [
-                      "Currency_Blueprints"
-                    ],
-                    "resolved_owner": "/Script/CD.CDPlayerAttributeSet"
-                  }
+                  "type": "StrProperty"
+                }
+              ]
+            ]
+          },
+          "game.tutorial.finished": {
+            "type": "StructPropertyValue",
+            "CustomStruct": [
+              [
+                "AsFloat",
+                {
+                  "type": "FloatProperty",
+                  "value": 1.0
                 }
               ],
-              "AttributeOwner": [
+              [
+                "AsString",
                 {
-                  "type": "ObjectProperty",
-                  "value": "None"
+                  "type": "StrProperty"
                 }
               ]
-            }
+            ]
           },
-          {
-            "type": "FloatProperty",
-            "value": 0.0
-          }
-        ],
-        [
-          {
+          "game.tutorial.skipped": {
             "type": "StructPropertyValue",
-            "CustomStruct": {
-              "AttributeName": [
+            "CustomStruct": [
+              [
+                "AsFloat",
                 {
-                  "type": "StrProperty",
-                  "value": "Currency_Electrum"
+                  "type": "FloatProperty",
+                  "value": 1.0
                 }
               ],
-              "Attribute": [
+              [
+                "AsString",
                 {
-                  "type": "FieldPathProperty",
-                  "value": {
-                    "path": [
-                      "Currency_Electrum"
-                    ],
-                    "resolved_owner": "/Script/CD.CDPlayerAttributeSet"
-                  }
+                  "type": "StrProperty"
+                }
+              ]
+            ]
+          },
+          "dialogs.messages.seen.Rumiko.0.50": {
+            "type": "StructPropertyValue",
+            "CustomStruct": [
+              [
+                "AsFloat",
+                {
+                  "type": "FloatProperty",
+                  "value": 1.0
                 }
               ],
-              "AttributeOwner": [
+              [
+                "AsString",
                 {
-                  "type": "ObjectProperty",
-                  "value": "None"
+                  "type": "StrProperty"
                 }
               ]
-            }
+            ]
           },
-          {
-            "type": "FloatProperty",
-            "value": 0.0
+          "codex.Rumiko": {
+            "type": "StructPropertyValue",
+            "CustomStruct": [
+              [
+                "AsFloat",
+                {
+                  "type": "FloatProperty",
+                  "value": 1.0
+                }
+              ],
+              [
+                "AsString",
+                {
+                  "type": "StrProperty"
+                }
+              ]
+            ]
           }
+        }
+      }
+    ],
+    [
+      "PlayerAttributes",
+      {
+        "type": "MapProperty",
+        "key_type": "StructProperty",
+        "value_type": "FloatProperty",
+        "value": [
+          [
+            {
+              "type": "StructPropertyValue",
+              "CustomStruct": [
+                [
+                  "AttributeName",
+                  {
+                    "type": "StrProperty",
+                    "value": "Currency_Blueprints"
+                  }
+                ],
+                [
+                  "Attribute",
+                  {
+                    "type": "FieldPathProperty",
+                    "value": {
+                      "path": [
+                        "Currency_Blueprints"
+                      ],
+                      "resolved_owner": "/Script/CD.CDPlayerAttributeSet"
+                    }
+                  }
+                ],
+                [
+                  "AttributeOwner",
+                  {
+                    "type": "ObjectProperty",
+                    "value": "None"
+                  }
+                ]
+              ]
+            },
+            {
+              "type": "FloatProperty",
+              "value": 0.0
+            }
+          ],
+          [
+            {
+              "type": "StructPropertyValue",
+              "CustomStruct": [
+                [
+                  "AttributeName",
+                  {
+                    "type": "StrProperty",
+                    "value": "Currency_Electrum"
+                  }
+                ],
+                [
+                  "Attribute",
+                  {
+                    "type": "FieldPathProperty",
+                    "value": {
+                      "path": [
+                        "Currency_Electrum"
+                      ],
+                      "resolved_owner": "/Script/CD.CDPlayerAttributeSet"
+                    }
+                  }
+                ],
+                [
+                  "AttributeOwner",
+                  {
+                    "type": "ObjectProperty",
+                    "value": "None"
+                  }
+                ]
+              ]
+            },
+            {
+              "type": "FloatProperty",
+              "value": 0.0
+            }
+          ]
         ]
-      ]
-    },
-    "SecondaryWeaponClass": {
-      "type": "ObjectProperty",
-      "value": "/Game/Weapons/RocketLauncher/Blueprints/BP_RocketLauncher.BP_RocketLauncher_C"
-    }
-  }
+      }
+    ],
+    [
+      "SecondaryWeaponClass",
+      {
+        "type": "ObjectProperty",
+        "value": "/Game/Weapons/RocketLauncher/Blueprints/BP_RocketLauncher.BP_RocketLauncher_C"
+      }
+    ]
+  ]
 }"#;
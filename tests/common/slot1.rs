@@ -8,13 +8,14 @@ use gvas::{
             Int64Property, Int8Property, IntProperty, UInt16Property, UInt32Property,
             UInt64Property,
         },
+        property_tag::PropertyTagData,
         str_property::StrProperty,
         struct_property::{StructProperty, StructPropertyValue},
         struct_types::DateTime,
         Property,
     },
     types::{map::HashableIndexMap, Guid},
-    GvasFile, GvasHeader,
+    GvasFile, GvasHeader, GvasTrailer,
 };
 use std::str::FromStr;
 
@@ -264,10 +265,11 @@ pub(crate) fn expected() -> GvasFile {
             ]),
             save_game_class_name: String::from("/Script/UE4SaveFile.TestSaveGame"),
         },
-        properties: HashableIndexMap::from([
+        properties: vec![
             (
                 String::from("u8_test"),
                 Property::from(ByteProperty {
+                    tag: PropertyTagData::default(),
                     name: Some(String::from("None")),
                     value: BytePropertyValue::Byte(129),
                 }),
@@ -315,17 +317,19 @@ pub(crate) fn expected() -> GvasFile {
             (
                 String::from("struct_property"),
                 Property::from(StructProperty {
+                    tag: PropertyTagData::default(),
                     type_name: String::from("CustomStruct"),
                     guid: Guid::default(),
-                    value: StructPropertyValue::CustomStruct(HashableIndexMap::from([(
+                    value: StructPropertyValue::CustomStruct(vec![(
                         String::from("test_field"),
-                        vec![Property::from(UInt64Property::new(12345u64))],
-                    )])),
+                        Property::from(UInt64Property::new(12345u64)),
+                    )]),
                 }),
             ),
             (
                 String::from("date_time_property"),
                 Property::from(StructProperty {
+                    tag: PropertyTagData::default(),
                     type_name: String::from("DateTime"),
                     guid: Guid::default(),
                     value: StructPropertyValue::from(DateTime {
@@ -336,30 +340,33 @@ pub(crate) fn expected() -> GvasFile {
             (
                 String::from("array_of_structs"),
                 Property::from(ArrayProperty::Structs {
+                    tag: PropertyTagData::default(),
                     field_name: String::from("array_of_structs"),
                     type_name: String::from("CustomStruct"),
                     guid: Guid::default(),
                     structs: vec![
-                        StructPropertyValue::CustomStruct(HashableIndexMap::from([(
+                        StructPropertyValue::CustomStruct(vec![(
                             String::from("test_field"),
-                            vec![Property::from(UInt64Property::new(10u64))],
-                        )])),
-                        StructPropertyValue::CustomStruct(HashableIndexMap::from([(
+                            Property::from(UInt64Property::new(10u64)),
+                        )]),
+                        StructPropertyValue::CustomStruct(vec![(
                             String::from("test_field"),
-                            vec![Property::from(UInt64Property::new(10u64))],
-                        )])),
+                            Property::from(UInt64Property::new(10u64)),
+                        )]),
                     ],
                 }),
             ),
             (
                 String::from("array_of_ints"),
                 Property::from(ArrayProperty::Ints {
+                    tag: PropertyTagData::default(),
                     ints: vec![12, 12, 12, 12, 12],
                 }),
             ),
             (
                 String::from("array_of_strings"),
                 Property::from(ArrayProperty::Strings {
+                    tag: PropertyTagData::default(),
                     strings: vec![
                         Some(String::from("Hello world from array")),
                         Some(String::from("Hello world from array")),
@@ -367,7 +374,8 @@ pub(crate) fn expected() -> GvasFile {
                     ],
                 }),
             ),
-        ]),
+        ],
+        trailer: GvasTrailer::default(),
     }
 }
 
@@ -444,115 +452,166 @@ pub const SLOT1_JSON: &str = r#"{
     },
     "save_game_class_name": "/Script/UE4SaveFile.TestSaveGame"
   },
-  "properties": {
-    "u8_test": {
-      "type": "ByteProperty",
-      "name": "None",
-      "Byte": 129
-    },
-    "i8_test": {
-      "type": "Int8Property",
-      "value": -123
-    },
-    "ushort_test": {
-      "type": "UInt16Property",
-      "value": 65530
-    },
-    "short_test": {
-      "type": "Int16Property",
-      "value": -32764
-    },
-    "uint32_test": {
-      "type": "UInt32Property",
-      "value": 4294967294
-    },
-    "int32_test": {
-      "type": "IntProperty",
-      "value": -2147483647
-    },
-    "ulong_test": {
-      "type": "UInt64Property",
-      "value": 18446744073709551614
-    },
-    "long_test": {
-      "type": "Int64Property",
-      "value": -9223372036854775807
-    },
-    "f_property": {
-      "type": "FloatProperty",
-      "value": 3.14159
-    },
-    "d_property": {
-      "type": "DoubleProperty",
-      "value": 3.14159265358979
-    },
-    "str_property": {
-      "type": "StrProperty",
-      "value": "Hello world"
-    },
-    "struct_property": {
-      "type": "StructProperty",
-      "type_name": "CustomStruct",
-      "CustomStruct": {
-        "test_field": [
-          {
-            "type": "UInt64Property",
-            "value": 12345
-          }
+  "properties": [
+    [
+      "u8_test",
+      {
+        "type": "ByteProperty",
+        "name": "None",
+        "Byte": 129
+      }
+    ],
+    [
+      "i8_test",
+      {
+        "type": "Int8Property",
+        "value": -123
+      }
+    ],
+    [
+      "ushort_test",
+      {
+        "type": "UInt16Property",
+        "value": 65530
+      }
+    ],
+    [
+      "short_test",
+      {
+        "type": "Int16Property",
+        "value": -32764
+      }
+    ],
+    [
+      "uint32_test",
+      {
+        "type": "UInt32Property",
+        "value": 4294967294
+      }
+    ],
+    [
+      "int32_test",
+      {
+        "type": "IntProperty",
+        "value": -2147483647
+      }
+    ],
+    [
+      "ulong_test",
+      {
+        "type": "UInt64Property",
+        "value": 18446744073709551614
+      }
+    ],
+    [
+      "long_test",
+      {
+        "type": "Int64Property",
+        "value": -9223372036854775807
+      }
+    ],
+    [
+      "f_property",
+      {
+        "type": "FloatProperty",
+        "value": 3.14159
+      }
+    ],
+    [
+      "d_property",
+      {
+        "type": "DoubleProperty",
+        "value": 3.14159265358979
+      }
+    ],
+    [
+      "str_property",
+      {
+        "type": "StrProperty",
+        "value": "Hello world"
+      }
+    ],
+    [
+      "struct_property",
+      {
+        "type": "StructProperty",
+        "type_name": "CustomStruct",
+        "CustomStruct": [
+          [
+            "test_field",
+            {
+              "type": "UInt64Property",
+              "value": 12345
+            }
+          ]
         ]
       }
-    },
-    "date_time_property": {
-      "type": "StructProperty",
-      "type_name": "DateTime",
-      "DateTime": {
-        "ticks": 637864237380020000
+    ],
+    [
+      "date_time_property",
+      {
+        "type": "StructProperty",
+        "type_name": "DateTime",
+        "DateTime": {
+          "ticks": 637864237380020000
+        }
       }
-    },
-    "array_of_structs": {
-      "type": "ArrayProperty",
-      "field_name": "array_of_structs",
-      "type_name": "CustomStruct",
-      "structs": [
-        {
-          "CustomStruct": {
-            "test_field": [
-              {
-                "type": "UInt64Property",
-                "value": 10
-              }
+    ],
+    [
+      "array_of_structs",
+      {
+        "type": "ArrayProperty",
+        "field_name": "array_of_structs",
+        "type_name": "CustomStruct",
+        "structs": [
+          {
+            "CustomStruct": [
+              [
+                "test_field",
+                {
+                  "type": "UInt64Property",
+                  "value": 10
+                }
+              ]
             ]
-          }
-        },
-        {
-          "CustomStruct": {
-            "test_field": [
-              {
-                "type": "UInt64Property",
-                "value": 10
-              }
+          },
+          {
+            "CustomStruct": [
+              [
+                "test_field",
+                {
+                  "type": "UInt64Property",
+                  "value": 10
+                }
+              ]
             ]
           }
-        }
-      ]
-    },
-    "array_of_ints": {
-      "type": "ArrayProperty",
-      "ints": [
-        12,
-        12,
-        12,
-        12,
-        12
-      ]
-    },
-    "array_of_strings": {
-      "type": "ArrayProperty",
-      "strings": [
-        "Hello world from array",
-        "Hello world from array",
-        "Hello world from array"
-      ]
-    }
-  }
+        ]
+      }
+    ],
+    [
+      "array_of_ints",
+      {
+        "type": "ArrayProperty",
+        "ints": [
+          12,
+          12,
+          12,
+          12,
+          12
+        ]
+      }
+    ],
+    [
+      "array_of_strings",
+      {
+        "type": "ArrayProperty",
+        "strings": [
+          "Hello world from array",
+          "Hello world from array",
+          "Hello world from array"
+        ]
+      }
+    ]
+  ]
 }"#;
@@ -66,16 +66,19 @@ pub const TAGCONTAINER_JSON: &str = r#"{
     },
     "save_game_class_name": "/Script/Example.SaveGame"
   },
-  "properties": {
-    "TagTest": {
-      "type": "StructProperty",
-      "type_name": "GameplayTagContainer",
-      "GameplayTagContainer": [
-        "Character.Appearance.OverallColor.Gold",
-        "Character.Appearance.OverallColor.White",
-        "Character.Appearance.FluffColor.Red",
-        "Character.Appearance.FluffColor.Blue"
-      ]
-    }
-  }
+  "properties": [
+    [
+      "TagTest",
+      {
+        "type": "StructProperty",
+        "type_name": "GameplayTagContainer",
+        "GameplayTagContainer": [
+          "Character.Appearance.OverallColor.Gold",
+          "Character.Appearance.OverallColor.White",
+          "Character.Appearance.FluffColor.Red",
+          "Character.Appearance.FluffColor.Blue"
+        ]
+      }
+    ]
+  ]
 }"#;
@@ -4,13 +4,14 @@ use gvas::{
     properties::{
         delegate_property::{Delegate, MulticastInlineDelegateProperty, MulticastScriptDelegate},
         int_property::{BoolProperty, FloatProperty, IntProperty},
+        property_tag::PropertyTagData,
         str_property::StrProperty,
         struct_property::{StructProperty, StructPropertyValue},
         struct_types::Vector2D,
         Property,
     },
     types::{map::HashableIndexMap, Guid},
-    GvasFile, GvasHeader,
+    GvasFile, GvasHeader, GvasTrailer,
 };
 use ordered_float::OrderedFloat;
 use std::str::FromStr;
@@ -333,10 +334,11 @@ pub(crate) fn expected() -> GvasFile {
                 "/Game/_Blueprints/BP_SettingsSave.BP_SettingsSave_C",
             ),
         },
-        properties: HashableIndexMap::from([
+        properties: vec![
             (
                 String::from("SettingsChanged"),
                 Property::from(MulticastInlineDelegateProperty {
+                    tag: PropertyTagData::default(),
                     value: MulticastScriptDelegate {
                         delegates: vec![
                             Delegate::new(
@@ -717,87 +719,97 @@ pub(crate) fn expected() -> GvasFile {
             (
                 String::from("AudioSettings"),
                 Property::StructProperty(StructProperty {
+                    tag: PropertyTagData::default(),
                     guid: Guid::default(),
                     type_name: String::from("GameAudioSettings"),
-                    value: StructPropertyValue::CustomStruct(HashableIndexMap::from([
+                    value: StructPropertyValue::CustomStruct(vec![
                         (
                             String::from("MasterLevel"),
-                            vec![Property::FloatProperty(FloatProperty {
+                            Property::FloatProperty(FloatProperty {
+                                tag: PropertyTagData::default(),
                                 value: OrderedFloat::from(0.20348908),
-                            })],
+                            }),
                         ),
                         (
                             String::from("MusicLevel"),
-                            vec![Property::FloatProperty(FloatProperty {
+                            Property::FloatProperty(FloatProperty {
+                                tag: PropertyTagData::default(),
                                 value: OrderedFloat::from(0.1511635),
-                            })],
+                            }),
                         ),
                         (
                             String::from("SFXLevel"),
-                            vec![Property::FloatProperty(FloatProperty {
+                            Property::FloatProperty(FloatProperty {
+                                tag: PropertyTagData::default(),
                                 value: OrderedFloat::from(0.5436054),
-                            })],
+                            }),
                         ),
-                    ])),
+                    ]),
                 }),
             ),
             (
                 String::from("GameSettings"),
                 Property::StructProperty(StructProperty {
+                    tag: PropertyTagData::default(),
                     guid: Guid::default(),
                     type_name: String::from("GameSettings"),
-                    value: StructPropertyValue::CustomStruct(HashableIndexMap::from([
+                    value: StructPropertyValue::CustomStruct(vec![
                         (
                             String::from("CurrentSaveSlot"),
-                            vec![Property::StrProperty(StrProperty::from("SAVE2"))],
+                            Property::StrProperty(StrProperty::from("SAVE2")),
                         ),
                         (
                             String::from("LoadTutorial"),
-                            vec![Property::BoolProperty(BoolProperty::new(false))],
+                            Property::BoolProperty(BoolProperty::new(false)),
                         ),
                         (
                             String::from("DisplayNewOrders"),
-                            vec![Property::BoolProperty(BoolProperty::new(false))],
+                            Property::BoolProperty(BoolProperty::new(false)),
                         ),
                         (
                             String::from("EscapeExitsTool"),
-                            vec![Property::BoolProperty(BoolProperty::new(false))],
+                            Property::BoolProperty(BoolProperty::new(false)),
                         ),
                         (
                             String::from("UseDarkMode"),
-                            vec![Property::BoolProperty(BoolProperty::new(true))],
+                            Property::BoolProperty(BoolProperty::new(true)),
                         ),
                         (
                             String::from("AnimateDayCycle"),
-                            vec![Property::BoolProperty(BoolProperty::new(false))],
+                            Property::BoolProperty(BoolProperty::new(false)),
                         ),
                         (
                             String::from("EnableTractorCollision"),
-                            vec![Property::BoolProperty(BoolProperty::new(false))],
+                            Property::BoolProperty(BoolProperty::new(false)),
                         ),
                         (
                             String::from("ShowInventory"),
-                            vec![Property::BoolProperty(BoolProperty::new(true))],
+                            Property::BoolProperty(BoolProperty::new(true)),
                         ),
                         (
                             String::from("CameraAngle"),
-                            vec![Property::from(StructProperty {
+                            Property::from(StructProperty {
+                                tag: PropertyTagData::default(),
                                 type_name: String::from("Vector2D"),
                                 guid: Guid::default(),
                                 value: StructPropertyValue::Vector2D(Vector2D {
                                     x: OrderedFloat::from(30.574748247861862),
                                     y: OrderedFloat::from(60.42525175213814),
                                 }),
-                            })],
+                            }),
                         ),
-                    ])),
+                    ]),
                 }),
             ),
             (
                 String::from("HighScore"),
-                Property::IntProperty(IntProperty { value: 2649 }),
+                Property::IntProperty(IntProperty {
+                    tag: PropertyTagData::default(),
+                    value: 2649,
+                }),
             ),
-        ]),
+        ],
+        trailer: GvasTrailer::default(),
     }
 }
 
@@ -892,413 +904,437 @@ pub const VECTOR2D_JSON: &str = r#"{
     },
     "save_game_class_name": "/Game/_Blueprints/BP_SettingsSave.BP_SettingsSave_C"
   },
-  "properties": {
-    "SettingsChanged": {
-      "type": "MulticastInlineDelegateProperty",
-      "value": {
-        "delegates": [
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_WaterGauge_C_2147482315",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_C_2147482312",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_Single_C_2147482309",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_3_C_2147482305",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_5Row_C_2147482301",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_5_C_2147482297",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_C_2147482293",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row_C_2147482286",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row3_C_2147482280",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row5_C_2147482274",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_C_2147482268",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row_C_2147482265",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row3_C_2147482261",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row5_C_2147482257",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_PlasticRow_C_2147482253",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_C_2147482249",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_1x10_C_2147482242",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_3Row_C_2147482235",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_5Row_C_2147482228",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_10x10_C_2147482221",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Modify_C_2147482214",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Row_C_2147482198",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Row3_C_2147482181",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_C_2147482164",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_C_2147482161",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_3_C_2147482157",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_5_C_2147482153",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_C_2147482149",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_AutomatedActionControl_C_2147482145",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_RemovePlaceable_C_2147482142",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_SeedSilo_C_2147482139",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_TractorBarn_C_2147482132",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Sell_C_2147482125",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_FuelStorageTank_C_2147482118",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_ChickenRun_C_2147482115",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_MovePlaceable_C_2147482112",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Beehive_C_2147482109",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_SetPHTool_Row_C_2147482106",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_BiodieselRefinery_C_2147482089",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_OilPress_C_2147482086",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_FlourMill_C_2147482083",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_LargeChickenCoop_C_2147482080",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_CropSign_C_2147482077",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_C_2147482070",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_Row_C_2147482054",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_Row3_C_2147482037",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Warehouse_C_2147482020",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_HarvestSilo_C_2147482013",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Stockpile_C_2147482008",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_CompostStation_C_2147482001",
-            "function_name": "SettingsChanged_Event"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_Renders_C_1",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_PlayerPawn_C_2147482331",
-            "function_name": "UpdatedSavedSettings"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478921",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478905",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478890",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478875",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478860",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478303",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478288",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478273",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478258",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478243",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478228",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478141",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478126",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478111",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478096",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477750",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477735",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477720",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477705",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477690",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477675",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477660",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477645",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477189",
-            "function_name": "SettingsChanged"
-          },
-          {
-            "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477162",
-            "function_name": "SettingsChanged"
-          }
-        ]
+  "properties": [
+    [
+      "SettingsChanged",
+      {
+        "type": "MulticastInlineDelegateProperty",
+        "value": {
+          "delegates": [
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_WaterGauge_C_2147482315",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_C_2147482312",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_Single_C_2147482309",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_3_C_2147482305",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_5Row_C_2147482301",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plow_Row_5_C_2147482297",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_C_2147482293",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row_C_2147482286",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row3_C_2147482280",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Plant_Row5_C_2147482274",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_C_2147482268",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row_C_2147482265",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row3_C_2147482261",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Cultivate_Row5_C_2147482257",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_PlasticRow_C_2147482253",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_C_2147482249",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_1x10_C_2147482242",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_3Row_C_2147482235",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_5Row_C_2147482228",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Purchase_10x10_C_2147482221",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Modify_C_2147482214",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Row_C_2147482198",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Row3_C_2147482181",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_C_2147482164",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_C_2147482161",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_3_C_2147482157",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_5_C_2147482153",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Harvest_Row_C_2147482149",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_AutomatedActionControl_C_2147482145",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_RemovePlaceable_C_2147482142",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_SeedSilo_C_2147482139",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_TractorBarn_C_2147482132",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Sell_C_2147482125",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_FuelStorageTank_C_2147482118",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_ChickenRun_C_2147482115",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_MovePlaceable_C_2147482112",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Beehive_C_2147482109",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_SetPHTool_Row_C_2147482106",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_BiodieselRefinery_C_2147482089",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_OilPress_C_2147482086",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_FlourMill_C_2147482083",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_LargeChickenCoop_C_2147482080",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_CropSign_C_2147482077",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_C_2147482070",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_Row_C_2147482054",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Mulch_Row3_C_2147482037",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Warehouse_C_2147482020",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_HarvestSilo_C_2147482013",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_Stockpile_C_2147482008",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_ActionTool_CompostStation_C_2147482001",
+              "function_name": "SettingsChanged_Event"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_Renders_C_1",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_PlayerPawn_C_2147482331",
+              "function_name": "UpdatedSavedSettings"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478921",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478905",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478890",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478875",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478860",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478303",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478288",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478273",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478258",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478243",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478228",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478141",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478126",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478111",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147478096",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477750",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477735",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477720",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477705",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477690",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477675",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477660",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477645",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477189",
+              "function_name": "SettingsChanged"
+            },
+            {
+              "object": "/Game/DefaultMap.DefaultMap:PersistentLevel.BP_AutomatedTool_C_2147477162",
+              "function_name": "SettingsChanged"
+            }
+          ]
+        }
       }
-    },
-    "AudioSettings": {
-      "type": "StructProperty",
-      "type_name": "GameAudioSettings",
-      "CustomStruct": {
-        "MasterLevel": [
-          {
-            "type": "FloatProperty",
-            "value": 0.20348908
-          }
-        ],
-        "MusicLevel": [
-          {
-            "type": "FloatProperty",
-            "value": 0.1511635
-          }
-        ],
-        "SFXLevel": [
-          {
-            "type": "FloatProperty",
-            "value": 0.5436054
-          }
+    ],
+    [
+      "AudioSettings",
+      {
+        "type": "StructProperty",
+        "type_name": "GameAudioSettings",
+        "CustomStruct": [
+          [
+            "MasterLevel",
+            {
+              "type": "FloatProperty",
+              "value": 0.20348908
+            }
+          ],
+          [
+            "MusicLevel",
+            {
+              "type": "FloatProperty",
+              "value": 0.1511635
+            }
+          ],
+          [
+            "SFXLevel",
+            {
+              "type": "FloatProperty",
+              "value": 0.5436054
+            }
+          ]
         ]
       }
-    },
-    "GameSettings": {
-      "type": "StructProperty",
-      "type_name": "GameSettings",
-      "CustomStruct": {
-        "CurrentSaveSlot": [
-          {
-            "type": "StrProperty",
-            "value": "SAVE2"
-          }
-        ],
-        "LoadTutorial": [
-          {
-            "type": "BoolProperty",
-            "value": false
-          }
-        ],
-        "DisplayNewOrders": [
-          {
-            "type": "BoolProperty",
-            "value": false
-          }
-        ],
-        "EscapeExitsTool": [
-          {
-            "type": "BoolProperty",
-            "value": false
-          }
-        ],
-        "UseDarkMode": [
-          {
-            "type": "BoolProperty",
-            "value": true
-          }
-        ],
-        "AnimateDayCycle": [
-          {
-            "type": "BoolProperty",
-            "value": false
-          }
-        ],
-        "EnableTractorCollision": [
-          {
-            "type": "BoolProperty",
-            "value": false
-          }
-        ],
-        "ShowInventory": [
-          {
-            "type": "BoolProperty",
-            "value": true
-          }
-        ],
-        "CameraAngle": [
-          {
-            "type": "StructProperty",
-            "type_name": "Vector2D",
-            "Vector2D": {
-              "x": 30.574748247861862,
-              "y": 60.42525175213814
+    ],
+    [
+      "GameSettings",
+      {
+        "type": "StructProperty",
+        "type_name": "GameSettings",
+        "CustomStruct": [
+          [
+            "CurrentSaveSlot",
+            {
+              "type": "StrProperty",
+              "value": "SAVE2"
+            }
+          ],
+          [
+            "LoadTutorial",
+            {
+              "type": "BoolProperty",
+              "value": false
+            }
+          ],
+          [
+            "DisplayNewOrders",
+            {
+              "type": "BoolProperty",
+              "value": false
+            }
+          ],
+          [
+            "EscapeExitsTool",
+            {
+              "type": "BoolProperty",
+              "value": false
+            }
+          ],
+          [
+            "UseDarkMode",
+            {
+              "type": "BoolProperty",
+              "value": true
+            }
+          ],
+          [
+            "AnimateDayCycle",
+            {
+              "type": "BoolProperty",
+              "value": false
             }
-          }
+          ],
+          [
+            "EnableTractorCollision",
+            {
+              "type": "BoolProperty",
+              "value": false
+            }
+          ],
+          [
+            "ShowInventory",
+            {
+              "type": "BoolProperty",
+              "value": true
+            }
+          ],
+          [
+            "CameraAngle",
+            {
+              "type": "StructProperty",
+              "type_name": "Vector2D",
+              "Vector2D": {
+                "x": 30.574748247861862,
+                "y": 60.42525175213814
+              }
+            }
+          ]
         ]
       }
-    },
-    "HighScore": {
-      "type": "IntProperty",
-      "value": 2649
-    }
-  }
+    ],
+    [
+      "HighScore",
+      {
+        "type": "IntProperty",
+        "value": 2649
+      }
+    ]
+  ]
 }"#;
@@ -1,10 +1,7 @@
 mod common;
 use common::*;
 mod gvas_tests;
-use gvas::{
-    game_version::{DeserializedGameVersion, GameVersion, PalworldCompressionType},
-    GvasFile,
-};
+use gvas::{game_version::GameVersion, GvasFile};
 use std::{collections::HashMap, fs, io::Cursor, path::Path};
 
 fn test_gvas_file(path: &str) -> GvasFile {
@@ -30,14 +27,7 @@ fn test_gvas_file_(
     file.write(&mut writer).expect("Write GvasFile");
 
     // Compare the two Vec<u8>s
-    if match file.deserialized_game_version {
-        DeserializedGameVersion::Default => true,
-        DeserializedGameVersion::Palworld(PalworldCompressionType::Zlib) => false,
-        DeserializedGameVersion::Palworld(PalworldCompressionType::ZlibTwice) => false,
-        _ => unimplemented!(),
-    } {
-        assert_eq!(cursor.get_ref(), writer.get_ref());
-    }
+    assert_eq!(cursor.get_ref(), writer.get_ref());
 
     // Read the file back in again
     let mut reader = Cursor::new(writer.into_inner());
@@ -0,0 +1,85 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes256,
+};
+use gvas::{
+    container::AesEcb,
+    error::{DeserializeError, Error},
+    game_version::DeserializedGameVersion,
+    GvasFile,
+};
+
+use crate::common::*;
+
+const KEY: [u8; 32] = [
+    0x3b, 0x91, 0x0e, 0x7c, 0xd4, 0x58, 0xa2, 0x16, 0xef, 0x04, 0x6d, 0xb9, 0x27, 0xc3, 0x8a, 0x50,
+    0x19, 0xf6, 0x42, 0x9d, 0x73, 0xe8, 0x35, 0xab, 0x60, 0x0c, 0xd7, 0x8e, 0x21, 0x4f, 0xba, 0x96,
+];
+
+fn read_slot1() -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    fs::read(path).expect("Read test asset")
+}
+
+/// Encrypts `data` after appending `padding`
+fn encrypt(data: &[u8], padding: &[u8]) -> Vec<u8> {
+    let cipher = Aes256::new(&KEY.into());
+    let mut data = [data, padding].concat();
+    assert_eq!(data.len() % 16, 0);
+    for block in data.chunks_exact_mut(16) {
+        cipher.encrypt_block(block.into());
+    }
+    data
+}
+
+fn test_round_trip(padding: &[u8], expected_padding: u8) {
+    let data = encrypt(&read_slot1(), padding);
+    let container = AesEcb::new(KEY);
+
+    let file =
+        GvasFile::read_with_containers(&mut Cursor::new(&data), &[&container], &HashMap::new())
+            .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom(AesEcb::NAME, vec![expected_padding])
+    );
+    assert_eq!(file.header, slot1::expected().header);
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[&container])
+        .expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+}
+
+#[test]
+fn aes_zero_padding() {
+    let length = read_slot1().len();
+    test_round_trip(&vec![0; (16 - length % 16) % 16], AesEcb::ZERO_PADDING);
+}
+
+#[test]
+fn aes_pkcs7_padding() {
+    let padding = 16 - read_slot1().len() % 16;
+    test_round_trip(&vec![padding as u8; padding], AesEcb::PKCS7_PADDING);
+}
+
+#[test]
+fn aes_wrong_key() {
+    let length = read_slot1().len();
+    let data = encrypt(&read_slot1(), &vec![0; (16 - length % 16) % 16]);
+    let container = AesEcb::new([0; 32]);
+
+    // The file isn't detected, and read as plain GVAS
+    let result =
+        GvasFile::read_with_containers(&mut Cursor::new(&data), &[&container], &HashMap::new());
+    assert!(
+        matches!(
+            result,
+            Err(Error::Deserialize(DeserializeError::InvalidHeader(_)))
+        ),
+        "Unexpected result {result:?}"
+    );
+}
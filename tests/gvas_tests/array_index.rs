@@ -0,0 +1,188 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use gvas::{
+    builder::GvasFileBuilder,
+    engine_version::EngineVersion,
+    game_version::GameVersion,
+    object_version::EUnrealEngineObjectUE5Version,
+    properties::{
+        int_property::{BoolProperty, ByteProperty, IntProperty},
+        property_tag::PropertyTagData,
+        str_property::StrProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        struct_types::IntPoint,
+        Property, PropertyTrait,
+    },
+    types::Guid,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::SLOT1_PATH;
+
+fn tag(array_index: u32) -> PropertyTagData {
+    PropertyTagData {
+        array_index,
+        ..Default::default()
+    }
+}
+
+/// Builds the entries of a static array, with the entry at index 2 skipped like Unreal does for
+/// entries that match their default value.
+fn static_array<F: Fn(u32) -> Property>(name: &str, f: F) -> Vec<(String, Property)> {
+    [0, 1, 3]
+        .into_iter()
+        .map(|i| (String::from(name), f(i)))
+        .collect()
+}
+
+fn properties() -> Vec<(String, Property)> {
+    let mut points = StructProperty::new(
+        Guid::default(),
+        String::from("IntPoint"),
+        StructPropertyValue::IntPoint(IntPoint::new(1, 2)),
+    );
+    points.tag = tag(3);
+
+    [
+        static_array("Slots", |i| {
+            Property::from(IntProperty {
+                tag: tag(i),
+                value: i as i32 * 10,
+            })
+        }),
+        static_array("Flags", |i| {
+            Property::from(BoolProperty {
+                tag: tag(i),
+                value: i % 2 == 1,
+            })
+        }),
+        static_array("Bytes", |i| {
+            let mut property = ByteProperty::new_byte(Some(String::from("None")), i as u8);
+            property.tag = tag(i);
+            Property::from(property)
+        }),
+        static_array("Names", |i| {
+            Property::from(StrProperty {
+                tag: tag(i),
+                value: Some(format!("Name {i}")),
+            })
+        }),
+        vec![(String::from("Points"), Property::from(points))],
+        vec![(
+            String::from("Custom"),
+            Property::from(StructProperty::new(
+                Guid::default(),
+                String::from("Custom"),
+                StructPropertyValue::CustomStruct(static_array("Inner", |i| {
+                    Property::from(IntProperty {
+                        tag: tag(i),
+                        value: i as i32,
+                    })
+                })),
+            )),
+        )],
+    ]
+    .concat()
+}
+
+/// Writes a file with static arrays, and checks that it reads back unchanged.
+fn test_array_index(file_version_ue5: Option<u32>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    let data = fs::read(path).expect("Read test asset");
+    let mut file =
+        GvasFile::read(&mut Cursor::new(data), GameVersion::Default).expect("Read GvasFile");
+
+    if let (
+        Some(file_version_ue5),
+        GvasHeader::Version2 {
+            package_file_version,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        },
+    ) = (file_version_ue5, file.header.clone())
+    {
+        file.header = GvasHeader::Version3 {
+            package_file_version,
+            package_file_version_ue5: file_version_ue5,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        };
+    }
+    file.properties = properties();
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let data = writer.into_inner();
+
+    let hints = HashMap::from([(
+        String::from("Custom.StructProperty"),
+        String::from("Custom"),
+    )]);
+    let read_back =
+        GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, &hints)
+            .expect("Read GvasFile");
+    let array_indices = |file: &GvasFile| {
+        file.properties
+            .iter()
+            .map(|(_, property)| property.tag().map_or(0, |tag| tag.array_index))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(array_indices(&file), array_indices(&read_back));
+    if file_version_ue5.is_none() {
+        assert_eq!(file, read_back);
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    read_back.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(data, writer.into_inner());
+}
+
+#[test]
+fn array_index() {
+    test_array_index(None);
+}
+
+#[test]
+fn array_index_complete_type_name() {
+    test_array_index(Some(
+        EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as u32,
+    ));
+}
+
+#[test]
+fn array_index_interleaved() {
+    // The entries of a static array don't have to follow each other
+    let file = GvasFileBuilder::new(EngineVersion::VER_UE4_27, "/Script/Test.TestSaveGame")
+        .property("Slots", IntProperty::new(1))
+        .property("Score", IntProperty::new(42))
+        .property(
+            "Slots",
+            IntProperty {
+                tag: tag(1),
+                value: 2,
+            },
+        )
+        .build()
+        .expect("Build GvasFile");
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let data = writer.into_inner();
+
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    let names: Vec<&str> = read_back
+        .properties
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["Slots", "Score", "Slots"]);
+    assert_eq!(read_back, file);
+
+    let mut writer = Cursor::new(Vec::new());
+    read_back.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+}
@@ -0,0 +1,175 @@
+use std::{fs, io::Cursor, path::Path};
+
+use gvas::{
+    builder::GvasFileBuilder,
+    custom_version::{CustomVersionTrait, FEditorObjectVersion, FUE5ReleaseStreamObjectVersion},
+    engine_version::EngineVersion,
+    error::{Error, SerializeError},
+    game_version::GameVersion,
+    properties::{int_property::IntProperty, str_property::StrProperty},
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+const SAVE_GAME_CLASS_NAME: &str = "/Script/GvasTests.TestSaveGame";
+
+/// Compares the header built for the engine version of a file with the header of the file
+fn assert_header_matches(path: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    let file = GvasFile::read(&mut Cursor::new(data), GameVersion::Default).expect("Read GvasFile");
+    let (engine_version, patch, change_list, branch) = match &file.header {
+        GvasHeader::Version1 { engine_version, .. }
+        | GvasHeader::Version2 { engine_version, .. }
+        | GvasHeader::Version3 { engine_version, .. } => (
+            engine_version.get_version(),
+            engine_version.patch,
+            engine_version.change_list,
+            engine_version.branch.clone(),
+        ),
+    };
+
+    let header = GvasFileBuilder::new(engine_version, SAVE_GAME_CLASS_NAME)
+        .patch(patch)
+        .change_list(change_list)
+        .branch(branch)
+        .build_header()
+        .expect("Build header");
+
+    match (&header, &file.header) {
+        (
+            GvasHeader::Version2 {
+                package_file_version,
+                engine_version,
+                custom_version_format,
+                ..
+            },
+            GvasHeader::Version2 {
+                package_file_version: expected_package_file_version,
+                engine_version: expected_engine_version,
+                custom_version_format: expected_custom_version_format,
+                ..
+            },
+        ) => {
+            assert_eq!(package_file_version, expected_package_file_version);
+            assert_eq!(engine_version, expected_engine_version);
+            assert_eq!(custom_version_format, expected_custom_version_format);
+        }
+        (
+            GvasHeader::Version3 {
+                package_file_version,
+                package_file_version_ue5,
+                engine_version,
+                custom_version_format,
+                ..
+            },
+            GvasHeader::Version3 {
+                package_file_version: expected_package_file_version,
+                package_file_version_ue5: expected_package_file_version_ue5,
+                engine_version: expected_engine_version,
+                custom_version_format: expected_custom_version_format,
+                ..
+            },
+        ) => {
+            assert_eq!(package_file_version, expected_package_file_version);
+            assert_eq!(package_file_version_ue5, expected_package_file_version_ue5);
+            assert_eq!(engine_version, expected_engine_version);
+            assert_eq!(custom_version_format, expected_custom_version_format);
+        }
+        _ => panic!("Header version mismatch for {engine_version:?}"),
+    }
+
+    let custom_versions = header.get_custom_versions();
+    let expected_custom_versions = file.header.get_custom_versions();
+    for guid in [
+        FEditorObjectVersion::GUID,
+        FUE5ReleaseStreamObjectVersion::GUID,
+    ] {
+        assert_eq!(
+            custom_versions.get(&guid),
+            expected_custom_versions.get(&guid),
+            "{engine_version:?} {guid}"
+        );
+    }
+}
+
+#[test]
+fn header_matches_files() {
+    assert_header_matches(OPTIONS_PATH);
+    assert_header_matches(SLOT1_PATH);
+    assert_header_matches(SLOT3_PATH);
+    assert_header_matches(VECTOR2D_PATH);
+}
+
+#[test]
+fn package_file_versions() {
+    for (engine_version, expected_ue4, expected_ue5) in [
+        (EngineVersion::VER_UE4_27, 522, None),
+        (EngineVersion::VER_UE5_0, 522, Some(1004)),
+        (EngineVersion::VER_UE5_1, 522, Some(1008)),
+        (EngineVersion::VER_UE5_3, 522, Some(1009)),
+        (EngineVersion::VER_UE5_4, 522, Some(1012)),
+        (EngineVersion::VER_UE5_5, 522, Some(1013)),
+        (EngineVersion::VER_UE5_6, 522, Some(1017)),
+    ] {
+        let header = GvasFileBuilder::new(engine_version, SAVE_GAME_CLASS_NAME)
+            .build_header()
+            .expect("Build header");
+        match header {
+            GvasHeader::Version1 { .. } => panic!("Built a version 1 header"),
+            GvasHeader::Version2 {
+                package_file_version,
+                ..
+            } => {
+                assert_eq!(package_file_version, expected_ue4, "{engine_version:?}");
+                assert_eq!(None, expected_ue5, "{engine_version:?}");
+            }
+            GvasHeader::Version3 {
+                package_file_version,
+                package_file_version_ue5,
+                ..
+            } => {
+                assert_eq!(package_file_version, expected_ue4, "{engine_version:?}");
+                assert_eq!(
+                    Some(package_file_version_ue5),
+                    expected_ue5,
+                    "{engine_version:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    for engine_version in [
+        EngineVersion::VER_UE4_27,
+        EngineVersion::VER_UE5_0,
+        EngineVersion::VER_UE5_3,
+        EngineVersion::VER_UE5_4,
+    ] {
+        let file = GvasFileBuilder::new(engine_version, SAVE_GAME_CLASS_NAME)
+            .property("Score", IntProperty::new(42))
+            .property("Name", StrProperty::from("Player"))
+            .property("Slots", IntProperty::new(1))
+            .property("Slots", IntProperty::new(2))
+            .build()
+            .expect("Build GvasFile");
+
+        let mut writer = Cursor::new(Vec::new());
+        file.write(&mut writer).expect("Write GvasFile");
+        let mut reader = Cursor::new(writer.into_inner());
+        let read_back = GvasFile::read(&mut reader, GameVersion::Default).expect("Read GvasFile");
+        assert_eq!(read_back, file, "{engine_version:?}");
+    }
+}
+
+#[test]
+fn no_release() {
+    let result = GvasFileBuilder::new(EngineVersion::UNKNOWN, SAVE_GAME_CLASS_NAME).build();
+    assert!(matches!(
+        result,
+        Err(Error::Serialize(SerializeError::InvalidValue(_)))
+    ));
+}
@@ -0,0 +1,146 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use flate2::Crc;
+use gvas::{
+    container::{Checksum, ChecksumAlgorithm, ChecksumPosition, SaveContainer},
+    error::{DeserializeError, Error},
+    game_version::DeserializedGameVersion,
+    properties::{int_property::IntProperty, Property},
+    GvasFile,
+};
+use md5::{Digest, Md5};
+use sha1::Sha1;
+
+use crate::common::*;
+
+fn read_slot1() -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    fs::read(path).expect("Read test asset")
+}
+
+fn crc32(data: &[u8]) -> Vec<u8> {
+    let mut crc = Crc::new();
+    crc.update(data);
+    crc.sum().to_le_bytes().to_vec()
+}
+
+fn read(data: &[u8], container: &Checksum) -> Result<GvasFile, Error> {
+    GvasFile::read_with_containers(&mut Cursor::new(data), &[container], &HashMap::new())
+}
+
+fn write(file: &GvasFile, container: &Checksum) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[container])
+        .expect("Write GvasFile");
+    writer.into_inner()
+}
+
+fn test_round_trip(container: Checksum, data: Vec<u8>) {
+    assert!(container.detect(&data));
+    let file = read(&data, &container).expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom(Checksum::NAME, Vec::new())
+    );
+    assert_eq!(file.properties, slot1::expected().properties);
+    assert_eq!(write(&file, &container), data);
+}
+
+#[test]
+fn checksum_md5_suffix() {
+    let plain = read_slot1();
+    let data = [&plain[..], &Md5::digest(&plain)[..]].concat();
+    test_round_trip(
+        Checksum::new(ChecksumAlgorithm::Md5, ChecksumPosition::Suffix),
+        data,
+    );
+}
+
+#[test]
+fn checksum_detect() {
+    // A checksum after the data isn't verified, so plain GVAS files are claimed
+    let plain = read_slot1();
+    assert!(Checksum::new(ChecksumAlgorithm::Md5, ChecksumPosition::Suffix).detect(&plain));
+    assert!(!Checksum::new(ChecksumAlgorithm::Md5, ChecksumPosition::Prefix).detect(&plain));
+}
+
+#[test]
+fn checksum_sha1_prefix() {
+    let plain = read_slot1();
+    let data = [&Sha1::digest(&plain)[..], &plain[..]].concat();
+    test_round_trip(
+        Checksum::new(ChecksumAlgorithm::Sha1, ChecksumPosition::Prefix),
+        data,
+    );
+}
+
+#[test]
+fn checksum_crc32_range() {
+    let plain = read_slot1();
+    let data = [&plain[..], &crc32(&plain[4..plain.len() - 4])[..]].concat();
+    test_round_trip(
+        Checksum {
+            skip_start: 4,
+            skip_end: 4,
+            ..Checksum::new(ChecksumAlgorithm::Crc32, ChecksumPosition::Suffix)
+        },
+        data,
+    );
+}
+
+#[test]
+fn checksum_mismatch() {
+    let container = Checksum::new(ChecksumAlgorithm::Md5, ChecksumPosition::Suffix);
+    let plain = read_slot1();
+    let data = [&plain[..], &[0; 16]].concat();
+
+    let result = read(&data, &container);
+    assert!(
+        matches!(
+            result,
+            Err(Error::Deserialize(DeserializeError::ChecksumMismatch(_, _, position)))
+                if position == plain.len() as u64
+        ),
+        "Unexpected result {result:?}"
+    );
+
+    let (file, diagnostics) = GvasFile::read_lenient_with_containers(
+        &mut Cursor::new(&data),
+        &[&container],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].path, "");
+    assert_eq!(diagnostics[0].position, plain.len() as u64);
+    match &diagnostics[0].error {
+        Error::Deserialize(DeserializeError::ChecksumMismatch(expected, actual, _)) => {
+            assert_eq!(expected.as_ref(), "00".repeat(16));
+            assert_eq!(actual.len(), 32);
+        }
+        error => panic!("Unexpected error {error}"),
+    }
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    // Writing fixes the checksum
+    let written = write(&file, &container);
+    assert_eq!(written, [&plain[..], &Md5::digest(&plain)[..]].concat());
+}
+
+#[test]
+fn checksum_recomputed() {
+    let container = Checksum::new(ChecksumAlgorithm::Crc32, ChecksumPosition::Prefix);
+    let plain = read_slot1();
+    let data = [&crc32(&plain)[..], &plain[..]].concat();
+
+    let mut file = read(&data, &container).expect("Read GvasFile");
+    file.properties.push((
+        String::from("new_property"),
+        Property::from(IntProperty::new(42)),
+    ));
+
+    let written = write(&file, &container);
+    assert_eq!(written[..4], crc32(&written[4..]));
+    let read_back = read(&written, &container).expect("Read GvasFile");
+    assert_eq!(read_back, file);
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use gvas::{
+    cursor_ext::{ReadExt, WriteExt},
+    game_version::GameVersion,
+    limits::Limits,
+    object_version::{EUnrealEngineObjectUE5Version, FPackageFileVersion},
+    properties::{
+        array_property::ArrayProperty, struct_property::StructPropertyValue, Property,
+        PropertyOptions, PropertyTrait,
+    },
+    types::map::HashableIndexMap,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+const UE5_VERSION: u32 = EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as u32;
+
+/// Converts a header to a UE 5.4 header.
+fn upgrade_header(header: &GvasHeader) -> GvasHeader {
+    match header.clone() {
+        GvasHeader::Version1 { .. } => unreachable!("Test files have version 2 or 3 headers"),
+        GvasHeader::Version2 {
+            package_file_version,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        }
+        | GvasHeader::Version3 {
+            package_file_version,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+            ..
+        } => GvasHeader::Version3 {
+            package_file_version,
+            package_file_version_ue5: UE5_VERSION,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        },
+    }
+}
+
+/// Writes a save as UE 5.4, reads it back, and checks that both layouts round trip.
+fn test_complete_type_name(path: &str, hints: &HashMap<String, String>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    let file = GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, hints)
+        .expect("Read GvasFile");
+
+    // Write the file with UE 5.4 property tags
+    let mut upgraded = file.clone();
+    upgraded.header = upgrade_header(&file.header);
+    let mut writer = Cursor::new(Vec::new());
+    upgraded.write(&mut writer).expect("Write UE 5.4 GvasFile");
+    let upgraded_data = writer.into_inner();
+
+    // Read it back, and write it again
+    let read_back = GvasFile::read_with_hints(
+        &mut Cursor::new(&upgraded_data),
+        GameVersion::Default,
+        hints,
+    )
+    .expect("Read UE 5.4 GvasFile");
+    let mut writer = Cursor::new(Vec::new());
+    read_back.write(&mut writer).expect("Write UE 5.4 GvasFile");
+    assert_eq!(upgraded_data, writer.into_inner());
+
+    // Writing the file with the original header restores the original bytes
+    let mut downgraded = read_back;
+    downgraded.header = file.header;
+    let mut writer = Cursor::new(Vec::new());
+    downgraded.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(data, writer.into_inner());
+}
+
+#[test]
+fn complete_type_name_slot1() {
+    test_complete_type_name(SLOT1_PATH, &HashMap::new());
+}
+
+#[test]
+fn complete_type_name_saveslot3() {
+    test_complete_type_name(SAVESLOT_03_PATH, &saveslot3::hints());
+}
+
+#[test]
+fn complete_type_name_features_01() {
+    test_complete_type_name(FEATURES_01_PATH, &features::hints());
+}
+
+#[test]
+fn complete_type_name_vector2d() {
+    test_complete_type_name(VECTOR2D_PATH, &HashMap::new());
+}
+
+#[test]
+fn complete_type_name_enum_array() {
+    test_complete_type_name(ENUM_ARRAY_PATH, &HashMap::new());
+}
+
+#[test]
+fn complete_type_name_delegate() {
+    test_complete_type_name(DELEGATE_PATH, &HashMap::new());
+}
+
+/// Reads a property with a UE 5.4 tag, and checks that it's written back unchanged.
+fn read_property(data: &[u8], properties_stack: &mut Vec<String>) -> Property {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack,
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::new(522, UE5_VERSION),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(data);
+    let property_type = reader.read_string().expect("Read property type");
+    let property = Property::new(&mut reader, &property_type, true, &mut options, None)
+        .expect("Read property");
+    assert_eq!(reader.position(), data.len() as u64);
+
+    let mut writer = Cursor::new(Vec::new());
+    property
+        .write(&mut writer, true, &mut options)
+        .expect("Write property");
+    assert_eq!(writer.get_ref(), data);
+
+    property
+}
+
+fn write_type_name(writer: &mut Cursor<Vec<u8>>, name: &str, parameter_count: i32) {
+    writer.write_string(name).expect("Write name");
+    writer
+        .write_i32::<LittleEndian>(parameter_count)
+        .expect("Write parameter count");
+}
+
+#[test]
+fn complete_type_name_struct() {
+    let mut writer = Cursor::new(Vec::new());
+    write_type_name(&mut writer, "StructProperty", 1);
+    write_type_name(&mut writer, "Vector", 1);
+    write_type_name(&mut writer, "/Script/CoreUObject", 0);
+    writer.write_u32::<LittleEndian>(12).expect("Write size");
+    writer.write_u8(0).expect("Write flags");
+    for value in [1f32, 2f32, 3f32] {
+        writer
+            .write_f32::<LittleEndian>(value)
+            .expect("Write value");
+    }
+
+    let property = read_property(writer.get_ref(), &mut Vec::new());
+    let property = property.get_struct().expect("StructProperty");
+    assert_eq!(property.type_name, "Vector");
+    assert!(matches!(property.value, StructPropertyValue::VectorF(_)));
+    assert_eq!(
+        property.tag.type_parameters[0].to_string(),
+        "Vector(/Script/CoreUObject)"
+    );
+}
+
+#[test]
+fn complete_type_name_bool() {
+    for (flags, value) in [(0x00, false), (0x10, true)] {
+        let mut writer = Cursor::new(Vec::new());
+        write_type_name(&mut writer, "BoolProperty", 0);
+        writer.write_u32::<LittleEndian>(0).expect("Write size");
+        writer.write_u8(flags).expect("Write flags");
+
+        let property = read_property(writer.get_ref(), &mut Vec::new());
+        assert_eq!(property.get_bool().expect("BoolProperty").value, value);
+    }
+}
+
+#[test]
+fn complete_type_name_struct_array() {
+    let mut writer = Cursor::new(Vec::new());
+    write_type_name(&mut writer, "ArrayProperty", 1);
+    write_type_name(&mut writer, "StructProperty", 1);
+    write_type_name(&mut writer, "Vector", 1);
+    write_type_name(&mut writer, "/Script/CoreUObject", 0);
+    writer.write_u32::<LittleEndian>(16).expect("Write size");
+    writer.write_u8(0).expect("Write flags");
+    writer.write_u32::<LittleEndian>(1).expect("Write count");
+    for value in [1f32, 2f32, 3f32] {
+        writer
+            .write_f32::<LittleEndian>(value)
+            .expect("Write value");
+    }
+
+    let property = read_property(writer.get_ref(), &mut vec![String::from("Positions")]);
+    match property.get_array().expect("ArrayProperty") {
+        ArrayProperty::Structs {
+            field_name,
+            type_name,
+            structs,
+            ..
+        } => {
+            assert_eq!(field_name, "Positions");
+            assert_eq!(type_name, "Vector");
+            assert_eq!(structs.len(), 1);
+        }
+        array => panic!("Unexpected array {array:?}"),
+    }
+}
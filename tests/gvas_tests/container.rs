@@ -0,0 +1,840 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Cursor, Read, Write},
+    path::Path,
+};
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression, Crc};
+use gvas::{
+    container::{
+        Palworld, PalworldCodec, PalworldOodle, ReadSeek, SaveContainer, SerializeCompressed,
+    },
+    error::{DeserializeError, Error, SerializeError},
+    game_version::{DeserializedGameVersion, GameVersion, PalworldChunk, PalworldCompressionType},
+    limits::Limits,
+    properties::{int_property::IntProperty, Property},
+    write_options::WriteOptions,
+    GvasFile, GvasTrailer,
+};
+
+use crate::common::*;
+
+/// GVAS data with every byte inverted, and a one byte key stored as metadata
+struct Inverted;
+
+impl SaveContainer for Inverted {
+    fn name(&self) -> &str {
+        "Inverted"
+    }
+
+    fn detect(&self, prefix: &[u8]) -> bool {
+        prefix.get(1..5) == Some(&[!b'G', !b'V', !b'A', !b'S'])
+    }
+
+    fn unwrap(
+        &self,
+        reader: &mut dyn ReadSeek,
+        _: &Limits,
+    ) -> Result<(Vec<u8>, DeserializedGameVersion), Error> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let key = data.remove(0);
+        let data = data.into_iter().map(|b| !b).collect();
+        Ok((
+            data,
+            DeserializedGameVersion::custom(self.name(), vec![key]),
+        ))
+    }
+
+    fn wrap(
+        &self,
+        payload: &[u8],
+        game_version: &DeserializedGameVersion,
+        writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let DeserializedGameVersion::Custom { metadata, .. } = game_version else {
+            Err(SerializeError::invalid_value("Not an inverted file"))?
+        };
+        writer.write_all(metadata)?;
+        writer.write_all(&payload.iter().map(|b| !b).collect::<Vec<_>>())?;
+        Ok(())
+    }
+}
+
+/// Stand-in for Oodle, reverses the data
+struct Reversed;
+
+impl PalworldCodec for Reversed {
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(data.iter().rev().copied().collect())
+    }
+
+    fn decompress(&self, data: &[u8], _: usize) -> Result<Vec<u8>, Error> {
+        Ok(data.iter().rev().copied().collect())
+    }
+}
+
+fn read_asset(path: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    fs::read(path).expect("Read test asset")
+}
+
+#[test]
+fn custom_container() {
+    let plain = read_asset(SLOT1_PATH);
+    let mut data = vec![0x2a];
+    data.extend(plain.iter().map(|b| !b));
+
+    let file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::DEFAULT, &Inverted],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom("Inverted", vec![0x2a])
+    );
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[&Inverted])
+        .expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+
+    // The container must be registered for writing
+    let result = file.write(&mut Cursor::new(Vec::new()));
+    assert!(
+        matches!(
+            result,
+            Err(Error::Serialize(SerializeError::InvalidValue(_)))
+        ),
+        "Unexpected result {result:?}"
+    );
+}
+
+#[test]
+fn detect_container() {
+    let containers: &[&dyn SaveContainer] = &[&Palworld::DEFAULT, &Inverted];
+
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file = GvasFile::read_with_containers(&mut Cursor::new(&data), containers, &HashMap::new())
+        .expect("Read GvasFile");
+    assert!(matches!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            compression_type: PalworldCompressionType::Zlib,
+            chunk: None,
+            ..
+        }
+    ));
+
+    let data = read_asset(SLOT1_PATH);
+    let file = GvasFile::read_with_containers(&mut Cursor::new(&data), containers, &HashMap::new())
+        .expect("Read GvasFile");
+    assert_eq!(file, slot1::expected());
+}
+
+#[test]
+fn palworld_header() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Palworld).expect("Read GvasFile");
+
+    // Compress the data again instead of writing it unchanged
+    let options = WriteOptions {
+        compression_level: Some(6),
+        ..WriteOptions::default()
+    };
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_options(&mut writer, &options)
+        .expect("Write GvasFile");
+    let written = writer.into_inner();
+    assert_ne!(written, data);
+
+    // Uncompressed length, then the length of the zlib data following the header
+    assert_eq!(written[..4], data[..4]);
+    assert_eq!(
+        u32::from_le_bytes([written[4], written[5], written[6], written[7]]) as usize,
+        written.len() - 12
+    );
+    assert_eq!(written[8..12], data[8..12]);
+}
+
+#[test]
+fn read_auto() {
+    let data = read_asset(SLOT1_PATH);
+    let file =
+        GvasFile::read_auto(&mut Cursor::new(&data), &HashMap::new()).expect("Read GvasFile");
+    assert_eq!(file, slot1::expected());
+
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file =
+        GvasFile::read_auto(&mut Cursor::new(&data), &palworld::hints()).expect("Read GvasFile");
+    assert!(matches!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            compression_type: PalworldCompressionType::Zlib,
+            ..
+        }
+    ));
+
+    let data = read_asset(PALWORLD_ZLIB_TWICE_PATH);
+    let file =
+        GvasFile::read_auto(&mut Cursor::new(&data), &palworld::hints()).expect("Read GvasFile");
+    assert!(matches!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            compression_type: PalworldCompressionType::ZlibTwice,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn read_auto_unknown() {
+    let mut data = read_asset(SLOT1_PATH);
+    data[0] = !data[0];
+    let result = GvasFile::read_auto(&mut Cursor::new(&data), &HashMap::new());
+    match result {
+        Err(Error::Deserialize(DeserializeError::InvalidHeader(message))) => {
+            assert!(message.contains("known container"), "{message}")
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn palworld_oodle() {
+    let plain = read_asset(SLOT1_PATH);
+    let mut data = Vec::new();
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend(b"PlM\x31");
+    data.extend(plain.iter().rev());
+
+    let container = PalworldOodle::new(Reversed);
+    let file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::DEFAULT, &container],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom("PalworldOodle", vec![0x31])
+    );
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[&container])
+        .expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+
+    // Without a codec the file is still recognized
+    let result = GvasFile::read_auto(&mut Cursor::new(&data), &HashMap::new());
+    match result {
+        Err(Error::Deserialize(DeserializeError::InvalidHeader(message))) => {
+            assert!(message.contains("PalworldOodle"), "{message}")
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    }
+}
+
+/// Puts a `CNK` header with the lengths of the `PlZ` or `PlM` header in front of `data`
+fn chunked(data: &[u8], chunk_type: u8) -> Vec<u8> {
+    chunked_with_lengths(data, chunk_type, &data[..8])
+}
+
+/// Puts a `CNK` header with `lengths` in front of `data`
+fn chunked_with_lengths(data: &[u8], chunk_type: u8, lengths: &[u8]) -> Vec<u8> {
+    let mut chunked = lengths.to_vec();
+    chunked.extend(b"CNK");
+    chunked.push(chunk_type);
+    chunked.extend(data);
+    chunked
+}
+
+#[test]
+fn palworld_chunked() {
+    let plain = read_asset(PALWORLD_ZLIB_PATH);
+    let data = chunked(&plain, 0x31);
+
+    let file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::DEFAULT],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert!(matches!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            compression_type: PalworldCompressionType::Zlib,
+            chunk: Some(PalworldChunk {
+                chunk_type: 0x31,
+                decompressed_length: None,
+                compressed_length: None,
+            }),
+            ..
+        }
+    ));
+    let expected =
+        GvasFile::read(&mut Cursor::new(&plain), GameVersion::Palworld).expect("Read GvasFile");
+    assert_eq!(file.properties, expected.properties);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+    assert_eq!(written, data);
+
+    let read_back = GvasFile::read_with_containers(
+        &mut Cursor::new(&written),
+        &[&Palworld::DEFAULT],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(file, read_back);
+}
+
+#[test]
+fn palworld_oodle_chunked() {
+    let plain = read_asset(SLOT1_PATH);
+    let mut data = Vec::new();
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend(b"PlM\x31");
+    data.extend(plain.iter().rev());
+    let data = chunked(&data, 0x32);
+
+    let container = PalworldOodle::new(Reversed);
+    let file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::DEFAULT, &container],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom("PalworldOodle", vec![0x31, 0x32])
+    );
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[&container])
+        .expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+}
+
+#[test]
+fn palworld_chunk_lengths() {
+    let plain = read_asset(PALWORLD_ZLIB_PATH);
+    let mut lengths = 0x1234u32.to_le_bytes().to_vec();
+    lengths.extend(&plain[4..8]);
+    let data = chunked_with_lengths(&plain, 0x31, &lengths);
+
+    let mut file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::DEFAULT],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert!(matches!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            chunk: Some(PalworldChunk {
+                chunk_type: 0x31,
+                decompressed_length: Some(0x1234),
+                compressed_length: None,
+            }),
+            ..
+        }
+    ));
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+
+    // The decompressed length stays as read, the compressed length follows the PlZ header
+    file.properties
+        .push((String::from("Added"), IntProperty::new(1).into()));
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+    assert_eq!(written[..4], 0x1234u32.to_le_bytes());
+    assert_eq!(written[4..8], written[16..20]);
+    assert_ne!(written[4..8], plain[4..8]);
+
+    let read_back =
+        GvasFile::read_auto(&mut Cursor::new(&written), &HashMap::new()).expect("Read GvasFile");
+    assert_eq!(file.properties, read_back.properties);
+    assert!(matches!(
+        read_back.deserialized_game_version,
+        DeserializedGameVersion::Palworld {
+            chunk: Some(PalworldChunk {
+                chunk_type: 0x31,
+                decompressed_length: Some(0x1234),
+                compressed_length: None,
+            }),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn palworld_oodle_chunk_lengths() {
+    let plain = read_asset(SLOT1_PATH);
+    let mut data = Vec::new();
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend((plain.len() as u32).to_le_bytes());
+    data.extend(b"PlM\x31");
+    data.extend(plain.iter().rev());
+    let mut lengths = 0x1234u32.to_le_bytes().to_vec();
+    lengths.extend(0x5678u32.to_le_bytes());
+    let data = chunked_with_lengths(&data, 0x32, &lengths);
+
+    let container = PalworldOodle::new(Reversed);
+    let file =
+        GvasFile::read_with_containers(&mut Cursor::new(&data), &[&container], &HashMap::new())
+            .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::custom(
+            "PalworldOodle",
+            vec![0x31, 0x32, 0x34, 0x12, 0x00, 0x00, 0x78, 0x56, 0x00, 0x00]
+        )
+    );
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_containers(&mut writer, &[&container])
+        .expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+}
+
+/// Writes `data` as a `SerializeCompressed` stream
+fn serialize_compressed(data: &[u8], chunk_size: usize) -> Vec<u8> {
+    serialize_compressed_with_level(data, chunk_size, Compression::default())
+}
+
+fn serialize_compressed_with_level(data: &[u8], chunk_size: usize, level: Compression) -> Vec<u8> {
+    let chunks: Vec<(Vec<u8>, usize)> = data
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let mut encoder = ZlibEncoder::new(Vec::new(), level);
+            encoder.write_all(chunk).expect("Compress chunk");
+            (encoder.finish().expect("Compress chunk"), chunk.len())
+        })
+        .collect();
+    let compressed_size: usize = chunks.iter().map(|(chunk, _)| chunk.len()).sum();
+
+    let mut stream = Vec::new();
+    for size in [0x9E2A83C1, chunk_size, compressed_size, data.len()] {
+        stream.extend((size as i64).to_le_bytes());
+    }
+    for (chunk, uncompressed_size) in &chunks {
+        stream.extend((chunk.len() as i64).to_le_bytes());
+        stream.extend((*uncompressed_size as i64).to_le_bytes());
+    }
+    for (chunk, _) in &chunks {
+        stream.extend(chunk);
+    }
+    stream
+}
+
+#[test]
+fn serialize_compressed_multiple_chunks() {
+    let plain = read_asset(SLOT1_PATH);
+    let data = serialize_compressed(&plain, 256);
+
+    let file = GvasFile::read_with_hints(
+        &mut Cursor::new(&data),
+        GameVersion::SerializeCompressed,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::SerializeCompressed {
+            chunk_size: 256,
+            level: Some(6),
+        }
+    );
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+
+    let file =
+        GvasFile::read_auto(&mut Cursor::new(&data), &HashMap::new()).expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::SerializeCompressed {
+            chunk_size: 256,
+            level: Some(6),
+        }
+    );
+}
+
+#[test]
+fn serialize_compressed_single_chunk() {
+    let mut file = slot1::expected();
+    file.deserialized_game_version = DeserializedGameVersion::SerializeCompressed {
+        chunk_size: SerializeCompressed::DEFAULT_CHUNK_SIZE,
+        level: None,
+    };
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let data = writer.into_inner();
+    assert_eq!(
+        data,
+        serialize_compressed(
+            &read_asset(SLOT1_PATH),
+            SerializeCompressed::DEFAULT_CHUNK_SIZE as usize
+        )
+    );
+
+    let read_back = GvasFile::read_with_hints(
+        &mut Cursor::new(&data),
+        GameVersion::SerializeCompressed,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(file.properties, read_back.properties);
+    assert_eq!(
+        read_back.deserialized_game_version,
+        DeserializedGameVersion::SerializeCompressed {
+            chunk_size: SerializeCompressed::DEFAULT_CHUNK_SIZE,
+            level: Some(6),
+        }
+    );
+}
+
+#[test]
+fn serialize_compressed_level() {
+    let plain = read_asset(SLOT1_PATH);
+    let data = serialize_compressed_with_level(&plain, 256, Compression::best());
+
+    let file = GvasFile::read_with_hints(
+        &mut Cursor::new(&data),
+        GameVersion::SerializeCompressed,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(
+        file.deserialized_game_version,
+        DeserializedGameVersion::SerializeCompressed {
+            chunk_size: 256,
+            level: Some(9),
+        }
+    );
+
+    // Written again with the level it was read with
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), data);
+
+    // Or with the level of the write options
+    let options = WriteOptions {
+        compression_level: Some(1),
+        ..WriteOptions::default()
+    };
+    let mut writer = Cursor::new(Vec::new());
+    file.write_with_options(&mut writer, &options)
+        .expect("Write GvasFile");
+    assert_eq!(
+        writer.into_inner(),
+        serialize_compressed_with_level(&plain, 256, Compression::fast())
+    );
+}
+
+#[test]
+fn serialize_compressed_truncated() {
+    let data = serialize_compressed(&read_asset(SLOT1_PATH), 256);
+    let result = GvasFile::read_with_hints(
+        &mut Cursor::new(&data[..data.len() - 1]),
+        GameVersion::SerializeCompressed,
+        &HashMap::new(),
+    );
+    assert!(
+        matches!(result, Err(Error::Io(_))),
+        "Unexpected result {result:?}"
+    );
+}
+
+/// Reads a Palworld save
+fn read_palworld(data: &[u8]) -> GvasFile {
+    GvasFile::read_with_containers(
+        &mut Cursor::new(data),
+        &[&Palworld::DEFAULT],
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile")
+}
+
+/// Returns the zlib header and decompressed data of a Palworld save with a `PlZ` header
+fn palworld_zlib_data(data: &[u8]) -> ([u8; 2], Vec<u8>) {
+    let mut decompressed = Vec::new();
+    ZlibDecoder::new(&data[12..])
+        .read_to_end(&mut decompressed)
+        .expect("Decompress data");
+    ([data[12], data[13]], decompressed)
+}
+
+#[test]
+fn palworld_compression_level() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Palworld).expect("Read GvasFile");
+    let DeserializedGameVersion::Palworld { zlib, .. } = &file.deserialized_game_version else {
+        panic!(
+            "Unexpected game version {:?}",
+            file.deserialized_game_version
+        )
+    };
+    assert_eq!(zlib.header, Some([0x78, 0x9c]));
+    assert_eq!(zlib.level, Some(6));
+
+    let write_with_level = |level| {
+        let options = WriteOptions {
+            compression_level: Some(level),
+            ..WriteOptions::default()
+        };
+        let mut writer = Cursor::new(Vec::new());
+        file.write_with_options(&mut writer, &options)
+            .map(|()| writer.into_inner())
+    };
+
+    let (header, decompressed) = palworld_zlib_data(&data);
+    let fast = write_with_level(1).expect("Write GvasFile");
+    let best = write_with_level(9).expect("Write GvasFile");
+    assert!(best.len() < fast.len());
+    assert_eq!(
+        palworld_zlib_data(&fast),
+        ([0x78, 0x01], decompressed.clone())
+    );
+    assert_eq!(palworld_zlib_data(&best), ([0x78, 0xda], decompressed));
+    assert_ne!(header, [0x78, 0xda]);
+
+    let result = write_with_level(10);
+    assert!(
+        matches!(
+            result,
+            Err(Error::Serialize(SerializeError::InvalidValue(_)))
+        ),
+        "Unexpected result {result:?}"
+    );
+
+    // The level can be set on the file as well
+    let mut file = file.clone();
+    file.deserialized_game_version.set_compression_level(9);
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    assert_eq!(writer.into_inner(), best);
+}
+
+#[test]
+fn palworld_modified() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let mut file = read_palworld(&data);
+    file.properties.push((
+        String::from("new_property"),
+        Property::from(IntProperty::new(42)),
+    ));
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&written), GameVersion::Palworld).expect("Read GvasFile");
+    assert_eq!(read_back.properties, file.properties);
+}
+
+/// Returns the decompressed and compressed length of a Palworld save with a `PlZ` header
+fn palworld_lengths(data: &[u8]) -> (usize, usize) {
+    let length = |offset: usize| {
+        u32::from_le_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]) as usize
+    };
+    (length(0), length(4))
+}
+
+#[test]
+fn palworld_modified_end() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let mut file = read_palworld(&data);
+    // The data written matches the original up to the last property
+    file.properties.pop().expect("Last property");
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+
+    let (_, decompressed) = palworld_zlib_data(&written);
+    assert_eq!(
+        palworld_lengths(&written),
+        (decompressed.len(), written.len() - 12)
+    );
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&written), GameVersion::Palworld).expect("Read GvasFile");
+    assert_eq!(read_back.properties, file.properties);
+}
+
+#[test]
+fn palworld_extended() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let mut file = read_palworld(&data);
+    // The data written matches all of the original and continues after it
+    file.trailer = GvasTrailer::new(vec![0, 0, 0, 0, 1, 2, 3, 4]);
+
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+
+    let (_, original) = palworld_zlib_data(&data);
+    let (_, decompressed) = palworld_zlib_data(&written);
+    assert_eq!(decompressed[..original.len()], original[..]);
+    assert_eq!(decompressed[original.len()..], [1, 2, 3, 4]);
+    assert_eq!(
+        palworld_lengths(&written),
+        (decompressed.len(), written.len() - 12)
+    );
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&written), GameVersion::Palworld).expect("Read GvasFile");
+    assert_eq!(read_back.trailer.data, file.trailer.data);
+}
+
+#[test]
+fn palworld_compression_types() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Palworld).expect("Read GvasFile");
+    let (_, payload) = palworld_zlib_data(&data);
+
+    for compression_type in [
+        PalworldCompressionType::None,
+        PalworldCompressionType::ZlibTwice,
+    ] {
+        let mut file = file.clone();
+        file.deserialized_game_version = DeserializedGameVersion::palworld(compression_type);
+        let mut writer = Cursor::new(Vec::new());
+        file.write(&mut writer).expect("Write GvasFile");
+        let written = writer.into_inner();
+        assert_eq!(written[11], i8::from(compression_type) as u8);
+
+        let compressed_length = match compression_type {
+            PalworldCompressionType::None => {
+                assert_eq!(written[12..], payload[..]);
+                payload.len()
+            }
+            _ => {
+                let mut once = Vec::new();
+                ZlibDecoder::new(&written[12..])
+                    .read_to_end(&mut once)
+                    .expect("Decompress data");
+                let mut decompressed = Vec::new();
+                ZlibDecoder::new(&once[..])
+                    .read_to_end(&mut decompressed)
+                    .expect("Decompress data");
+                assert_eq!(decompressed, payload);
+                once.len()
+            }
+        };
+        assert_eq!(
+            palworld_lengths(&written),
+            (payload.len(), compressed_length)
+        );
+
+        let read_back = GvasFile::read(&mut Cursor::new(&written), GameVersion::Palworld)
+            .expect("Read GvasFile");
+        assert_eq!(read_back.properties, file.properties);
+
+        // Written again unchanged
+        let mut writer = Cursor::new(Vec::new());
+        read_back.write(&mut writer).expect("Write GvasFile");
+        assert_eq!(writer.into_inner(), written);
+    }
+}
+
+#[test]
+fn palworld_recompressed() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Palworld).expect("Read GvasFile");
+
+    // Without the compressed data the file is compressed again, with the same zlib header
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let written = writer.into_inner();
+    assert_eq!(palworld_zlib_data(&written), palworld_zlib_data(&data));
+
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&written), GameVersion::Palworld).expect("Read GvasFile");
+    assert_eq!(read_back, file);
+}
+
+/// Returns the last four bytes replacing the ones of `data`, so that its CRC-32 becomes `crc`
+fn forge_crc32(data: &[u8], crc: u32) -> [u8; 4] {
+    let table: Vec<u32> = (0..256u32)
+        .map(|mut value| {
+            for _ in 0..8 {
+                value = match value & 1 {
+                    1 => (value >> 1) ^ 0xEDB88320,
+                    _ => value >> 1,
+                };
+            }
+            value
+        })
+        .collect();
+
+    let mut prefix = Crc::new();
+    prefix.update(&data[..data.len() - 4]);
+    // Four zero bytes from the state `state` end in `!crc`, so the bytes are the difference
+    let mut state = !crc;
+    for _ in 0..4 {
+        let index = table
+            .iter()
+            .position(|value| value >> 24 == state >> 24)
+            .expect("Table index") as u32;
+        state = ((state ^ table[index as usize]) << 8) | index;
+    }
+    (state ^ !prefix.sum()).to_le_bytes()
+}
+
+#[test]
+fn palworld_same_crc32() {
+    let data = read_asset(PALWORLD_ZLIB_PATH);
+    let file = read_palworld(&data);
+    let (_, mut payload) = palworld_zlib_data(&data);
+
+    // Different data of the same length and CRC-32 isn't mistaken for the original
+    let mut crc = Crc::new();
+    crc.update(&payload);
+    let middle = payload.len() / 2;
+    payload[middle] ^= 0xff;
+    let length = payload.len();
+    let forged = forge_crc32(&payload, crc.sum());
+    payload[length - 4..].copy_from_slice(&forged);
+    let mut forged_crc = Crc::new();
+    forged_crc.update(&payload);
+    assert_eq!(forged_crc.sum(), crc.sum());
+
+    let mut written = Vec::new();
+    Palworld::DEFAULT
+        .wrap(&payload, &file.deserialized_game_version, &mut written)
+        .expect("Wrap payload");
+    assert_eq!(palworld_zlib_data(&written).1, payload);
+
+    // The original data is still written unchanged
+    let (_, payload) = palworld_zlib_data(&data);
+    let mut written = Vec::new();
+    Palworld::DEFAULT
+        .wrap(&payload, &file.deserialized_game_version, &mut written)
+        .expect("Wrap payload");
+    assert_eq!(written, data);
+}
@@ -0,0 +1,106 @@
+use std::{fs, io::Cursor, path::Path};
+
+use gvas::{
+    custom_version::{CustomVersionTrait, FEditorObjectVersion, FUE5ReleaseStreamObjectVersion},
+    engine_version::EngineVersion,
+    game_version::GameVersion,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+#[test]
+fn editor_object_version() {
+    for (engine_version, expected) in [
+        (EngineVersion::UNKNOWN, None),
+        (EngineVersion::VER_UE4_11, Some(0)),
+        (EngineVersion::VER_UE4_18, Some(20)),
+        (EngineVersion::VER_UE4_23, Some(34)),
+        (EngineVersion::VER_UE4_25, Some(38)),
+        (EngineVersion::VER_UE4_27, Some(40)),
+        (EngineVersion::VER_UE5_0, Some(40)),
+        (EngineVersion::VER_UE5_3, Some(40)),
+        (EngineVersion::VER_UE5_4, Some(40)),
+        (EngineVersion::VER_UE5_5, Some(40)),
+        (EngineVersion::VER_UE5_6, Some(40)),
+    ] {
+        assert_eq!(
+            FEditorObjectVersion::version_for(engine_version),
+            expected,
+            "{engine_version:?}"
+        );
+    }
+}
+
+#[test]
+fn ue5_release_stream_object_version() {
+    for (engine_version, expected) in [
+        (EngineVersion::UNKNOWN, None),
+        (EngineVersion::VER_UE4_27, Some(0)),
+        (EngineVersion::VER_UE5_0, Some(36)),
+        (EngineVersion::VER_UE5_1, Some(41)),
+        (EngineVersion::VER_UE5_2, Some(44)),
+        (EngineVersion::VER_UE5_3, Some(47)),
+        (EngineVersion::VER_UE5_4, Some(51)),
+        (EngineVersion::VER_UE5_5, Some(51)),
+        (EngineVersion::VER_UE5_6, Some(51)),
+    ] {
+        assert_eq!(
+            FUE5ReleaseStreamObjectVersion::version_for(engine_version),
+            expected,
+            "{engine_version:?}"
+        );
+    }
+}
+
+#[test]
+fn engine_version_order() {
+    assert!(EngineVersion::VER_UE4_27 < EngineVersion::VER_UE5_0);
+    assert!(EngineVersion::VER_UE5_2 < EngineVersion::VER_UE5_3);
+    assert!(EngineVersion::VER_UE5_6 < EngineVersion::VER_UE4_AUTOMATIC_VERSION);
+}
+
+/// Compares the custom versions stored in a file with the versions resolved for its engine
+fn assert_custom_versions(path: &str, game_version: GameVersion) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    let file = GvasFile::read(&mut Cursor::new(data), game_version).expect("Read GvasFile");
+    let engine_version = match &file.header {
+        GvasHeader::Version1 { engine_version, .. }
+        | GvasHeader::Version2 { engine_version, .. }
+        | GvasHeader::Version3 { engine_version, .. } => engine_version.get_version(),
+    };
+    let custom_versions = file.header.get_custom_versions();
+
+    let editor_object_version = custom_versions
+        .get(&FEditorObjectVersion::GUID)
+        .map(|version| *version as i32);
+    assert_eq!(
+        editor_object_version,
+        FEditorObjectVersion::version_for(engine_version),
+        "{engine_version:?}"
+    );
+
+    let release_stream_object_version = custom_versions
+        .get(&FUE5ReleaseStreamObjectVersion::GUID)
+        .map(|version| *version as i32);
+    match engine_version >= EngineVersion::VER_UE5_0 {
+        true => assert_eq!(
+            release_stream_object_version,
+            FUE5ReleaseStreamObjectVersion::version_for(engine_version),
+            "{engine_version:?}"
+        ),
+        false => assert_eq!(release_stream_object_version, None),
+    }
+}
+
+#[test]
+fn custom_versions_of_files() {
+    assert_custom_versions(PACKAGE_VERSION_525_PATH, GameVersion::Default);
+    assert_custom_versions(DELEGATE_PATH, GameVersion::Default);
+    assert_custom_versions(OPTIONS_PATH, GameVersion::Default);
+    assert_custom_versions(SLOT1_PATH, GameVersion::Default);
+    assert_custom_versions(TRANSFORM_PATH, GameVersion::Default);
+    assert_custom_versions(PALWORLD_ZLIB_PATH, GameVersion::Palworld);
+    assert_custom_versions(VECTOR2D_PATH, GameVersion::Default);
+}
@@ -0,0 +1,148 @@
+use std::{fs, io::Cursor, path::Path};
+
+use gvas::{
+    builder::GvasFileBuilder,
+    engine_version::{EngineVersion, FEngineVersion},
+    game_version::GameVersion,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+fn read_header(path: &str) -> GvasHeader {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    GvasFile::read(&mut Cursor::new(data), GameVersion::Default)
+        .expect("Read GvasFile")
+        .header
+}
+
+fn set_engine_version(header: &mut GvasHeader, version: FEngineVersion) {
+    match header {
+        GvasHeader::Version1 { engine_version, .. }
+        | GvasHeader::Version2 { engine_version, .. }
+        | GvasHeader::Version3 { engine_version, .. } => *engine_version = version,
+    }
+}
+
+#[test]
+fn estimate_files() {
+    for (path, expected) in [
+        (OPTIONS_PATH, EngineVersion::VER_UE4_25),
+        (DELEGATE_PATH, EngineVersion::VER_UE4_23),
+        (SLOT1_PATH, EngineVersion::VER_UE4_27),
+        (SLOT3_PATH, EngineVersion::VER_UE5_0),
+        (VECTOR2D_PATH, EngineVersion::VER_UE5_3),
+    ] {
+        let header = read_header(path);
+        let estimate = header.estimate_engine_version().expect("Estimate");
+        assert_eq!(estimate.oldest, expected, "{path}");
+        assert_eq!(estimate.newest, expected, "{path}");
+        assert_eq!(estimate.confidence, 1.0, "{path}");
+        assert_eq!(header.resolve_engine_version(), expected, "{path}");
+    }
+}
+
+#[test]
+fn custom_engine_build() {
+    // Saved by a 4.27 engine built from a studio branch, with a package file version of no release
+    let header = read_header(PACKAGE_VERSION_524_PATH);
+    let estimate = header.estimate_engine_version().expect("Estimate");
+    assert_eq!(estimate.oldest, EngineVersion::VER_UE4_26);
+    assert_eq!(estimate.newest, EngineVersion::VER_UE4_27);
+    assert_eq!(estimate.confidence, 0.75);
+    assert!(estimate.contains(EngineVersion::VER_UE4_27));
+    assert!(!estimate.contains(EngineVersion::VER_UE5_0));
+    assert_eq!(header.resolve_engine_version(), EngineVersion::VER_UE4_27);
+}
+
+#[test]
+fn blank_engine_version() {
+    let mut header = read_header(VECTOR2D_PATH);
+    set_engine_version(&mut header, FEngineVersion::new(0, 0, 0, 0, String::new()));
+    assert_eq!(header.resolve_engine_version(), EngineVersion::VER_UE5_3);
+}
+
+#[test]
+fn bogus_engine_version() {
+    let mut header = read_header(SLOT3_PATH);
+    set_engine_version(
+        &mut header,
+        FEngineVersion::new(4, 27, 2, 0, String::from("++UE4+Release-4.27")),
+    );
+    assert_eq!(header.resolve_engine_version(), EngineVersion::VER_UE5_0);
+}
+
+#[test]
+fn version1_header() {
+    let header = GvasHeader::Version1 {
+        package_file_version: 434,
+        engine_version: FEngineVersion::new(0, 0, 0, 0, String::new()),
+        save_game_class_name: String::from("/Script/Test.TestSaveGame"),
+    };
+    let estimate = header.estimate_engine_version().expect("Estimate");
+    assert_eq!(estimate.oldest, EngineVersion::VER_UE4_7);
+    assert_eq!(estimate.newest, EngineVersion::VER_UE4_7);
+    assert_eq!(estimate.confidence, 1.0);
+    assert_eq!(header.resolve_engine_version(), EngineVersion::VER_UE4_7);
+}
+
+#[test]
+fn nothing_to_compare() {
+    let mut header = GvasFileBuilder::new(EngineVersion::VER_UE4_27, "/Script/Test.TestSaveGame")
+        .build_header()
+        .expect("Build header");
+    if let GvasHeader::Version2 {
+        package_file_version,
+        custom_versions,
+        ..
+    } = &mut header
+    {
+        *package_file_version = 0;
+        custom_versions.clear();
+    }
+    assert_eq!(header.estimate_engine_version(), None);
+    assert_eq!(header.resolve_engine_version(), EngineVersion::VER_UE4_27);
+}
+
+#[test]
+fn ue5_releases() {
+    // 5.4 to 5.6 only differ by their UE5 package file version
+    for engine_version in [
+        EngineVersion::VER_UE5_4,
+        EngineVersion::VER_UE5_5,
+        EngineVersion::VER_UE5_6,
+    ] {
+        let header = GvasFileBuilder::new(engine_version, "/Script/Test.TestSaveGame")
+            .build_header()
+            .expect("Build header");
+        let mut writer = Cursor::new(Vec::new());
+        header.write(&mut writer).expect("Write header");
+        writer.set_position(0);
+        let header = GvasHeader::read(&mut writer).expect("Read header");
+
+        let estimate = header.estimate_engine_version().expect("Estimate");
+        assert_eq!(estimate.oldest, engine_version);
+        assert_eq!(estimate.newest, engine_version);
+        assert_eq!(estimate.confidence, 1.0);
+    }
+}
+
+#[test]
+fn newer_package_file_version() {
+    // A UE5 package file version no release saves matches 5.4 to 5.6 equally
+    let mut header = GvasFileBuilder::new(EngineVersion::VER_UE5_5, "/Script/Test.TestSaveGame")
+        .build_header()
+        .expect("Build header");
+    if let GvasHeader::Version3 {
+        package_file_version_ue5,
+        ..
+    } = &mut header
+    {
+        *package_file_version_ue5 = 1014;
+    }
+    let estimate = header.estimate_engine_version().expect("Estimate");
+    assert_eq!(estimate.oldest, EngineVersion::VER_UE5_4);
+    assert_eq!(estimate.newest, EngineVersion::VER_UE5_6);
+    assert_eq!(estimate.confidence, 0.75);
+}
@@ -1,9 +1,12 @@
 use gvas::{
     error::{DeserializeError, Error},
     game_version::GameVersion,
+    limits::Limits,
+    object_version::FPackageFileVersion,
     properties::{
         array_property::ArrayProperty, enum_property::EnumProperty, map_property::MapProperty,
-        set_property::SetProperty, str_property::StrProperty, PropertyOptions,
+        set_property::SetProperty, str_property::StrProperty, text_property::FText, Property,
+        PropertyOptions,
     },
     types::map::HashableIndexMap,
     GvasFile,
@@ -38,86 +41,17 @@ fn test_invalid_header() {
     }
 }
 
-const INVALID_ARRAY_INDEX: [u8; 8] = [
-    0, 0, 0, 0, // length
-    1, 0, 0, 0, // array_index
-];
-
-#[test]
-fn test_invalid_array_index() {
-    // StrProperty
-    let mut reader = Cursor::new(INVALID_ARRAY_INDEX);
-    let result = StrProperty::read_header(&mut reader);
-    match result {
-        Err(Error::Deserialize(DeserializeError::InvalidArrayIndex(value, position))) => {
-            assert_eq!(value, 1);
-            assert_eq!(position, 4);
-        }
-        _ => panic!("Unexpected result {result:?}"),
-    };
-
-    // EnumProperty
-    let mut reader = Cursor::new(INVALID_ARRAY_INDEX);
-    let result = EnumProperty::read_header(&mut reader);
-    match result {
-        Err(Error::Deserialize(DeserializeError::InvalidArrayIndex(value, position))) => {
-            assert_eq!(value, 1);
-            assert_eq!(position, 4);
-        }
-        _ => panic!("Unexpected result {result:?}"),
-    };
-
-    let mut options = PropertyOptions {
-        hints: &HashMap::new(),
-        properties_stack: &mut Vec::new(),
-        custom_versions: &HashableIndexMap::new(),
-    };
-
-    // ArrayProperty
-    let mut reader = Cursor::new(INVALID_ARRAY_INDEX);
-    let result = ArrayProperty::read_header(&mut reader, &mut options);
-    match result {
-        Err(Error::Deserialize(DeserializeError::InvalidArrayIndex(value, position))) => {
-            assert_eq!(value, 1);
-            assert_eq!(position, 4);
-        }
-        _ => panic!("Unexpected result {result:?}"),
-    };
-
-    // SetProperty
-    let mut reader = Cursor::new(INVALID_ARRAY_INDEX);
-    let result = SetProperty::read_header(&mut reader, &mut options);
-    match result {
-        Err(Error::Deserialize(DeserializeError::InvalidArrayIndex(value, position))) => {
-            assert_eq!(value, 1);
-            assert_eq!(position, 4);
-        }
-        _ => panic!("Unexpected result {result:?}"),
-    };
-
-    // MapProperty
-    let mut reader = Cursor::new(INVALID_ARRAY_INDEX);
-    let result = MapProperty::read_header(&mut reader, &mut options);
-    match result {
-        Err(Error::Deserialize(DeserializeError::InvalidArrayIndex(value, position))) => {
-            assert_eq!(value, 1);
-            assert_eq!(position, 4);
-        }
-        _ => panic!("Unexpected result {result:?}"),
-    };
-}
-
 const INVALID_TERMINATOR: [u8; 9] = [
     0, 0, 0, 0, // length
     0, 0, 0, 0, // array_index
-    1, // terminator
+    2, // terminator
 ];
 
 const INVALID_TERMINATOR_ENUM: [u8; 14] = [
     0, 0, 0, 0, // length
     0, 0, 0, 0, // array_index
     1, 0, 0, 0, 0, // enum_type
-    1, // terminator
+    2, // terminator
 ];
 
 const INVALID_TERMINATOR_MAP: [u8; 19] = [
@@ -125,17 +59,26 @@ const INVALID_TERMINATOR_MAP: [u8; 19] = [
     0, 0, 0, 0, // array_index
     1, 0, 0, 0, 0, // key_type
     1, 0, 0, 0, 0, // value_type
-    1, // terminator
+    2, // terminator
 ];
 
 #[test]
 fn test_invalid_terminator() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
     // StrProperty
     let mut reader = Cursor::new(INVALID_TERMINATOR);
-    let result = StrProperty::read_header(&mut reader);
+    let result = StrProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidTerminator(value, position))) => {
-            assert_eq!(value, 1);
+            assert_eq!(value, 2);
             assert_eq!(position, 8);
         }
         _ => panic!("Unexpected result {result:?}"),
@@ -143,27 +86,21 @@ fn test_invalid_terminator() {
 
     // EnumProperty
     let mut reader = Cursor::new(INVALID_TERMINATOR_ENUM);
-    let result = EnumProperty::read_header(&mut reader);
+    let result = EnumProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidTerminator(value, position))) => {
-            assert_eq!(value, 1);
+            assert_eq!(value, 2);
             assert_eq!(position, 13);
         }
         _ => panic!("Unexpected result {result:?}"),
     };
 
-    let mut options = PropertyOptions {
-        hints: &HashMap::new(),
-        properties_stack: &mut Vec::new(),
-        custom_versions: &HashableIndexMap::new(),
-    };
-
     // ArrayProperty
     let mut reader = Cursor::new(INVALID_TERMINATOR_ENUM);
     let result = ArrayProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidTerminator(value, position))) => {
-            assert_eq!(value, 1);
+            assert_eq!(value, 2);
             assert_eq!(position, 13);
         }
         _ => panic!("Unexpected result {result:?}"),
@@ -174,7 +111,7 @@ fn test_invalid_terminator() {
     let result = SetProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidTerminator(value, position))) => {
-            assert_eq!(value, 1);
+            assert_eq!(value, 2);
             assert_eq!(position, 13);
         }
         _ => panic!("Unexpected result {result:?}"),
@@ -185,7 +122,7 @@ fn test_invalid_terminator() {
     let result = MapProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidTerminator(value, position))) => {
-            assert_eq!(value, 1);
+            assert_eq!(value, 2);
             assert_eq!(position, 18);
         }
         _ => panic!("Unexpected result {result:?}"),
@@ -220,7 +157,7 @@ const INVALID_LENGTH_SET: [u8; 22] = [
     0, 0, 0, 0, // array_index
     1, 0, 0, 0, 0, // property_type
     0, // terminator
-    0, 0, 0, 0, // allocation_flags
+    0, 0, 0, 0, // keys_to_remove count
     0, 0, 0, 0, // element_count
 ];
 
@@ -230,15 +167,24 @@ const INVALID_LENGTH_MAP: [u8; 27] = [
     1, 0, 0, 0, 0, // key_type
     1, 0, 0, 0, 0, // value_type
     0, // terminator
-    0, 0, 0, 0, // allocation_flags
+    0, 0, 0, 0, // keys_to_remove count
     0, 0, 0, 0, // element_count
 ];
 
 #[test]
 fn test_invalid_length() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
     // StrProperty
     let mut reader = Cursor::new(INVALID_LENGTH_STR);
-    let result = StrProperty::read_header(&mut reader);
+    let result = StrProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidValueSize(expected, read, position))) => {
             assert_eq!(expected, 0);
@@ -250,7 +196,7 @@ fn test_invalid_length() {
 
     // EnumProperty
     let mut reader = Cursor::new(INVALID_LENGTH_ENUM);
-    let result = EnumProperty::read_header(&mut reader);
+    let result = EnumProperty::read_header(&mut reader, &mut options);
     match result {
         Err(Error::Deserialize(DeserializeError::InvalidValueSize(expected, read, position))) => {
             assert_eq!(expected, 0);
@@ -260,12 +206,6 @@ fn test_invalid_length() {
         _ => panic!("Unexpected result {result:?}"),
     }
 
-    let mut options = PropertyOptions {
-        hints: &HashMap::new(),
-        properties_stack: &mut Vec::new(),
-        custom_versions: &HashableIndexMap::new(),
-    };
-
     // ArrayProperty
     let mut reader = Cursor::new(INVALID_LENGTH_ARRAY);
     let result = ArrayProperty::read_header(&mut reader, &mut options);
@@ -302,3 +242,122 @@ fn test_invalid_length() {
         _ => panic!("Unexpected result {result:?}"),
     };
 }
+
+const INVALID_TEXT_HISTORY: [u8; 5] = [
+    0, 0, 0, 0,  // flags
+    14, // history_type
+];
+
+#[test]
+fn test_invalid_text_history() {
+    let options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(INVALID_TEXT_HISTORY);
+    let result = FText::read(&mut reader, &options);
+    match result {
+        Err(Error::Deserialize(DeserializeError::InvalidEnumValue(name, value, position))) => {
+            assert_eq!(
+                name.into_string(),
+                "gvas::properties::text_property::TextHistoryType"
+            );
+            assert_eq!(value, 14);
+            assert_eq!(position, 5);
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    };
+}
+
+const UNEXPECTED_PROPERTY_TYPE: [u8; 54] = [
+    64, 0, 0, 0, // length
+    0, 0, 0, 0, // array_index
+    15, 0, 0, 0, b'S', b't', b'r', b'u', b'c', b't', b'P', b'r', b'o', b'p', b'e', b'r', b't',
+    b'y', 0, // property_type
+    0, // terminator
+    1, 0, 0, 0, // property_count
+    2, 0, 0, 0, b'A', 0, // field_name
+    12, 0, 0, 0, b'I', b'n', b't', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0, // inner property_type
+];
+
+#[test]
+fn test_unexpected_property_type() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(UNEXPECTED_PROPERTY_TYPE);
+    let result = ArrayProperty::read_header(&mut reader, &mut options);
+    match result {
+        Err(Error::Deserialize(DeserializeError::UnexpectedPropertyType(
+            expected,
+            found,
+            position,
+        ))) => {
+            assert_eq!(expected.into_string(), "StructProperty");
+            assert_eq!(found.into_string(), "IntProperty");
+            assert_eq!(position, 54);
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    };
+}
+
+const NESTED_INVALID_STRING: [u8; 68] = [
+    0, 0, 0, 0, // length
+    0, 0, 0, 0, // array_index
+    2, 0, 0, 0, b'B', 0, // type_name
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // guid
+    0, // terminator
+    2, 0, 0, 0, b'C', 0, // property_name
+    12, 0, 0, 0, b'S', b't', b'r', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0, // property_type
+    6, 0, 0, 0, // length
+    0, 0, 0, 0, // array_index
+    0, // terminator
+    2, 0, 0, 0, b'x', 1, // value
+];
+
+#[test]
+fn test_error_property_path() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut vec![String::from("A")],
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(NESTED_INVALID_STRING);
+    let result = Property::new(&mut reader, "StructProperty", true, &mut options, None);
+    let Err(err) = result else {
+        panic!("Unexpected result {result:?}")
+    };
+    assert_eq!(
+        err.to_string(),
+        "at A.StructProperty.C.StrProperty (StrProperty): Invalid string terminator 1 at position 0x44"
+    );
+    match err {
+        Error::Deserialize(DeserializeError::InProperty(path, property_type, err)) => {
+            assert_eq!(path.into_string(), "A.StructProperty.C.StrProperty");
+            assert_eq!(property_type.into_string(), "StrProperty");
+            assert!(matches!(
+                *err,
+                Error::Deserialize(DeserializeError::InvalidStringTerminator(1, 0x44))
+            ));
+        }
+        _ => panic!("Unexpected error {err}"),
+    };
+    assert_eq!(options.properties_stack, &["A"]);
+}
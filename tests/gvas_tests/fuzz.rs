@@ -0,0 +1,142 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Cursor,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+};
+
+use gvas::{game_version::GameVersion, GvasFile};
+
+use crate::common::*;
+
+const ITERATIONS: usize = 1000;
+
+/// A small xorshift generator, so failures are reproducible without extra dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Corrupts a few bytes, and sometimes truncates the input.
+fn mutate(rng: &mut Rng, data: &[u8]) -> Vec<u8> {
+    let mut data = data.to_vec();
+    for _ in 0..=rng.below(8) {
+        let index = rng.below(data.len());
+        data[index] = match rng.below(4) {
+            0 => 0x00,
+            1 => 0xff,
+            2 => data[index].wrapping_add(1),
+            _ => rng.next() as u8,
+        };
+    }
+    if rng.below(8) == 0 {
+        data.truncate(rng.below(data.len()));
+    }
+    data
+}
+
+/// Reads the input strictly and leniently, and writes it back if reading succeeded, none of
+/// which may panic.
+fn read_and_write(data: Vec<u8>, game_version: GameVersion, hints: &HashMap<String, String>) {
+    if let Ok(file) = GvasFile::read_with_hints(&mut Cursor::new(&data), game_version, hints) {
+        let _ = file.write(&mut Cursor::new(Vec::new()));
+    }
+    if let Ok((file, _)) = GvasFile::read_lenient(&mut Cursor::new(&data), game_version, hints) {
+        let _ = file.write(&mut Cursor::new(Vec::new()));
+    }
+}
+
+fn fuzz(path: &str, game_version: GameVersion, hints: &HashMap<String, String>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15 ^ data.len() as u64);
+    for iteration in 0..ITERATIONS {
+        let input = mutate(&mut rng, &data);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            read_and_write(input.clone(), game_version, hints)
+        }));
+        assert!(
+            result.is_ok(),
+            "Panicked on iteration {iteration} with input {input:02x?}"
+        );
+    }
+}
+
+#[test]
+fn fuzz_random_bytes() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..ITERATIONS {
+        let mut input = b"GVAS".to_vec();
+        input.extend((0..rng.below(512)).map(|_| rng.next() as u8));
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            read_and_write(input.clone(), GameVersion::Default, &HashMap::new())
+        }));
+        assert!(result.is_ok(), "Panicked on input {input:02x?}");
+    }
+}
+
+#[test]
+fn fuzz_delegate() {
+    fuzz(DELEGATE_PATH, GameVersion::Default, &HashMap::new());
+}
+
+#[test]
+fn fuzz_options() {
+    fuzz(OPTIONS_PATH, GameVersion::Default, &HashMap::new());
+}
+
+#[test]
+fn fuzz_package_version_525() {
+    fuzz(
+        PACKAGE_VERSION_525_PATH,
+        GameVersion::Default,
+        &HashMap::new(),
+    );
+}
+
+#[test]
+fn fuzz_palworld_zlib() {
+    fuzz(PALWORLD_ZLIB_PATH, GameVersion::Palworld, &HashMap::new());
+}
+
+#[test]
+fn fuzz_profile_0() {
+    fuzz(PROFILE_0_PATH, GameVersion::Default, &profile0::hints());
+}
+
+#[test]
+fn fuzz_regression_01() {
+    fuzz(REGRESSION_01_PATH, GameVersion::Default, &HashMap::new());
+}
+
+#[test]
+fn fuzz_saveslot_03() {
+    fuzz(SAVESLOT_03_PATH, GameVersion::Default, &saveslot3::hints());
+}
+
+#[test]
+fn fuzz_slot1() {
+    fuzz(SLOT1_PATH, GameVersion::Default, &HashMap::new());
+}
+
+#[test]
+fn fuzz_tagcontainer() {
+    fuzz(TAGCONTAINER_PATH, GameVersion::Default, &HashMap::new());
+}
+
+#[test]
+fn fuzz_vector2d() {
+    fuzz(VECTOR2D_PATH, GameVersion::Default, &HashMap::new());
+}
@@ -0,0 +1,62 @@
+use std::{collections::HashMap, io::Cursor};
+
+use gvas::{
+    cursor_ext::ReadExt,
+    limits::Limits,
+    object_version::FPackageFileVersion,
+    properties::{
+        map_property::MapProperty, property_tag::PropertyTagData, Property, PropertyOptions,
+        PropertyTrait,
+    },
+    types::map::HashableIndexMap,
+};
+
+const MAP_NAME_INT: [u8; 88] = [
+    0x0c, 0x00, 0x00, 0x00, b'M', b'a', b'p', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0x00, // type
+    0x1e, 0x00, 0x00, 0x00, // length
+    0x00, 0x00, 0x00, 0x00, // array_index
+    0x0d, 0x00, 0x00, 0x00, b'N', b'a', b'm', b'e', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0x00, // key_type
+    0x0c, 0x00, 0x00, 0x00, b'I', b'n', b't', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0x00, // value_type
+    0x00, // terminator
+    0x02, 0x00, 0x00, 0x00, // keys_to_remove count
+    0x02, 0x00, 0x00, 0x00, b'A', 0x00, // key
+    0x02, 0x00, 0x00, 0x00, b'B', 0x00, // key
+    0x01, 0x00, 0x00, 0x00, // element_count
+    0x02, 0x00, 0x00, 0x00, b'C', 0x00, // key
+    0x2a, 0x00, 0x00, 0x00, // value
+];
+
+#[test]
+fn map_name_int_keys_to_remove() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(MAP_NAME_INT.to_vec());
+    let property_type = reader.read_string().expect("Read property type");
+    let property = Property::new(&mut reader, &property_type, true, &mut options, None)
+        .expect("Read MapProperty");
+    assert_eq!(reader.position(), MAP_NAME_INT.len() as u64);
+    assert_eq!(
+        property,
+        Property::from(MapProperty::NameInt {
+            tag: PropertyTagData::default(),
+            keys_to_remove: vec![String::from("A"), String::from("B")],
+            name_ints: HashableIndexMap::from([(String::from("C"), 42)]),
+        })
+    );
+
+    let mut writer = Cursor::new(Vec::new());
+    property
+        .write(&mut writer, true, &mut options)
+        .expect("Write MapProperty");
+    assert_eq!(writer.into_inner(), MAP_NAME_INT);
+}
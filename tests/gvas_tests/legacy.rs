@@ -0,0 +1,243 @@
+use std::{collections::HashMap, io::Cursor};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use gvas::{
+    cursor_ext::WriteExt,
+    engine_version::{EngineVersion, FEngineVersion},
+    error::{DeserializeError, Error},
+    game_version::{DeserializedGameVersion, GameVersion},
+    properties::{
+        array_property::ArrayProperty,
+        int_property::IntProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        struct_types::VectorF,
+        Property,
+    },
+    types::Guid,
+    GvasFile, GvasHeader, GvasTrailer, FILE_TYPE_GVAS,
+};
+
+const SAVE_GAME_CLASS_NAME: &str = "/Script/Legacy.LegacySaveGame";
+
+fn engine_version() -> FEngineVersion {
+    FEngineVersion::new(4, 7, 4, 2_468_591, String::from("++depot+UE4-Releases+4.7"))
+}
+
+fn hints() -> HashMap<String, String> {
+    HashMap::from([
+        (
+            String::from("Ids.ArrayProperty"),
+            String::from("IntProperty"),
+        ),
+        (
+            String::from("Points.ArrayProperty"),
+            String::from("StructProperty"),
+        ),
+        (
+            String::from("Points.ArrayProperty.StructProperty"),
+            String::from("Vector"),
+        ),
+    ])
+}
+
+fn legacy_file(package_file_version: u32) -> GvasFile {
+    let points = ArrayProperty::new(
+        String::from("StructProperty"),
+        Some((
+            String::from("Points"),
+            String::from("Vector"),
+            Guid::default(),
+        )),
+        vec![
+            Property::StructPropertyValue(VectorF::new(1.0, 2.0, 3.0).into()),
+            Property::StructPropertyValue(VectorF::new(-4.5, 0.0, 8.25).into()),
+        ],
+    )
+    .expect("Create ArrayProperty");
+    let ids = ArrayProperty::new(
+        String::from("IntProperty"),
+        None,
+        vec![IntProperty::new(7).into(), IntProperty::new(11).into()],
+    )
+    .expect("Create ArrayProperty");
+
+    GvasFile {
+        deserialized_game_version: DeserializedGameVersion::Default,
+        header: GvasHeader::Version1 {
+            package_file_version,
+            engine_version: engine_version(),
+            save_game_class_name: String::from(SAVE_GAME_CLASS_NAME),
+        },
+        properties: vec![
+            (String::from("Score"), Property::from(IntProperty::new(42))),
+            (
+                String::from("Location"),
+                Property::from(StructProperty::new(
+                    Guid::default(),
+                    String::from("Vector"),
+                    StructPropertyValue::VectorF(VectorF::new(0.5, 0.25, 0.125)),
+                )),
+            ),
+            (String::from("Points"), points.into()),
+            (String::from("Ids"), ids.into()),
+        ],
+        trailer: GvasTrailer::default(),
+    }
+}
+
+fn write_bytes(file: &GvasFile) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    writer.into_inner()
+}
+
+/// Writes the bytes a UE 4.7 (package version 434) build saves for `legacy_file`
+fn expected_bytes() -> Vec<u8> {
+    let mut data = Cursor::new(Vec::new());
+    data.write_u32::<LittleEndian>(FILE_TYPE_GVAS).unwrap();
+    data.write_u32::<LittleEndian>(1).unwrap();
+    data.write_u32::<LittleEndian>(434).unwrap();
+    data.write_u16::<LittleEndian>(4).unwrap();
+    data.write_u16::<LittleEndian>(7).unwrap();
+    data.write_u16::<LittleEndian>(4).unwrap();
+    data.write_u32::<LittleEndian>(2_468_591).unwrap();
+    data.write_string("++depot+UE4-Releases+4.7").unwrap();
+    data.write_string(SAVE_GAME_CLASS_NAME).unwrap();
+
+    // No property GUID flag
+    data.write_string("Score").unwrap();
+    data.write_string("IntProperty").unwrap();
+    data.write_u32::<LittleEndian>(4).unwrap();
+    data.write_u32::<LittleEndian>(0).unwrap();
+    data.write_i32::<LittleEndian>(42).unwrap();
+
+    // No struct GUID
+    data.write_string("Location").unwrap();
+    data.write_string("StructProperty").unwrap();
+    data.write_u32::<LittleEndian>(12).unwrap();
+    data.write_u32::<LittleEndian>(0).unwrap();
+    data.write_string("Vector").unwrap();
+    for value in [0.5f32, 0.25, 0.125] {
+        data.write_f32::<LittleEndian>(value).unwrap();
+    }
+
+    // No inner type and no inner struct tag
+    data.write_string("Points").unwrap();
+    data.write_string("ArrayProperty").unwrap();
+    data.write_u32::<LittleEndian>(28).unwrap();
+    data.write_u32::<LittleEndian>(0).unwrap();
+    data.write_u32::<LittleEndian>(2).unwrap();
+    for value in [1.0f32, 2.0, 3.0, -4.5, 0.0, 8.25] {
+        data.write_f32::<LittleEndian>(value).unwrap();
+    }
+
+    data.write_string("Ids").unwrap();
+    data.write_string("ArrayProperty").unwrap();
+    data.write_u32::<LittleEndian>(12).unwrap();
+    data.write_u32::<LittleEndian>(0).unwrap();
+    data.write_u32::<LittleEndian>(2).unwrap();
+    data.write_i32::<LittleEndian>(7).unwrap();
+    data.write_i32::<LittleEndian>(11).unwrap();
+
+    data.write_string("None").unwrap();
+    data.write_u32::<LittleEndian>(0).unwrap();
+    data.into_inner()
+}
+
+#[test]
+fn read_version1() {
+    let data = expected_bytes();
+    let file = GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, &hints())
+        .expect("Read GvasFile");
+    assert_eq!(file, legacy_file(434));
+    assert!(file.header.get_custom_versions().is_empty());
+    assert_eq!(write_bytes(&file), data);
+}
+
+#[test]
+fn read_header_version1() {
+    let header = GvasHeader::read(&mut Cursor::new(expected_bytes())).expect("Read GvasHeader");
+    assert_eq!(
+        header,
+        GvasHeader::Version1 {
+            package_file_version: 434,
+            engine_version: engine_version(),
+            save_game_class_name: String::from(SAVE_GAME_CLASS_NAME),
+        }
+    );
+}
+
+#[test]
+fn missing_array_hint() {
+    let data = expected_bytes();
+    let error = GvasFile::read(&mut Cursor::new(&data), GameVersion::Default)
+        .expect_err("Read without hints");
+    let Error::Deserialize(DeserializeError::InProperty(_, _, error)) = error else {
+        panic!("Unexpected error {error:?}");
+    };
+    assert!(
+        matches!(
+            error.as_ref(),
+            Error::Deserialize(DeserializeError::MissingHint(property_type, path, _))
+                if property_type.as_ref() == "ArrayProperty"
+                    && path.as_ref() == "Points.ArrayProperty"
+        ),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn round_trip_package_versions() {
+    // 441 adds struct GUIDs, 500 array inner types and 503 property GUIDs
+    for package_file_version in [214, 434, 441, 498, 500, 503, 517] {
+        let file = legacy_file(package_file_version);
+        let data = write_bytes(&file);
+        let read_back =
+            GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, &hints())
+                .expect("Read GvasFile");
+        assert_eq!(read_back, file, "{package_file_version}");
+    }
+}
+
+#[test]
+fn property_guid_requires_version() {
+    let mut file = legacy_file(498);
+    let mut property = IntProperty::new(1);
+    property.tag.property_guid = Some(Guid([1; 16]));
+    file.properties
+        .push((String::from("Guarded"), property.into()));
+
+    let mut writer = Cursor::new(Vec::new());
+    assert!(file.write(&mut writer).is_err());
+
+    let mut file = file;
+    if let GvasHeader::Version1 {
+        package_file_version,
+        ..
+    } = &mut file.header
+    {
+        *package_file_version = 503;
+    }
+    let data = write_bytes(&file);
+    let read_back =
+        GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, &hints())
+            .expect("Read GvasFile");
+    assert_eq!(read_back, file);
+}
+
+#[test]
+fn retarget_version1() {
+    let mut file = legacy_file(434);
+    file.retarget(EngineVersion::VER_UE4_27).expect("Retarget");
+    assert!(matches!(
+        file.header,
+        GvasHeader::Version2 {
+            package_file_version: 522,
+            ..
+        }
+    ));
+    let data = write_bytes(&file);
+    let read_back =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    assert_eq!(read_back, file);
+}
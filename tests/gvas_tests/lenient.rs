@@ -0,0 +1,185 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use gvas::{
+    builder::GvasFileBuilder,
+    engine_version::EngineVersion,
+    error::{DeserializeError, Error},
+    game_version::GameVersion,
+    properties::{
+        array_property::ArrayProperty,
+        int_property::IntProperty,
+        str_property::StrProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
+    GvasFile,
+};
+
+use crate::common::*;
+
+fn read_slot1() -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    fs::read(path).expect("Read test asset")
+}
+
+/// Replaces the terminator of the first occurrence of `string` after `offset`.
+fn corrupt_terminator(data: &mut [u8], string: &[u8], offset: usize) -> usize {
+    let index = data[offset..]
+        .windows(string.len() + 1)
+        .position(|w| w[..string.len()] == *string && w[string.len()] == 0)
+        .expect("Find string")
+        + offset
+        + string.len();
+    data[index] = 1;
+    index
+}
+
+fn write(file: &GvasFile) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    writer.into_inner()
+}
+
+#[test]
+fn lenient_valid_file() {
+    let data = read_slot1();
+    let (file, diagnostics) = GvasFile::read_lenient(
+        &mut Cursor::new(&data),
+        GameVersion::Default,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert!(diagnostics.is_empty());
+    assert_eq!(file, slot1::expected());
+    assert_eq!(write(&file), data);
+}
+
+#[test]
+fn lenient_invalid_value() {
+    let mut data = read_slot1();
+    let position = corrupt_terminator(&mut data, b"Hello world", 0);
+
+    let result = GvasFile::read(&mut Cursor::new(&data), GameVersion::Default);
+    assert!(result.is_err(), "Unexpected result {result:?}");
+
+    let (file, diagnostics) = GvasFile::read_lenient(
+        &mut Cursor::new(&data),
+        GameVersion::Default,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].path, "str_property.StrProperty");
+    assert_eq!(diagnostics[0].property_type, "StrProperty");
+    match &diagnostics[0].error {
+        Error::Deserialize(DeserializeError::InProperty(_, _, error)) => assert!(
+            matches!(
+                **error,
+                Error::Deserialize(DeserializeError::InvalidStringTerminator(1, p))
+                    if p == position as u64 + 1
+            ),
+            "Unexpected error {error}"
+        ),
+        error => panic!("Unexpected error {error}"),
+    }
+
+    assert!(matches!(
+        find_property(&file.properties, "str_property"),
+        Property::UnknownProperty(_)
+    ));
+    // The properties after the recovered one are still read
+    let expected = slot1::expected();
+    assert_eq!(
+        find_property(&file.properties, "struct_property"),
+        find_property(&expected.properties, "struct_property")
+    );
+    assert_eq!(file.properties.len(), expected.properties.len());
+
+    assert_eq!(write(&file), data);
+}
+
+#[test]
+fn lenient_invalid_struct() {
+    let mut data = read_slot1();
+    let offset = data
+        .windows(b"CustomStruct".len())
+        .position(|w| w == b"CustomStruct")
+        .expect("Find struct");
+    corrupt_terminator(&mut data, b"test_field", offset);
+
+    let (file, diagnostics) = GvasFile::read_lenient(
+        &mut Cursor::new(&data),
+        GameVersion::Default,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].path, "struct_property.StructProperty");
+    assert!(matches!(
+        find_property(&file.properties, "struct_property"),
+        Property::UnknownProperty(_)
+    ));
+
+    // The struct header parameters are kept as well
+    assert_eq!(write(&file), data);
+}
+
+#[test]
+fn lenient_legacy_tags() {
+    // UE 4.7 tags have neither a struct GUID nor an array inner type
+    let custom_struct = vec![(
+        String::from("test_field"),
+        StrProperty::from("Hello world").into(),
+    )];
+    let file = GvasFileBuilder::new(EngineVersion::VER_UE4_7, "/Script/Test.TestSaveGame")
+        .property(
+            "struct_property",
+            StructProperty::new(
+                Guid::default(),
+                String::from("CustomStruct"),
+                StructPropertyValue::CustomStruct(custom_struct),
+            ),
+        )
+        .property(
+            "array_property",
+            ArrayProperty::new(
+                String::from("IntProperty"),
+                None,
+                vec![IntProperty::new(1).into(), IntProperty::new(2).into()],
+            )
+            .expect("Create ArrayProperty"),
+        )
+        .build()
+        .expect("Build GvasFile");
+    let mut data = write(&file);
+    corrupt_terminator(&mut data, b"test_field", 0);
+
+    // Without a hint, the array can't be read either
+    let (file, diagnostics) = GvasFile::read_lenient(
+        &mut Cursor::new(&data),
+        GameVersion::Default,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    let paths: Vec<&str> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "struct_property.StructProperty",
+            "array_property.ArrayProperty"
+        ]
+    );
+    assert!(matches!(
+        find_property(&file.properties, "struct_property"),
+        Property::UnknownProperty(_)
+    ));
+    assert!(matches!(
+        find_property(&file.properties, "array_property"),
+        Property::UnknownProperty(_)
+    ));
+    assert_eq!(write(&file), data);
+}
@@ -0,0 +1,247 @@
+use std::{collections::HashMap, fs::File, io::Cursor, path::Path};
+
+use gvas::{
+    cursor_ext::ReadExt,
+    error::{DeserializeError, Error},
+    game_version::GameVersion,
+    limits::Limits,
+    object_version::{EUnrealEngineObjectUE5Version, FPackageFileVersion},
+    properties::{
+        set_property::SetProperty, str_property::StrProperty, text_property::FText, Property,
+        PropertyOptions,
+    },
+    types::map::HashableIndexMap,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+const SET_HUGE_COUNT: [u8; 33] = [
+    0x08, 0x00, 0x00, 0x00, // length
+    0x00, 0x00, 0x00, 0x00, // array_index
+    0x0c, 0x00, 0x00, 0x00, b'I', b'n', b't', b'P', b'r', b'o', b'p', b'e', b'r', b't', b'y',
+    0x00, // property_type
+    0x00, // terminator
+    0x00, 0x00, 0x00, 0x00, // keys_to_remove count
+    0xff, 0xff, 0xff, 0xff, // element_count
+];
+
+const STR_TOO_LONG: [u8; 16] = [
+    0x0c, 0x00, 0x00, 0x00, b'h', b'e', b'l', b'l', b'o', b' ', b'w', b'o', b'r', b'l', b'd', 0x00,
+];
+
+fn assert_limit_exceeded<T: std::fmt::Debug>(
+    result: Result<T, Error>,
+    expected_name: &str,
+    expected_limit: u64,
+) {
+    // Errors inside properties carry the property path
+    let error = match result {
+        Err(Error::Deserialize(DeserializeError::InProperty(_, _, error))) => *error,
+        Err(error) => error,
+        Ok(value) => panic!("Unexpected result {value:?}"),
+    };
+    match error {
+        Error::Deserialize(DeserializeError::LimitExceeded(name, value, limit, _)) => {
+            assert_eq!(name.as_ref(), expected_name);
+            assert_eq!(limit, expected_limit);
+            assert!(value > limit);
+        }
+        _ => panic!("Unexpected error {error}"),
+    }
+}
+
+#[test]
+fn element_count_limit() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(SET_HUGE_COUNT);
+    let result = SetProperty::read_header(&mut reader, &mut options);
+    match result {
+        Err(Error::Deserialize(DeserializeError::LimitExceeded(name, value, limit, position))) => {
+            assert_eq!(name.as_ref(), "element count");
+            assert_eq!(value, u32::MAX as u64);
+            assert_eq!(limit, Limits::DEFAULT.max_element_count as u64);
+            assert_eq!(position, 33);
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn string_length_limit() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits {
+            max_string_length: 8,
+            ..Limits::default()
+        },
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(STR_TOO_LONG);
+    let result = StrProperty::read(&mut reader, false, &mut options);
+    match result {
+        Err(Error::Deserialize(DeserializeError::LimitExceeded(name, value, limit, position))) => {
+            assert_eq!(name.as_ref(), "string length");
+            assert_eq!(value, 12);
+            assert_eq!(limit, 8);
+            assert_eq!(position, 4);
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    }
+
+    // The same string is accepted with the default limits
+    options.limits = Limits::default();
+    let mut reader = Cursor::new(STR_TOO_LONG);
+    let property = StrProperty::read(&mut reader, false, &mut options).expect("Read StrProperty");
+    assert_eq!(property.value.as_deref(), Some("hello world"));
+}
+
+#[test]
+fn text_depth_limit() {
+    let options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    // Far more nested Transform histories than the stack could handle without a limit
+    let mut data = Vec::new();
+    for _ in 0..1_000_000 {
+        data.extend([0x00, 0x00, 0x00, 0x00, 0x0a]);
+    }
+
+    let mut reader = Cursor::new(data);
+    let result = FText::read(&mut reader, &options);
+    match result {
+        Err(Error::Deserialize(DeserializeError::LimitExceeded(name, value, limit, position))) => {
+            assert_eq!(name.as_ref(), "nesting depth");
+            assert_eq!(value, 65);
+            assert_eq!(limit, 64);
+            assert_eq!(position, 65 * 5);
+        }
+        _ => panic!("Unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn property_depth_limit() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    let mut file = File::open(path).expect("Failed to open test asset");
+    let limits = Limits {
+        max_depth: 0,
+        ..Limits::default()
+    };
+    let result =
+        GvasFile::read_with_limits(&mut file, GameVersion::Default, &HashMap::new(), limits);
+    assert_limit_exceeded(result, "nesting depth", 0);
+}
+
+#[test]
+fn decompressed_size_limit() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(PALWORLD_ZLIB_PATH);
+    let mut file = File::open(path).expect("Failed to open test asset");
+    let limits = Limits {
+        max_decompressed_size: 1024,
+        ..Limits::default()
+    };
+    let result =
+        GvasFile::read_with_limits(&mut file, GameVersion::Palworld, &HashMap::new(), limits);
+    assert_limit_exceeded(result, "decompressed size", 1024);
+}
+
+#[test]
+fn struct_depth_limit() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    // Far more nested structs than the stack could handle without a limit
+    let mut data = Vec::new();
+    for _ in 0..1_000_000 {
+        data.extend([0x02, 0x00, 0x00, 0x00, b'A', 0x00]); // property_name
+        data.extend([0x0f, 0x00, 0x00, 0x00]);
+        data.extend(b"StructProperty\0"); // property_type
+        data.extend([0x00; 8]); // length, array_index
+        data.extend([0x02, 0x00, 0x00, 0x00, b'B', 0x00]); // type_name
+        data.extend([0x00; 17]); // guid, terminator
+    }
+
+    let mut reader = Cursor::new(data);
+    let property_name = reader.read_string().expect("Read property name");
+    let property_type = reader.read_string().expect("Read property type");
+    options.properties_stack.push(property_name);
+    let result = Property::new(&mut reader, &property_type, true, &mut options, None);
+    assert_limit_exceeded(result, "nesting depth", Limits::DEFAULT.max_depth as u64);
+}
+
+#[test]
+fn type_name_limits() {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::new(
+            522,
+            EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as u32,
+        ),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    // Far more nested type parameters than dropping the type name could handle without a limit
+    let mut data = vec![0x01, 0x00, 0x00, 0x00];
+    for _ in 0..1_000_000 {
+        data.extend([0x02, 0x00, 0x00, 0x00, b'A', 0x00]); // name
+        data.extend([0x01, 0x00, 0x00, 0x00]); // parameter count
+    }
+    let mut reader = Cursor::new(data);
+    let result = StrProperty::read(&mut reader, true, &mut options);
+    assert_limit_exceeded(result, "nesting depth", Limits::DEFAULT.max_depth as u64);
+
+    // Type parameter names are limited like other strings
+    let mut data = vec![0x01, 0x00, 0x00, 0x00];
+    data.extend(STR_TOO_LONG);
+    data.extend([0x00, 0x00, 0x00, 0x00]);
+    options.limits.max_string_length = 8;
+    let mut reader = Cursor::new(data);
+    let result = StrProperty::read(&mut reader, true, &mut options);
+    assert_limit_exceeded(result, "string length", 8);
+}
+
+#[test]
+fn header_string_length_limit() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    let limits = Limits {
+        max_string_length: 8,
+        ..Limits::default()
+    };
+
+    let mut file = File::open(&path).expect("Failed to open test asset");
+    let result = GvasHeader::read_with_limits(&mut file, limits);
+    assert_limit_exceeded(result, "string length", 8);
+
+    let mut file = File::open(&path).expect("Failed to open test asset");
+    let result =
+        GvasFile::read_with_limits(&mut file, GameVersion::Default, &HashMap::new(), limits);
+    assert_limit_exceeded(result, "string length", 8);
+}
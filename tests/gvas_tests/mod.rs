@@ -1,9 +1,28 @@
+#[cfg(feature = "aes")]
+mod aes;
+mod array_index;
+mod builder;
+#[cfg(feature = "checksum")]
+mod checksum;
+mod complete_type_name;
+mod container;
+mod custom_version;
+mod engine_version_estimate;
 mod errors;
+mod fuzz;
+mod keys_to_remove;
+mod legacy;
+mod lenient;
+mod limits;
 mod name_arrayindex;
 mod package_version_524;
 mod package_version_525;
+mod property_guid;
 mod regression_01;
+mod retarget;
+mod tag_extensions;
 mod test_cursor;
 mod test_file;
 mod test_guid;
 mod test_property;
+mod trailer;
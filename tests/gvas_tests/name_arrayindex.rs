@@ -1,5 +1,9 @@
 use gvas::cursor_ext::ReadExt;
-use gvas::properties::{name_property::NameProperty, PropertyOptions, PropertyTrait};
+use gvas::limits::Limits;
+use gvas::object_version::FPackageFileVersion;
+use gvas::properties::{
+    name_property::NameProperty, property_tag::PropertyTagData, PropertyOptions, PropertyTrait,
+};
 use gvas::types::map::HashableIndexMap;
 use std::collections::HashMap;
 use std::io::Cursor;
@@ -15,25 +19,32 @@ fn name_property_with_array_index() {
 
     // Convert the Vec<u8> to a NameProperty
     let mut cursor = Cursor::new(data);
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::default(),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
     let property_type = cursor.read_fstring().expect("Failed to read property type");
     assert_eq!(Some(String::from("NameProperty")), property_type);
-    let prop = NameProperty::read(&mut cursor, true).expect("Failed to read NameProperty");
+    let prop =
+        NameProperty::read(&mut cursor, true, &mut options).expect("Failed to read NameProperty");
 
     // Compare the parsed value to its expected value
     assert_eq!(
         NameProperty {
-            array_index: 1,
+            tag: PropertyTagData {
+                array_index: 1,
+                ..Default::default()
+            },
             value: Some("QU91_InvestigateTower_B2".into()),
         },
         prop
     );
 
     // Convert the NameProperty back to a Vec<u8>
-    let mut options = PropertyOptions {
-        hints: &HashMap::new(),
-        properties_stack: &mut Vec::new(),
-        custom_versions: &HashableIndexMap::new(),
-    };
     let mut writer = Cursor::new(Vec::new());
     prop.write(&mut writer, true, &mut options)
         .expect("Failed to serialize gvas file");
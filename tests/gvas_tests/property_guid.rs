@@ -0,0 +1,140 @@
+use std::{collections::HashMap, io::Cursor};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use gvas::{
+    cursor_ext::{ReadExt, WriteExt},
+    limits::Limits,
+    object_version::{EUnrealEngineObjectUE5Version, FPackageFileVersion},
+    properties::{Property, PropertyOptions, PropertyTrait},
+    types::{map::HashableIndexMap, Guid},
+};
+
+const PROPERTY_GUID: Guid = Guid([
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+]);
+
+/// Reads a property, checks its property GUID, and checks that it's written back unchanged.
+fn test_property_guid(data: &[u8], file_version_ue5: u32) {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::new(522, file_version_ue5),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(data);
+    let property_type = reader.read_string().expect("Read property type");
+    let property = Property::new(&mut reader, &property_type, true, &mut options, None)
+        .expect("Read property");
+    assert_eq!(reader.position(), data.len() as u64);
+
+    let tag = property.tag().expect("Property tag");
+    assert_eq!(tag.property_guid, Some(PROPERTY_GUID));
+
+    let mut writer = Cursor::new(Vec::new());
+    property
+        .write(&mut writer, true, &mut options)
+        .expect("Write property");
+    assert_eq!(writer.get_ref(), data);
+}
+
+#[test]
+fn property_guid_str() {
+    let mut writer = Cursor::new(Vec::new());
+    writer.write_string("StrProperty").expect("Write test data");
+    writer
+        .write_u32::<LittleEndian>(8)
+        .expect("Write test data"); // length
+    writer
+        .write_u32::<LittleEndian>(0)
+        .expect("Write test data"); // array_index
+    writer.write_u8(1).expect("Write test data"); // has_property_guid
+    writer.write_guid(&PROPERTY_GUID).expect("Write test data");
+    writer.write_string("Str").expect("Write test data");
+    test_property_guid(writer.get_ref(), 0);
+}
+
+#[test]
+fn property_guid_bool() {
+    let mut writer = Cursor::new(Vec::new());
+    writer
+        .write_string("BoolProperty")
+        .expect("Write test data");
+    writer
+        .write_u64::<LittleEndian>(0)
+        .expect("Write test data"); // length and array_index
+    writer.write_u8(1).expect("Write test data"); // value
+    writer.write_u8(1).expect("Write test data"); // has_property_guid
+    writer.write_guid(&PROPERTY_GUID).expect("Write test data");
+    test_property_guid(writer.get_ref(), 0);
+}
+
+#[test]
+fn property_guid_struct() {
+    let mut writer = Cursor::new(Vec::new());
+    writer
+        .write_string("StructProperty")
+        .expect("Write test data");
+    writer
+        .write_u32::<LittleEndian>(8)
+        .expect("Write test data"); // length
+    writer
+        .write_u32::<LittleEndian>(0)
+        .expect("Write test data"); // array_index
+    writer.write_string("IntPoint").expect("Write test data");
+    writer
+        .write_guid(&Guid::default())
+        .expect("Write test data");
+    writer.write_u8(1).expect("Write test data"); // has_property_guid
+    writer.write_guid(&PROPERTY_GUID).expect("Write test data");
+    writer
+        .write_i32::<LittleEndian>(1)
+        .expect("Write test data");
+    writer
+        .write_i32::<LittleEndian>(2)
+        .expect("Write test data");
+    test_property_guid(writer.get_ref(), 0);
+}
+
+#[test]
+fn property_guid_unknown() {
+    let mut writer = Cursor::new(Vec::new());
+    writer
+        .write_string("UnknownProperty")
+        .expect("Write test data");
+    writer
+        .write_u32::<LittleEndian>(2)
+        .expect("Write test data"); // length
+    writer
+        .write_u32::<LittleEndian>(0)
+        .expect("Write test data"); // array_index
+    writer.write_u8(1).expect("Write test data"); // has_property_guid
+    writer.write_guid(&PROPERTY_GUID).expect("Write test data");
+    writer
+        .write_u16::<LittleEndian>(0xffff)
+        .expect("Write test data");
+    test_property_guid(writer.get_ref(), 0);
+}
+
+#[test]
+fn property_guid_complete_type_name() {
+    let mut writer = Cursor::new(Vec::new());
+    writer.write_string("IntProperty").expect("Write test data");
+    writer
+        .write_i32::<LittleEndian>(0)
+        .expect("Write test data"); // type parameter count
+    writer
+        .write_u32::<LittleEndian>(4)
+        .expect("Write test data"); // length
+    writer.write_u8(0x02).expect("Write test data"); // flags
+    writer.write_guid(&PROPERTY_GUID).expect("Write test data");
+    writer
+        .write_i32::<LittleEndian>(42)
+        .expect("Write test data");
+    test_property_guid(
+        writer.get_ref(),
+        EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as u32,
+    );
+}
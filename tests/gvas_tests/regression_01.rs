@@ -39,7 +39,9 @@ fn regression_01_guid() {
 
     let original_guid = file
         .properties
-        .get("Thing")
+        .iter()
+        .find(|(name, _)| name == "Thing")
+        .map(|(_, property)| property)
         .expect("Failed to get test property in original asset")
         .get_struct()
         .expect("Failed to cast property from original asset to the correct type")
@@ -49,7 +51,9 @@ fn regression_01_guid() {
 
     let written_guid = read_back
         .properties
-        .get("Thing")
+        .iter()
+        .find(|(name, _)| name == "Thing")
+        .map(|(_, property)| property)
         .expect("Failed to get test property in written asset")
         .get_struct()
         .expect("Failed to cast property from written asset to the correct type")
@@ -0,0 +1,227 @@
+use std::{fs, io::Cursor, path::Path};
+
+use gvas::{
+    builder::GvasFileBuilder,
+    custom_version::{CustomVersionTrait, FEditorObjectVersion, FUE5ReleaseStreamObjectVersion},
+    engine_version::EngineVersion,
+    game_version::GameVersion,
+    properties::{
+        array_property::ArrayProperty,
+        int_property::IntProperty,
+        map_property::MapProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        struct_types::{QuatD, RotatorD, Vector2D, Vector2F, VectorD},
+        Property,
+    },
+    types::{map::HashableIndexMap, Guid},
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+fn read_file(path: &str) -> GvasFile {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    GvasFile::read(&mut Cursor::new(data), GameVersion::Default).expect("Read GvasFile")
+}
+
+fn write_bytes(file: &GvasFile) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    writer.into_inner()
+}
+
+fn write_and_read(file: &GvasFile) -> GvasFile {
+    let mut reader = Cursor::new(write_bytes(file));
+    GvasFile::read(&mut reader, GameVersion::Default).expect("Read GvasFile")
+}
+
+fn struct_property(type_name: &str, value: impl Into<StructPropertyValue>) -> StructProperty {
+    StructProperty::new(Guid::default(), type_name.to_string(), value.into())
+}
+
+#[test]
+fn vector2d_to_ue4() {
+    let mut file = read_file(VECTOR2D_PATH);
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE4_27).expect("Retarget");
+
+    assert!(matches!(
+        file.header,
+        GvasHeader::Version2 {
+            package_file_version: 522,
+            ..
+        }
+    ));
+    let custom_versions = file.header.get_custom_versions();
+    assert_eq!(custom_versions.get(&FEditorObjectVersion::GUID), Some(&40));
+    assert_eq!(
+        custom_versions.get(&FUE5ReleaseStreamObjectVersion::GUID),
+        None
+    );
+
+    assert_eq!(lossy_conversions.len(), 1);
+    assert_eq!(lossy_conversions[0].path, "GameSettings.CameraAngle");
+    assert_eq!(
+        lossy_conversions[0].original,
+        Vector2D::new(30.574748247861862, 60.42525175213814).into()
+    );
+    assert_eq!(
+        lossy_conversions[0].converted,
+        Vector2F::new(30.574748247861862f64 as f32, 60.42525175213814f64 as f32).into()
+    );
+
+    assert_eq!(write_and_read(&file), file);
+}
+
+#[test]
+fn slot2_to_ue5() {
+    let original = read_file(SLOT2_PATH);
+    let mut file = original.clone();
+    let custom_versions = file.header.get_custom_versions().clone();
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE5_4).expect("Retarget");
+    assert_eq!(lossy_conversions, vec![]);
+
+    assert!(matches!(
+        file.header,
+        GvasHeader::Version3 {
+            package_file_version: 522,
+            package_file_version_ue5: 1012,
+            ..
+        }
+    ));
+    // Custom versions unknown to the crate are kept
+    for (guid, version) in custom_versions.iter() {
+        if *guid != FEditorObjectVersion::GUID {
+            assert_eq!(file.header.get_custom_versions().get(guid), Some(version));
+        }
+    }
+
+    // Files read from UE 5.4 keep the complete type names of their tags, so compare the data
+    let data = write_bytes(&file);
+    let read_back = write_and_read(&file);
+    assert_eq!(read_back.header, file.header);
+    assert_eq!(write_bytes(&read_back), data);
+    let location = find_property(&read_back.properties, "playerlocationarray")
+        .get_array()
+        .cloned();
+    assert!(matches!(
+        location,
+        Some(ArrayProperty::Structs { structs, .. })
+            if structs == [VectorD::new(1015.786376953125, -3763.712158203125, 10182.298828125).into()]
+    ));
+
+    // Converting back to single precision is exact
+    let mut file = read_back;
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE4_25).expect("Retarget");
+    assert_eq!(lossy_conversions, vec![]);
+    assert_eq!(write_and_read(&file).properties, original.properties);
+}
+
+#[test]
+fn nested_values() {
+    let custom_struct = vec![
+        (
+            String::from("Location"),
+            struct_property("Vector", VectorD::new(1.5, 0.1, -2.0)).into(),
+        ),
+        (
+            String::from("Rotation"),
+            struct_property("Quat", QuatD::new(0.0, 0.0, 0.5, 0.75)).into(),
+        ),
+    ];
+
+    let mut file = GvasFileBuilder::new(EngineVersion::VER_UE5_3, "/Script/Test.TestSaveGame")
+        .property(
+            "Transform",
+            struct_property(
+                "Transform",
+                StructPropertyValue::CustomStruct(custom_struct),
+            ),
+        )
+        .property(
+            "Rotators",
+            ArrayProperty::new(
+                String::from("StructProperty"),
+                Some((
+                    String::from("Rotators"),
+                    String::from("Rotator"),
+                    Guid::default(),
+                )),
+                vec![
+                    Property::StructPropertyValue(RotatorD::new(90.0, 45.0, 0.0).into()),
+                    Property::StructPropertyValue(RotatorD::new(0.0, 1.0 / 3.0, 0.0).into()),
+                ],
+            )
+            .expect("Create ArrayProperty"),
+        )
+        .build()
+        .expect("Build GvasFile");
+
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE4_27).expect("Retarget");
+    let paths: Vec<&str> = lossy_conversions
+        .iter()
+        .map(|lossy| lossy.path.as_str())
+        .collect();
+    assert_eq!(paths, ["Transform.Location", "Rotators.1"]);
+    let read_back = write_and_read(&file);
+    assert_eq!(read_back, file);
+
+    let mut file = read_back;
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE5_3).expect("Retarget");
+    assert_eq!(lossy_conversions, vec![]);
+    let location = find_property(&file.properties, "Transform")
+        .get_struct()
+        .and_then(|property| property.value.get_custom_struct())
+        .map(|properties| find_property(properties, "Location").clone());
+    assert_eq!(
+        location,
+        Some(struct_property("Vector", VectorD::new(1.5, f64::from(0.1f32), -2.0)).into())
+    );
+    assert_eq!(write_and_read(&file), file);
+}
+
+#[test]
+fn map_key_collision() {
+    let key = |x: f64| Property::StructPropertyValue(VectorD::new(x, 0.0, 0.0).into());
+    let mut file = GvasFileBuilder::new(EngineVersion::VER_UE5_3, "/Script/Test.TestSaveGame")
+        .property(
+            "Spawns",
+            MapProperty::new(
+                String::from("StructProperty"),
+                String::from("IntProperty"),
+                vec![],
+                HashableIndexMap::from([
+                    (key(1.0), Property::from(IntProperty::new(1))),
+                    (key(1.0 + f64::EPSILON), Property::from(IntProperty::new(2))),
+                ]),
+            ),
+        )
+        .build()
+        .expect("Build GvasFile");
+    let expected = file.clone();
+
+    // Both keys narrow to the same single precision vector
+    let err = file
+        .retarget(EngineVersion::VER_UE4_27)
+        .expect_err("Colliding keys");
+    assert!(err.to_string().contains("Spawns.1.Key"), "{err}");
+    assert_eq!(file, expected);
+}
+
+#[test]
+fn unknown_engine_version() {
+    let mut file = read_file(VECTOR2D_PATH);
+    let expected = file.clone();
+    assert!(file.retarget(EngineVersion::UNKNOWN).is_err());
+    assert_eq!(file, expected);
+}
+
+#[test]
+fn no_conversion_within_lwc() {
+    let mut file = read_file(TRANSFORM_PATH);
+    let expected_properties = file.properties.clone();
+    let lossy_conversions = file.retarget(EngineVersion::VER_UE5_3).expect("Retarget");
+    assert_eq!(lossy_conversions, vec![]);
+    assert_eq!(file.properties, expected_properties);
+    assert_eq!(write_and_read(&file), file);
+}
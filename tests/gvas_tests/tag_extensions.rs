@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Cursor, Write},
+    path::Path,
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use gvas::{
+    cursor_ext::{ReadExt, WriteExt},
+    error::{DeserializeError, Error, SerializeError},
+    game_version::GameVersion,
+    limits::Limits,
+    object_version::{EUnrealEngineObjectUE5Version, FPackageFileVersion},
+    properties::{
+        property_tag::{
+            EOverriddenPropertyOperation, EPropertyTagExtension, OverridableInformation,
+        },
+        str_property::StrProperty,
+        Property, PropertyOptions, PropertyTrait,
+    },
+    types::map::HashableIndexMap,
+    GvasFile, GvasHeader,
+};
+
+use crate::common::*;
+
+const EXTENSIONS_VERSION: u32 =
+    EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization as u32;
+const COMPLETE_TYPE_NAME_VERSION: u32 =
+    EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as u32;
+
+fn read_property(data: &[u8], file_version_ue5: u32) -> Result<Property, Error> {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::new(522, file_version_ue5),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut reader = Cursor::new(data);
+    let property_type = reader.read_string()?;
+    let property = Property::new(&mut reader, &property_type, true, &mut options, None)?;
+    assert_eq!(reader.position(), data.len() as u64);
+    Ok(property)
+}
+
+fn write_property(property: &Property, file_version_ue5: u32) -> Result<Vec<u8>, Error> {
+    let mut options = PropertyOptions {
+        hints: &HashMap::new(),
+        properties_stack: &mut Vec::new(),
+        custom_versions: &HashableIndexMap::new(),
+        package_file_version: FPackageFileVersion::new(522, file_version_ue5),
+        limits: Limits::default(),
+        diagnostics: None,
+    };
+
+    let mut writer = Cursor::new(Vec::new());
+    property.write(&mut writer, true, &mut options)?;
+    Ok(writer.into_inner())
+}
+
+/// Writes an older `StrProperty` tag, followed by `extensions` and the value.
+fn legacy_str_property(extensions: &[u8]) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    writer.write_string("StrProperty").expect("Write type");
+    writer.write_u32::<LittleEndian>(8).expect("Write length");
+    writer
+        .write_u32::<LittleEndian>(0)
+        .expect("Write array_index");
+    writer.write_u8(0).expect("Write has_property_guid");
+    writer.write_all(extensions).expect("Write extensions");
+    writer.write_string("Str").expect("Write value");
+    writer.into_inner()
+}
+
+#[test]
+fn tag_extensions_none() {
+    let data = legacy_str_property(&[0x00]);
+    let property = read_property(&data, EXTENSIONS_VERSION).expect("Read property");
+    let tag = property.tag().expect("Property tag");
+    assert_eq!(tag.extensions, EPropertyTagExtension::NO_EXTENSION);
+    assert_eq!(tag.overridable, None);
+    assert_eq!(
+        write_property(&property, EXTENSIONS_VERSION).expect("Write property"),
+        data
+    );
+
+    // Older files don't store tag extensions
+    let data = legacy_str_property(&[]);
+    assert_eq!(
+        write_property(&property, EXTENSIONS_VERSION - 1).expect("Write property"),
+        data
+    );
+}
+
+#[test]
+fn tag_extensions_overridable() {
+    let data = legacy_str_property(&[0x02, 0x02, 0x01, 0x00, 0x00, 0x00]);
+    let property = read_property(&data, EXTENSIONS_VERSION).expect("Read property");
+    let tag = property.tag().expect("Property tag");
+    assert_eq!(tag.extensions, EPropertyTagExtension::NO_EXTENSION);
+    assert_eq!(
+        tag.overridable,
+        Some(OverridableInformation {
+            operation: EOverriddenPropertyOperation::Replaced,
+            experimental_overridable_logic: true,
+        })
+    );
+    assert_eq!(
+        write_property(&property, EXTENSIONS_VERSION).expect("Write property"),
+        data
+    );
+}
+
+#[test]
+fn tag_extensions_complete_type_name() {
+    let mut writer = Cursor::new(Vec::new());
+    writer.write_string("StrProperty").expect("Write type");
+    writer
+        .write_i32::<LittleEndian>(0)
+        .expect("Write parameter count");
+    writer.write_u32::<LittleEndian>(8).expect("Write length");
+    writer.write_u8(0x04).expect("Write flags");
+    writer
+        .write_all(&[0x03, 0x01, 0x00, 0x00, 0x00, 0x00])
+        .expect("Write extensions");
+    writer.write_string("Str").expect("Write value");
+    let data = writer.into_inner();
+
+    let property = read_property(&data, COMPLETE_TYPE_NAME_VERSION).expect("Read property");
+    let tag = property.tag().expect("Property tag");
+    assert_eq!(
+        tag.extensions,
+        EPropertyTagExtension::RESERVE_FOR_FUTURE_USE
+    );
+    assert_eq!(
+        tag.overridable,
+        Some(OverridableInformation {
+            operation: EOverriddenPropertyOperation::Modified,
+            experimental_overridable_logic: false,
+        })
+    );
+    assert_eq!(
+        write_property(&property, COMPLETE_TYPE_NAME_VERSION).expect("Write property"),
+        data
+    );
+}
+
+#[test]
+fn tag_extensions_invalid() {
+    let data = legacy_str_property(&[0x04]);
+    let err = read_property(&data, EXTENSIONS_VERSION).expect_err("Expected err");
+    let Error::Deserialize(DeserializeError::InProperty(path, property_type, err)) = err else {
+        panic!("Unexpected error {err}")
+    };
+    assert_eq!(path.as_ref(), "StrProperty");
+    assert_eq!(property_type.as_ref(), "StrProperty");
+    match *err {
+        Error::Deserialize(DeserializeError::InvalidTagExtensions(0x04, position)) => {
+            assert_eq!(position, 0x19)
+        }
+        _ => panic!("Unexpected error {err}"),
+    }
+}
+
+#[test]
+fn tag_extensions_unsupported_version() {
+    let mut property = StrProperty::from("Str");
+    property.tag.overridable = Some(OverridableInformation::default());
+    let property = Property::from(property);
+
+    write_property(&property, EXTENSIONS_VERSION).expect("Write property");
+    let err = write_property(&property, EXTENSIONS_VERSION - 1).expect_err("Expected err");
+    assert!(
+        matches!(err, Error::Serialize(SerializeError::InvalidValue(_))),
+        "Unexpected error {err}"
+    );
+}
+
+/// Writes a save with tag extensions, and checks that it reads back unchanged.
+fn test_file_round_trip(path: &str, hints: &HashMap<String, String>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let data = fs::read(path).expect("Read test asset");
+    let mut file = GvasFile::read_with_hints(&mut Cursor::new(&data), GameVersion::Default, hints)
+        .expect("Read GvasFile");
+
+    file.header = match file.header {
+        GvasHeader::Version1 { .. } => unreachable!("Test files have version 2 or 3 headers"),
+        GvasHeader::Version2 {
+            package_file_version,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        }
+        | GvasHeader::Version3 {
+            package_file_version,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+            ..
+        } => GvasHeader::Version3 {
+            package_file_version,
+            package_file_version_ue5: EXTENSIONS_VERSION,
+            engine_version,
+            custom_version_format,
+            custom_versions,
+            save_game_class_name,
+        },
+    };
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer).expect("Write GvasFile");
+    let extended_data = writer.into_inner();
+
+    let read_back = GvasFile::read_with_hints(
+        &mut Cursor::new(&extended_data),
+        GameVersion::Default,
+        hints,
+    )
+    .expect("Read GvasFile");
+    assert_eq!(file, read_back);
+}
+
+#[test]
+fn tag_extensions_saveslot3() {
+    test_file_round_trip(SAVESLOT_03_PATH, &saveslot3::hints());
+}
+
+#[test]
+fn tag_extensions_features_01() {
+    test_file_round_trip(FEATURES_01_PATH, &features::hints());
+}
+
+#[test]
+fn tag_extensions_slot1() {
+    test_file_round_trip(SLOT1_PATH, &HashMap::new());
+}
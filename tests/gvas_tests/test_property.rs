@@ -2,6 +2,8 @@ use std::{collections::HashMap, io::Cursor};
 
 use gvas::{
     cursor_ext::ReadExt,
+    limits::Limits,
+    object_version::FPackageFileVersion,
     properties::{
         array_property::ArrayProperty,
         enum_property::EnumProperty,
@@ -21,7 +23,7 @@ use gvas::{
     types::{map::HashableIndexMap, Guid},
 };
 
-use gvas::properties::text_property::FText;
+use gvas::properties::text_property::{FText, FTextHistory, FormatArgumentValue, TextGender};
 
 macro_rules! test_property {
     ($function_name:ident, $type:ident, $property_value:expr) => {
@@ -33,6 +35,9 @@ macro_rules! test_property {
                 hints: &HashMap::new(),
                 properties_stack: &mut Vec::new(),
                 custom_versions: &HashableIndexMap::new(),
+                package_file_version: FPackageFileVersion::default(),
+                limits: Limits::default(),
+                diagnostics: None,
             };
 
             // Export the property to a byte array
@@ -167,7 +172,7 @@ test_property!(
     SetProperty,
     SetProperty::new(
         String::from("FloatProperty"),
-        0,
+        vec![],
         vec![Property::from(FloatProperty::new(4321f32))]
     )
 );
@@ -179,7 +184,7 @@ test_property!(
     MapProperty::new(
         String::from("StrProperty"),
         String::from("FloatProperty"),
-        0,
+        vec![],
         HashableIndexMap::from([
             (
                 Property::from(StrProperty::from("key1")),
@@ -192,3 +197,91 @@ test_property!(
         ]),
     )
 );
+test_property!(
+    test_set_keys_to_remove,
+    SetProperty,
+    SetProperty::new(
+        String::from("IntProperty"),
+        vec![
+            Property::from(IntProperty::new(1)),
+            Property::from(IntProperty::new(2)),
+        ],
+        vec![Property::from(IntProperty::new(3))]
+    )
+);
+test_property!(
+    test_map_keys_to_remove,
+    MapProperty,
+    MapProperty::new(
+        String::from("StrProperty"),
+        String::from("FloatProperty"),
+        vec![Property::from(StrProperty::from("removed"))],
+        HashableIndexMap::from([(
+            Property::from(StrProperty::from("key1")),
+            Property::from(FloatProperty::new(-1f32)),
+        )]),
+    )
+);
+test_property!(
+    test_map_properties_keys_to_remove,
+    MapProperty,
+    MapProperty::new(
+        String::from("IntProperty"),
+        String::from("FloatProperty"),
+        vec![Property::from(IntProperty::new(7))],
+        HashableIndexMap::from([(
+            Property::from(IntProperty::new(1)),
+            Property::from(FloatProperty::new(0.5f32)),
+        )]),
+    )
+);
+
+// TextProperty
+test_property!(
+    test_text_generator,
+    TextProperty,
+    TextProperty::new(FText {
+        flags: 0,
+        history: FTextHistory::TextGenerator {
+            generator_type: String::from("/Script/Game.TextGenerator"),
+            generator_contents: vec![1, 2, 3, 4],
+        },
+    })
+);
+test_property!(
+    test_text_generator_none,
+    TextProperty,
+    TextProperty::new(FText {
+        flags: 0,
+        history: FTextHistory::TextGenerator {
+            generator_type: String::from("None"),
+            generator_contents: vec![],
+        },
+    })
+);
+test_property!(
+    test_text_raw,
+    TextProperty,
+    TextProperty::new(FText {
+        flags: 0,
+        history: FTextHistory::RawText {
+            source_string: Some(String::from("Raw text")),
+        },
+    })
+);
+test_property!(
+    test_text_gender,
+    TextProperty,
+    TextProperty::new(FText {
+        flags: 0,
+        history: FTextHistory::OrderedFormat {
+            source_format: Box::new(FText::new_base(
+                0,
+                None,
+                Some(String::from("key")),
+                Some(String::from("{0}|gender(he,she,it)")),
+            )),
+            arguments: vec![FormatArgumentValue::Gender(TextGender::Feminine)],
+        },
+    })
+);
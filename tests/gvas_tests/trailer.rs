@@ -0,0 +1,127 @@
+use std::{collections::HashMap, fs, io::Cursor, path::Path};
+
+use flate2::Crc;
+use gvas::{
+    error::{Error, SerializeError},
+    game_version::GameVersion,
+    properties::{int_property::IntProperty, Property},
+    GvasFile, GvasTrailer,
+};
+
+use crate::common::*;
+
+const THUMBNAIL: &[u8] = b"\x00\x00\x00\x00THUMBNAIL";
+
+fn read_slot1() -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SLOT1_PATH);
+    fs::read(path).expect("Read test asset")
+}
+
+/// Slot1 with `THUMBNAIL` instead of the usual four zero bytes
+fn slot1_with_trailer() -> Vec<u8> {
+    let mut data = read_slot1();
+    data.truncate(data.len() - 4);
+    data.extend(THUMBNAIL);
+    data
+}
+
+fn write(file: &GvasFile) -> Result<Vec<u8>, Error> {
+    let mut writer = Cursor::new(Vec::new());
+    file.write(&mut writer)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn trailer_padding() {
+    let file = GvasFile::read(&mut Cursor::new(read_slot1()), GameVersion::Default)
+        .expect("Read GvasFile");
+    assert_eq!(file.trailer, GvasTrailer::default());
+    assert!(file.trailer.is_padding());
+}
+
+#[test]
+fn trailer_round_trip() {
+    let data = slot1_with_trailer();
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    assert_eq!(file.trailer, GvasTrailer::new(THUMBNAIL.to_vec()));
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    assert_eq!(write(&file).expect("Write GvasFile"), data);
+}
+
+#[test]
+fn trailer_edited() {
+    let data = slot1_with_trailer();
+    let mut file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    file.properties.push((
+        String::from("new_property"),
+        Property::from(IntProperty::new(42)),
+    ));
+
+    // The trailer is written back as it is
+    let written = write(&file).expect("Write GvasFile");
+    assert!(written.ends_with(THUMBNAIL));
+
+    let read_back = GvasFile::read_with_hints(
+        &mut Cursor::new(&written),
+        GameVersion::Default,
+        &HashMap::new(),
+    )
+    .expect("Read GvasFile");
+    assert_eq!(read_back.properties, file.properties);
+    assert_eq!(read_back.trailer.data, THUMBNAIL);
+}
+
+#[test]
+fn trailer_stale() {
+    let data = slot1_with_trailer();
+    let mut file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    let body_crc32 = file.body_crc32().expect("Compute CRC-32");
+    let mut crc = Crc::new();
+    crc.update(&data[..data.len() - THUMBNAIL.len()]);
+    assert_eq!(body_crc32, crc.sum());
+    file.trailer = GvasTrailer::with_body_crc32(THUMBNAIL.to_vec(), body_crc32);
+    assert_eq!(write(&file).expect("Write GvasFile"), data);
+
+    file.properties.push((
+        String::from("new_property"),
+        Property::from(IntProperty::new(42)),
+    ));
+    let result = write(&file);
+    assert!(
+        matches!(
+            result,
+            Err(Error::Serialize(SerializeError::InvalidValue(_)))
+        ),
+        "Unexpected result {result:?}"
+    );
+
+    // The trailer is kept once it doesn't depend on the properties
+    file.trailer.body_crc32 = None;
+    let written = write(&file).expect("Write GvasFile");
+    assert!(written.ends_with(THUMBNAIL));
+}
+
+#[test]
+fn trailer_new() {
+    let mut file = slot1::expected();
+    file.trailer = GvasTrailer::new(THUMBNAIL.to_vec());
+    assert_eq!(write(&file).expect("Write GvasFile"), slot1_with_trailer());
+}
+
+#[test]
+fn trailer_missing() {
+    // The file ends right after the `None` terminator
+    let mut data = read_slot1();
+    data.truncate(data.len() - 4);
+    let file =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Default).expect("Read GvasFile");
+    assert_eq!(file.trailer, GvasTrailer::new(Vec::new()));
+    assert!(!file.trailer.is_padding());
+    assert_eq!(file.properties, slot1::expected().properties);
+
+    assert_eq!(write(&file).expect("Write GvasFile"), data);
+}
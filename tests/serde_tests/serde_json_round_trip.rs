@@ -1,6 +1,6 @@
 use crate::common::*;
-use gvas::{game_version::GameVersion, GvasFile};
-use std::{collections::HashMap, fs::File, path::Path};
+use gvas::{container::Palworld, game_version::GameVersion, GvasFile, GvasTrailer};
+use std::{collections::HashMap, fs::File, io::Cursor, path::Path};
 
 fn test_file_with_hints(path: &str, hints: &HashMap<String, String>) {
     let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
@@ -105,3 +105,46 @@ fn serde_transform() {
 fn serde_vector2d() {
     test_file(VECTOR2D_PATH);
 }
+
+#[test]
+fn serde_trailer() {
+    let mut file = slot1::expected();
+    file.trailer = GvasTrailer {
+        data: b"THUMBNAIL".to_vec(),
+        body_crc32: Some(0x12345678),
+    };
+    let value = serde_json::to_string(&file).expect("Deserialize");
+    assert!(value.contains("\"5448554d424e41494c\""), "{value}");
+    let from_value = serde_json::from_str::<GvasFile>(value.as_str()).expect("Serialize");
+    assert_eq!(file, from_value);
+}
+
+#[test]
+fn serde_palworld_zlib() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(PALWORLD_ZLIB_PATH);
+    let mut file = File::open(path).expect("Open test asset");
+    let file = GvasFile::read(&mut file, GameVersion::Palworld).expect("Parse gvas file");
+    let value = serde_json::to_string(&file).expect("Deserialize");
+    let from_value = serde_json::from_str::<GvasFile>(value.as_str()).expect("Serialize");
+    assert_eq!(file, from_value);
+}
+
+#[test]
+fn serde_palworld_compressed_data() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(PALWORLD_ZLIB_PATH);
+    let data = std::fs::read(path).expect("Read test asset");
+    let kept =
+        GvasFile::read(&mut Cursor::new(&data), GameVersion::Palworld).expect("Parse gvas file");
+    let file = GvasFile::read_with_containers(
+        &mut Cursor::new(&data),
+        &[&Palworld::RECOMPRESS],
+        &HashMap::new(),
+    )
+    .expect("Parse gvas file");
+
+    // The compressed data is kept in memory only
+    let value = serde_json::to_string(&kept).expect("Deserialize");
+    assert_eq!(value, serde_json::to_string(&file).expect("Deserialize"));
+    let from_value = serde_json::from_str::<GvasFile>(value.as_str()).expect("Serialize");
+    assert_eq!(file, from_value);
+}
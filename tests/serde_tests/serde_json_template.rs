@@ -14,6 +14,7 @@ use gvas::{
         map_property::MapProperty,
         name_property::NameProperty,
         object_property::ObjectProperty,
+        property_tag::PropertyTagData,
         set_property::SetProperty,
         str_property::StrProperty,
         struct_property::StructPropertyValue,
@@ -23,7 +24,7 @@ use gvas::{
         },
         text_property::{
             DateTimeStyle, FText, FTextHistory, FormatArgumentValue, NumberFormattingOptions,
-            RoundingMode, TextProperty, TransformType,
+            RoundingMode, TextGender, TextProperty, TransformType,
         },
         unknown_property::UnknownProperty,
         Property,
@@ -120,8 +121,8 @@ fn array_int8() {
                 String::from("Int8Property"),
                 None,
                 vec![
-                    Property::Int8Property(Int8Property { value: 0 }),
-                    Property::Int8Property(Int8Property { value: 1 }),
+                    Property::Int8Property(Int8Property::new(0)),
+                    Property::Int8Property(Int8Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -151,8 +152,8 @@ fn array_int16() {
                 String::from("Int16Property"),
                 None,
                 vec![
-                    Property::Int16Property(Int16Property { value: 0 }),
-                    Property::Int16Property(Int16Property { value: 1 }),
+                    Property::Int16Property(Int16Property::new(0)),
+                    Property::Int16Property(Int16Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -182,8 +183,8 @@ fn array_int32() {
                 String::from("IntProperty"),
                 None,
                 vec![
-                    Property::IntProperty(IntProperty { value: 0 }),
-                    Property::IntProperty(IntProperty { value: 1 }),
+                    Property::IntProperty(IntProperty::new(0)),
+                    Property::IntProperty(IntProperty::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -206,8 +207,8 @@ fn array_int64() {
                 String::from("Int64Property"),
                 None,
                 vec![
-                    Property::Int64Property(Int64Property { value: 0 }),
-                    Property::Int64Property(Int64Property { value: 1 }),
+                    Property::Int64Property(Int64Property::new(0)),
+                    Property::Int64Property(Int64Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -259,8 +260,8 @@ fn array_uint16() {
                 String::from("UInt16Property"),
                 None,
                 vec![
-                    Property::UInt16Property(UInt16Property { value: 0 }),
-                    Property::UInt16Property(UInt16Property { value: 1 }),
+                    Property::UInt16Property(UInt16Property::new(0)),
+                    Property::UInt16Property(UInt16Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -290,8 +291,8 @@ fn array_uint32() {
                 String::from("UInt32Property"),
                 None,
                 vec![
-                    Property::UInt32Property(UInt32Property { value: 0 }),
-                    Property::UInt32Property(UInt32Property { value: 1 }),
+                    Property::UInt32Property(UInt32Property::new(0)),
+                    Property::UInt32Property(UInt32Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -321,8 +322,8 @@ fn array_uint64() {
                 String::from("UInt64Property"),
                 None,
                 vec![
-                    Property::UInt64Property(UInt64Property { value: 0 }),
-                    Property::UInt64Property(UInt64Property { value: 1 }),
+                    Property::UInt64Property(UInt64Property::new(0)),
+                    Property::UInt64Property(UInt64Property::new(1)),
                 ],
             )
             .expect("ArrayProperty::new"),
@@ -572,13 +573,13 @@ fn array_map() {
                     Property::MapProperty(MapProperty::new(
                         "kta".to_string(),
                         "vta".to_string(),
-                        0,
+                        vec![],
                         HashableIndexMap::from([]),
                     )),
                     Property::MapProperty(MapProperty::new(
                         "ktb".to_string(),
                         "vtb".to_string(),
-                        1,
+                        vec![Property::from(StrProperty::from("k"))],
                         HashableIndexMap::from([]),
                     )),
                 ],
@@ -593,14 +594,18 @@ fn array_map() {
       "type": "MapProperty",
       "key_type": "kta",
       "value_type": "vta",
-      "allocation_flags": 0,
       "value": []
     },
     {
       "type": "MapProperty",
       "key_type": "ktb",
       "value_type": "vtb",
-      "allocation_flags": 1,
+      "keys_to_remove": [
+        {
+          "type": "StrProperty",
+          "value": "k"
+        }
+      ],
       "value": []
     }
   ]
@@ -708,7 +713,7 @@ fn map_enum_bool() {
         &Property::MapProperty(MapProperty::new(
             String::from("EnumProperty"),
             String::from("BoolProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::from(EnumProperty::new(None, String::from("a"))),
@@ -736,7 +741,7 @@ fn map_enum_int() {
         &Property::MapProperty(MapProperty::new(
             String::from("EnumProperty"),
             String::from("IntProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::from(EnumProperty::new(None, String::from("a"))),
@@ -764,7 +769,7 @@ fn map_enum_unknown() {
         &Property::MapProperty(MapProperty::new(
             String::from("EnumProperty"),
             String::from("UnknownProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::from(EnumProperty::new(None, String::from("a"))),
@@ -803,7 +808,7 @@ fn map_int_bool() {
         &Property::MapProperty(MapProperty::new(
             String::from("IntProperty"),
             String::from("BoolProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::IntProperty(IntProperty::new(0)),
@@ -823,7 +828,6 @@ fn map_int_bool() {
   "type": "MapProperty",
   "key_type": "IntProperty",
   "value_type": "BoolProperty",
-  "allocation_flags": 0,
   "value": [
     [
       {
@@ -866,7 +870,7 @@ fn map_name_bool() {
         &Property::MapProperty(MapProperty::new(
             String::from("NameProperty"),
             String::from("BoolProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::NameProperty(NameProperty::from("a")),
@@ -894,7 +898,7 @@ fn map_name_int() {
         &Property::MapProperty(MapProperty::new(
             String::from("NameProperty"),
             String::from("IntProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::NameProperty(NameProperty::from("a")),
@@ -922,7 +926,7 @@ fn map_name_property() {
         &Property::MapProperty(MapProperty::new(
             String::from("NameProperty"),
             String::from("UnknownProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::NameProperty(NameProperty::from("a")),
@@ -961,7 +965,7 @@ fn map_str_bool() {
         &Property::MapProperty(MapProperty::new(
             String::from("StrProperty"),
             String::from("BoolProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::StrProperty(StrProperty::from("a")),
@@ -989,7 +993,7 @@ fn map_str_int() {
         &Property::MapProperty(MapProperty::new(
             String::from("StrProperty"),
             String::from("IntProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::StrProperty(StrProperty::from("zero")),
@@ -1022,7 +1026,7 @@ fn map_str_property() {
         &Property::MapProperty(MapProperty::new(
             String::from("StrProperty"),
             String::from("UnknownProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::StrProperty(StrProperty::from("a")),
@@ -1061,7 +1065,7 @@ fn map_str_str() {
         &Property::MapProperty(MapProperty::new(
             String::from("StrProperty"),
             String::from("StrProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::StrProperty(StrProperty::from("a")),
@@ -1089,7 +1093,7 @@ fn map_struct_float() {
         &Property::MapProperty(MapProperty::new(
             String::from("StructProperty"),
             String::from("FloatProperty"),
-            0,
+            vec![],
             HashableIndexMap::from([
                 (
                     Property::from(StructPropertyValue::from(VectorF::new(0f32, 1f32, 2f32))),
@@ -1109,7 +1113,6 @@ fn map_struct_float() {
   "type": "MapProperty",
   "key_type": "StructProperty",
   "value_type": "FloatProperty",
-  "allocation_flags": 0,
   "value": [
     [
       {
@@ -1158,7 +1161,10 @@ fn map_struct_float() {
 fn name_array_index() {
     serde_json(
         &Property::NameProperty(NameProperty {
-            array_index: 1,
+            tag: PropertyTagData {
+                array_index: 1,
+                ..Default::default()
+            },
             value: None,
         }),
         r#"{
@@ -1172,7 +1178,7 @@ fn name_array_index() {
 fn name_none() {
     serde_json(
         &Property::NameProperty(NameProperty {
-            array_index: 0,
+            tag: PropertyTagData::default(),
             value: None,
         }),
         r#"{
@@ -1333,16 +1339,15 @@ fn set_int() {
     serde_json(
         &Property::SetProperty(SetProperty::new(
             String::from("IntProperty"),
-            0,
+            vec![],
             vec![
-                Property::IntProperty(IntProperty { value: 0 }),
-                Property::IntProperty(IntProperty { value: 1 }),
+                Property::IntProperty(IntProperty::new(0)),
+                Property::IntProperty(IntProperty::new(1)),
             ],
         )),
         r#"{
   "type": "SetProperty",
   "property_type": "IntProperty",
-  "allocation_flags": 0,
   "properties": [
     {
       "type": "IntProperty",
@@ -1360,7 +1365,7 @@ fn set_int() {
 #[test]
 fn str_none() {
     serde_json(
-        &Property::StrProperty(StrProperty { value: None }),
+        &Property::StrProperty(StrProperty::new(None)),
         r#"{
   "type": "StrProperty"
 }"#,
@@ -1527,22 +1532,21 @@ fn struct_intpoint() {
 #[test]
 fn struct_custom() {
     serde_json(
-        &Property::from(StructPropertyValue::CustomStruct(HashableIndexMap::from([
-            (
-                String::from("key"),
-                vec![Property::from(StrProperty::from("value"))],
-            ),
-        ]))),
+        &Property::from(StructPropertyValue::CustomStruct(vec![(
+            String::from("key"),
+            Property::from(StrProperty::from("value")),
+        )])),
         r#"{
   "type": "StructPropertyValue",
-  "CustomStruct": {
-    "key": [
+  "CustomStruct": [
+    [
+      "key",
       {
         "type": "StrProperty",
         "value": "value"
       }
     ]
-  }
+  ]
 }"#,
     )
 }
@@ -1550,36 +1554,44 @@ fn struct_custom() {
 #[test]
 fn struct_array_index() {
     serde_json(
-        &Property::from(StructPropertyValue::CustomStruct(HashableIndexMap::from([
+        &Property::from(StructPropertyValue::CustomStruct(vec![
             (
                 String::from("TrackedQuestsNames"),
-                vec![
-                    Property::NameProperty(NameProperty {
-                        array_index: 0,
-                        value: Some(String::from("QU91_InvestigateTower_B2")),
-                    }),
-                    Property::NameProperty(NameProperty {
+                Property::NameProperty(NameProperty {
+                    tag: PropertyTagData::default(),
+                    value: Some(String::from("QU91_InvestigateTower_B2")),
+                }),
+            ),
+            (
+                String::from("TrackedQuestsNames"),
+                Property::NameProperty(NameProperty {
+                    tag: PropertyTagData {
                         array_index: 1,
-                        value: Some(String::from("QU91_InvestigateTower_B2")),
-                    }),
-                ],
+                        ..Default::default()
+                    },
+                    value: Some(String::from("QU91_InvestigateTower_B2")),
+                }),
             ),
-        ]))),
+        ])),
         r#"{
   "type": "StructPropertyValue",
-  "CustomStruct": {
-    "TrackedQuestsNames": [
+  "CustomStruct": [
+    [
+      "TrackedQuestsNames",
       {
         "type": "NameProperty",
         "value": "QU91_InvestigateTower_B2"
-      },
+      }
+    ],
+    [
+      "TrackedQuestsNames",
       {
         "type": "NameProperty",
         "array_index": 1,
         "value": "QU91_InvestigateTower_B2"
       }
     ]
-  }
+  ]
 }"#,
     )
 }
@@ -1966,6 +1978,70 @@ fn text_transform() {
     );
 }
 
+#[test]
+fn text_generator() {
+    serde_json(
+        &Property::TextProperty(TextProperty::new(FText {
+            flags: 0,
+            history: FTextHistory::TextGenerator {
+                generator_type: String::from("Generator"),
+                generator_contents: vec![1, 2],
+            },
+        })),
+        r#"{
+  "type": "TextProperty",
+  "history": "TextGenerator",
+  "generator_type": "Generator",
+  "generator_contents": [
+    1,
+    2
+  ]
+}"#,
+    );
+}
+
+#[test]
+fn text_raw() {
+    serde_json(
+        &Property::TextProperty(TextProperty::new(FText {
+            flags: 0,
+            history: FTextHistory::RawText {
+                source_string: Some(String::from("Raw")),
+            },
+        })),
+        r#"{
+  "type": "TextProperty",
+  "history": "RawText",
+  "source_string": "Raw"
+}"#,
+    );
+}
+
+#[test]
+fn text_gender() {
+    serde_json(
+        &Property::TextProperty(TextProperty::new(FText {
+            flags: 0,
+            history: FTextHistory::OrderedFormat {
+                source_format: Box::new(FText::new_none(0, Some(None))),
+                arguments: vec![FormatArgumentValue::Gender(TextGender::Neuter)],
+            },
+        })),
+        r#"{
+  "type": "TextProperty",
+  "history": "OrderedFormat",
+  "source_format": {
+    "history": "None"
+  },
+  "arguments": [
+    {
+      "Gender": "Neuter"
+    }
+  ]
+}"#,
+    );
+}
+
 #[test]
 fn unknown() {
     serde_json(